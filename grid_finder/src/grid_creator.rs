@@ -141,6 +141,7 @@ pub fn create_grids<const W: usize>(
                             set.letter_counts,
                             &finder_words,
                             &exclude_words,
+                            &FinderDigraphs::default(),
                             Character::E,
                             &mut counter,
                             &mut result,
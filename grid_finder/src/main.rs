@@ -22,6 +22,7 @@ use std::{
 };
 use ws_core::{
     complete_solve,
+    dictionary::WordAutomata,
     finder::{
         cluster::Cluster,
         falling_probability,
@@ -64,6 +65,13 @@ pub enum Commands {
     CheckWords {
         grid: String,
     },
+
+    /// Compile english_words.txt into the binary dictionary used for bonus words
+    CompileWords {
+        /// Shorter words are left out
+        #[arg(long, default_value = "4")]
+        min_length: usize,
+    },
 }
 
 #[derive(Args, Debug)]
@@ -167,6 +175,9 @@ fn main() {
         Some(Commands::CheckWords { grid }) => {
             check_words(grid);
         }
+        Some(Commands::CompileWords { min_length }) => {
+            compile_words(min_length);
+        }
         None => do_finder(FindGridsArgs::default()),
     }
 
@@ -378,7 +389,7 @@ fn cluster_files(options: &ClusterArgs) {
                         >= options.min_falling
             })
             .filter(|grid| {
-                if let Some(..) = orientation::find_taboo_word(&grid.grid) {
+                if let Some(..) = orientation::find_taboo_word(&grid.grid, grid.digraphs.as_ref()) {
                     taboo_grids += 1;
                     false
                 } else {
@@ -492,7 +503,9 @@ fn check_words(grid: String) {
     let english_words = std::fs::read_to_string("english_words.txt")
         .expect("Expected a file named 'english_words.txt'");
 
-    let results = complete_solve::do_complete_solve(&grid, english_words.as_str(), 4);
+    let dictionary = WordAutomata::from_word_list(english_words.as_str(), 4);
+
+    let results = complete_solve::do_complete_solve(&grid, &dictionary);
     //https://raw.githubusercontent.com/dwyl/english-words/master/words.txt
 
     let found_words = results
@@ -504,6 +517,21 @@ fn check_words(grid: String) {
     info!("{found_words}");
 }
 
+fn compile_words(min_length: usize) {
+    let english_words = std::fs::read_to_string("english_words.txt")
+        .expect("Expected a file named 'english_words.txt'");
+
+    let dictionary = WordAutomata::from_word_list(english_words.as_str(), min_length);
+    let bytes = dictionary.to_bytes();
+    std::fs::write("english_words.wsda", &bytes).expect("Should be able to write dictionary");
+
+    info!(
+        "Wrote {} states ({} bytes) to english_words.wsda",
+        dictionary.state_count(),
+        bytes.len()
+    );
+}
+
 fn do_finder(options: FindGridsArgs) {
     info!("Starting up");
 
@@ -657,7 +685,7 @@ fn do_finder(options: FindGridsArgs) {
         let mut taboo_grids = 0;
 
         grids.retain(|grid| {
-            if let Some(..) = orientation::find_taboo_word(&grid.grid) {
+            if let Some(..) = orientation::find_taboo_word(&grid.grid, grid.digraphs.as_ref()) {
                 taboo_grids += 1;
 
                 false
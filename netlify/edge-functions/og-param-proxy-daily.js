@@ -6,7 +6,8 @@ export default async (request, context) => {
   let page = await response.text();
 
   try {
-    const daily = url.pathname.substring(7);
+    // Challenge links end with /vs/<challenge>, which is not part of the level
+    const daily = url.pathname.substring(7).split("/vs/")[0];
 
     page = page.replace(
       `https://wordsalad.online/images/og_image.png`,
@@ -6,7 +6,8 @@ export default async (request, context) => {
   let page = await response.text();
 
   try {
-    const game = url.pathname.substring(6);
+    // Challenge links end with /vs/<challenge>, which is not part of the level
+    const game = url.pathname.substring(6).split("/vs/")[0];
 
     page = page.replace(
       `https://wordsalad.online/images/og_image.png`,
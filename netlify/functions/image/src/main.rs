@@ -1,10 +1,9 @@
 use aws_lambda_events::encodings::Body;
 use aws_lambda_events::event::apigw::{ApiGatewayProxyRequest, ApiGatewayProxyResponse};
 use aws_lambda_events::http::{HeaderMap, HeaderValue};
-use base64::Engine;
 use lambda_runtime::{service_fn, Error, LambdaEvent};
 use resvg::usvg::*;
-use ws_core::{DesignedLevel, Grid, Tile};
+use ws_core::{split_challenge_path, DesignedLevel, Grid, Tile};
 
 #[tokio::main]
 async fn main() -> Result<(), Error> {
@@ -28,14 +27,11 @@ fn get_parameter<'a>(
 async fn image_request_handler(
     lambda_event: LambdaEvent<ApiGatewayProxyRequest>,
 ) -> Result<ApiGatewayProxyResponse, Error> {
-    let daily: Option<DesignedLevel> = get_parameter(&lambda_event, "daily")
-        .and_then(|x| x.parse().ok())
-        .map(|i| level_from_daily_index(i));
+    let daily: Option<DesignedLevel> =
+        get_parameter(&lambda_event, "daily").and_then(level_from_daily_parameter);
 
-    let game: Option<DesignedLevel> = get_parameter(&lambda_event, "game")
-        .and_then(|data| base64::engine::general_purpose::URL_SAFE.decode(data).ok())
-        .and_then(|data| String::from_utf8(data).ok())
-        .map(|data| DesignedLevel::from_tsv_line(&data.trim()).expect("Could not parse level"));
+    let game: Option<DesignedLevel> =
+        get_parameter(&lambda_event, "game").and_then(level_from_game_parameter);
 
     let width: u32 = get_parameter(&lambda_event, "width")
         .and_then(|x| x.parse().ok())
@@ -61,6 +57,22 @@ async fn image_request_handler(
     Ok(resp)
 }
 
+/// The daily level for a `daily` parameter, which may still have a challenge after it
+fn level_from_daily_parameter(daily: &str) -> Option<DesignedLevel> {
+    let (daily, _) = split_challenge_path(daily);
+    daily
+        .trim_end_matches('/')
+        .parse()
+        .ok()
+        .map(level_from_daily_index)
+}
+
+/// The level for a `game` parameter, which may still have a challenge after it
+fn level_from_game_parameter(game: &str) -> Option<DesignedLevel> {
+    let (game, _) = split_challenge_path(game);
+    DesignedLevel::try_from_share_data(game)
+}
+
 fn level_from_daily_index(index: usize) -> DesignedLevel {
     let daily_index = index.saturating_sub(1);
 
@@ -167,11 +179,7 @@ mod tests {
         512
     )]
     fn test_game_image(game: &str, width: u32, height: u32) {
-        let level = base64::engine::general_purpose::URL_SAFE
-            .decode(game)
-            .unwrap();
-        let level = String::from_utf8(level).unwrap();
-        let level = DesignedLevel::from_tsv_line(&level.trim()).expect("Could not parse level");
+        let level = DesignedLevel::try_from_share_data(game).expect("Could not parse level");
 
         let data = draw_image(level, width, height);
         let len = data.len();
@@ -184,6 +192,22 @@ mod tests {
         assert!(len < 300000, "Image is too big - {len} bytes");
     }
 
+    #[test]
+    fn test_challenge_parameters() {
+        const GAME: &str =
+            "Sk5FSU1BTFpSWUlaVFRESwlCZW5uZXQgU2lzdGVycwlKYW5lCUtpdHR5CUxpenppZQlMeWRpYQlNYXJ5";
+
+        assert_eq!(
+            level_from_daily_parameter("2/vs/AQAAUwAGAQIDBAUGAEFsZXhYyA"),
+            Some(level_from_daily_index(2))
+        );
+        assert_eq!(
+            level_from_game_parameter(format!("{GAME}/vs/AQAAUwAGAQIDBAUGAEFsZXhYyA").as_str()),
+            DesignedLevel::try_from_share_data(GAME)
+        );
+        assert!(level_from_game_parameter(GAME).is_some());
+    }
+
     fn calculate_hash<T: Hash>(t: &T) -> u64 {
         let mut s = std::collections::hash_map::DefaultHasher::new();
         t.hash(&mut s);
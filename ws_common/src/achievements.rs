@@ -19,6 +19,7 @@ impl Plugin for AchievementsPlugin {
 
         app.init_tracked_resource::<AchievementsState>();
         app.init_tracked_resource::<WordsFoundCountState>();
+        app.init_tracked_resource::<NearMissCountState>();
         app.add_systems(
             Update,
             track_level_completion_achievements.run_if(|found_words: Res<FoundWordsState>| {
@@ -35,6 +36,11 @@ impl Plugin for AchievementsPlugin {
             track_hint_achievements.run_if(|e: EventReader<HintEvent>| !e.is_empty()),
         );
 
+        app.add_systems(
+            Update,
+            track_near_misses.run_if(|e: EventReader<NearMissEvent>| !e.is_empty()),
+        );
+
         app.add_systems(
             Update,
             track_streak_achievements.run_if(|s: Res<Streak>| s.is_changed()),
@@ -107,6 +113,20 @@ fn track_hint_achievements(
     }
 }
 
+fn track_near_misses(
+    mut events: EventReader<NearMissEvent>,
+    mut achievements: ResMut<AchievementsState>,
+    mut near_miss_count: ResMut<NearMissCountState>,
+) {
+    for _ in events.read() {
+        near_miss_count.count += 1;
+
+        if near_miss_count.count == 10 {
+            maybe_unlock(&mut achievements, Achievement::CloseButNoCigar);
+        }
+    }
+}
+
 fn track_selfie_achievements(
     mut achievements: ResMut<AchievementsState>,
     video: Res<VideoResource>,
@@ -186,7 +206,7 @@ fn track_level_completion_achievements(
                 maybe_unlock(&mut achievements, Achievement::CaesarSalad);
             }
         }
-        CurrentLevel::Custom { .. } => {}
+        CurrentLevel::Custom { .. } | CurrentLevel::Endless { .. } => {}
         CurrentLevel::NonLevel(NonLevel::DailyChallengeFinished)
         | CurrentLevel::NonLevel(NonLevel::DailyChallengeReset) => {
             maybe_unlock(&mut achievements, Achievement::CaesarSalad);
@@ -196,21 +216,33 @@ fn track_level_completion_achievements(
 
     let eligible_for_timed: bool = match current_level.as_ref() {
         CurrentLevel::DailyChallenge { .. } | CurrentLevel::Fixed { .. } => true,
-        CurrentLevel::Tutorial { .. } | CurrentLevel::Custom { .. } | CurrentLevel::NonLevel(_) => {
-            false
-        }
+        CurrentLevel::Tutorial { .. }
+        | CurrentLevel::Custom { .. }
+        | CurrentLevel::Endless { .. }
+        | CurrentLevel::NonLevel(_) => false,
     };
 
     if !eligible_for_timed {
         return;
     }
 
+    if found_words.hard_mode {
+        maybe_unlock(&mut achievements, Achievement::HardBoiled);
+
+        if sequence_completions.get_total_complete_hard_mode()
+            + daily_challenge_completions.get_daily_challenges_complete_hard_mode()
+            >= 10
+        {
+            maybe_unlock(&mut achievements, Achievement::IronChef);
+        }
+    }
+
     if found_words.hints_used == 0 {
         maybe_unlock(&mut achievements, Achievement::RightInOne);
     }
 
     let secs = level_time.total_elapsed().as_secs();
-    if secs <= 60 {
+    if secs <= 60 && !found_words.zen_mode && found_words.versus.is_none() {
         maybe_unlock(&mut achievements, Achievement::Pow);
         if secs <= 30 {
             maybe_unlock(&mut achievements, Achievement::Whoosh);
@@ -229,7 +261,7 @@ fn track_level_completion_achievements(
 
 fn was_completed_alphabetically(state: &FoundWordsState) -> bool {
     for (expected, completion) in state.word_completions.iter().enumerate() {
-        let Completion::Complete { index } = completion else {
+        let Completion::Complete { index, .. } = completion else {
             return false;
         };
         if expected != *index as usize {
@@ -242,7 +274,7 @@ fn was_completed_alphabetically(state: &FoundWordsState) -> bool {
 
 fn was_completed_reverse_alphabetically(state: &FoundWordsState) -> bool {
     for (expected, completion) in state.word_completions.iter().rev().enumerate() {
-        let Completion::Complete { index } = completion else {
+        let Completion::Complete { index, .. } = completion else {
             return false;
         };
         if expected != *index as usize {
@@ -354,6 +386,16 @@ impl TrackableResource for WordsFoundCountState {
     const KEY: &'static str = "WordsFoundCount";
 }
 
+/// The number of near misses in every level
+#[derive(Debug, Resource, Clone, PartialEq, Serialize, Deserialize, Default)]
+struct NearMissCountState {
+    pub count: usize,
+}
+
+impl TrackableResource for NearMissCountState {
+    const KEY: &'static str = "NearMissCount";
+}
+
 #[derive(
     Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize_repr, Deserialize_repr, AsRefStr, Display,
 )]
@@ -437,6 +479,15 @@ pub enum Achievement {
     /// Zed's dead, baby - find a word that contains a Z
     #[strum(serialize = "Zed's dead, baby")]
     ZedDeadBaby,
+    /// Close, But No Cigar - almost find a word 10 times
+    #[strum(serialize = "Close, But No Cigar")]
+    CloseButNoCigar,
+    /// Hard Boiled - complete a puzzle in hard mode
+    #[strum(serialize = "Hard Boiled")]
+    HardBoiled,
+    /// Iron Chef - complete 10 puzzles in hard mode
+    #[strum(serialize = "Iron Chef")]
+    IronChef,
 }
 
 impl Achievement {
@@ -470,6 +521,9 @@ impl Achievement {
             XMarksTheSpot => "CgkInsjSxL0FEAIQGA",
             YouGoGlenCoco => "CgkInsjSxL0FEAIQGQ",
             ZedDeadBaby => "CgkInsjSxL0FEAIQGg",
+            CloseButNoCigar => "CgkInsjSxL0FEAIQGw",
+            HardBoiled => "CgkInsjSxL0FEAIQHA",
+            IronChef => "CgkInsjSxL0FEAIQHQ",
         }
         //spellchecker:enable
     }
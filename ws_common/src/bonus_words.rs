@@ -0,0 +1,138 @@
+use std::collections::BTreeMap;
+
+use crate::prelude::*;
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use nice_bevy_utils::{CanInitTrackedResource, TrackableResource};
+use serde::{Deserialize, Serialize};
+use ws_core::dictionary::{Dictionary, WordAutomata};
+
+/// Shorter words are too easy to trace by accident
+pub const MIN_BONUS_WORD_LENGTH: usize = 4;
+
+lazy_static! {
+    /// Compiled from `english_words.txt` by the grid finder's `compile-words` command
+    static ref BONUS_WORDS_DICTIONARY: WordAutomata =
+        WordAutomata::try_from_bytes(include_bytes!("../../grid_finder/english_words.wsda"))
+            .expect("Bonus words dictionary should be valid");
+}
+
+pub struct BonusWordsPlugin;
+
+impl Plugin for BonusWordsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_tracked_resource::<BonusWordsState>();
+    }
+}
+
+/// Real words which are not part of the theme, found in each level
+#[derive(
+    Debug, Clone, Resource, Serialize, Deserialize, MavericContext, Default, PartialEq, Eq,
+)]
+pub struct BonusWordsState {
+    /// The words found in each level, keyed by the level's full name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub words_by_level: BTreeMap<String, Vec<String>>,
+    /// The number of bonus words found in every level
+    pub total_found: usize,
+}
+
+impl TrackableResource for BonusWordsState {
+    const KEY: &'static str = "BonusWords";
+}
+
+impl BonusWordsState {
+    /// The bonus words found in this level, in the order they were found
+    pub fn words_for_level(&self, level: &DesignedLevel) -> &[String] {
+        self.words_by_level
+            .get(level.full_name().as_str())
+            .map(|words| words.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// The number of bonus words found in this level
+    pub fn count_for_level(&self, level: &DesignedLevel) -> usize {
+        self.words_for_level(level).len()
+    }
+
+    /// Check a finished selection against the dictionary.
+    /// Returns the characters if it is a bonus word which has not been found yet
+    pub fn find_bonus_word(&self, level: &DesignedLevel, solution: &[Tile]) -> Option<CharsArray> {
+        self.find_bonus_word_in(level, solution, &*BONUS_WORDS_DICTIONARY)
+    }
+
+    pub fn find_bonus_word_in(
+        &self,
+        level: &DesignedLevel,
+        solution: &[Tile],
+        dictionary: &impl Dictionary,
+    ) -> Option<CharsArray> {
+        if solution.len() < MIN_BONUS_WORD_LENGTH {
+            return None;
+        }
+        let characters = level.selection_characters(solution);
+
+        if level.words.iter().any(|word| word.characters == characters) {
+            return None;
+        }
+
+        if !dictionary.contains_characters(&characters) {
+            return None;
+        }
+
+        let text = characters.iter().map(|c| c.as_char()).join("");
+        if self.words_for_level(level).contains(&text) {
+            return None;
+        }
+
+        Some(characters)
+    }
+
+    /// Record a bonus word for this level
+    pub fn record(&mut self, level: &DesignedLevel, characters: &CharsArray) {
+        self.words_by_level
+            .entry(level.full_name().to_string())
+            .or_default()
+            .push(characters.iter().map(|c| c.as_char()).join(""));
+        self.total_found += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_dictionary_matches_word_list() {
+        let expected = WordAutomata::from_word_list(
+            include_str!("../../grid_finder/english_words.txt"),
+            MIN_BONUS_WORD_LENGTH,
+        );
+
+        assert!(BONUS_WORDS_DICTIONARY.iter().eq(expected.iter()));
+    }
+
+    #[test]
+    pub fn test_words_are_kept_for_each_level() {
+        /* spellchecker:disable */
+        let sports = DesignedLevel::from_tsv_line(
+            "ASHPKILOEUIOGNDT\tSports\tPOLO\tSHOOTING\tKENDO\tSAILING\tLUGE\tSKIING",
+        )
+        .unwrap();
+        let ireland = DesignedLevel::from_tsv_line(
+            "KGRROEAYDNLAUBIW\tCounties of Ireland\tDerry\tDonegal\tDublin\tGalway\tKerry",
+        )
+        .unwrap();
+        /* spellchecker:enable */
+        let sail = normalize_characters_array("Sail").unwrap();
+        let bury = normalize_characters_array("Bury").unwrap();
+
+        let mut state = BonusWordsState::default();
+        state.record(&sports, &sail);
+        state.record(&ireland, &bury);
+
+        assert_eq!(state.words_for_level(&sports), ["SAIL"]);
+        assert_eq!(state.words_for_level(&ireland), ["BURY"]);
+        assert_eq!(state.total_found, 2);
+    }
+}
@@ -89,6 +89,15 @@ fn handle_button_activations(
     daily_challenges: Res<DailyChallenges>,
     achievements: Res<AchievementsState>,
     mut level_time: ResMut<LevelTime>,
+    mut settings: (
+        ResMut<NudgeSettings>,
+        ResMut<NearMissSettings>,
+        ResMut<HardModeSettings>,
+        ResMut<ZenModeSettings>,
+        ResMut<TimeAttackState>,
+        ResMut<VersusSettings>,
+        ResMut<HintSettings>,
+    ),
 
     mut event_writers: (
         EventWriter<ChangeLevelEvent>,
@@ -111,6 +120,13 @@ fn handle_button_activations(
             &mut video_resource,
             daily_challenges.as_ref(),
             &mut level_time,
+            &mut settings.0,
+            &mut settings.1,
+            &mut settings.2,
+            &mut settings.3,
+            &mut settings.4,
+            &mut settings.5,
+            &mut settings.6,
             &purchases,
             &mut event_writers.0,
             &mut event_writers.1,
@@ -295,7 +311,7 @@ impl ButtonInteraction {
     fn on_activated(
         &self,
         current_level: &CurrentLevel,
-        _found_words: &FoundWordsState, //needed for share
+        found_words: &FoundWordsState,
         menu_state: &mut ResMut<MenuState>,
         popup_state: &mut ResMut<PopupState>,
 
@@ -305,6 +321,13 @@ impl ButtonInteraction {
 
         daily_challenges: &DailyChallenges,
         level_time: &mut ResMut<LevelTime>,
+        nudge_settings: &mut ResMut<NudgeSettings>,
+        near_miss_settings: &mut ResMut<NearMissSettings>,
+        hard_mode_settings: &mut ResMut<HardModeSettings>,
+        zen_mode_settings: &mut ResMut<ZenModeSettings>,
+        time_attack: &mut ResMut<TimeAttackState>,
+        versus_settings: &mut ResMut<VersusSettings>,
+        hint_settings: &mut ResMut<HintSettings>,
         purchases: &Purchases,
 
         change_level_events: &mut EventWriter<ChangeLevelEvent>,
@@ -357,6 +380,91 @@ impl ButtonInteraction {
             ButtonInteraction::SettingsMenu(SettingsLayoutEntity::RestorePurchases) => {
                 refresh_and_restore_events.send(RefreshAndRestoreEvent);
             }
+            ButtonInteraction::SettingsMenu(SettingsLayoutEntity::ToggleNudges) => {
+                nudge_settings.enabled = !nudge_settings.enabled;
+                if nudge_settings.enabled {
+                    crate::platform_specific::show_toast_sync("Hint nudges turned on");
+                } else {
+                    crate::platform_specific::show_toast_sync("Hint nudges turned off");
+                }
+            }
+            ButtonInteraction::SettingsMenu(SettingsLayoutEntity::ChangeNudgeTiming) => {
+                nudge_settings.cycle_timing();
+                crate::platform_specific::show_toast_sync(format!(
+                    "Nudges start after {} seconds stuck",
+                    nudge_settings.highlight_tile_seconds
+                ));
+            }
+            ButtonInteraction::SettingsMenu(SettingsLayoutEntity::ChangeHintType) => {
+                hint_settings.hint_type = hint_settings.hint_type.next();
+                let hint_type = hint_settings.hint_type;
+                crate::platform_specific::show_toast_sync(format!(
+                    "Hints now reveal the {} and cost {}",
+                    hint_type.name(),
+                    HintState::cost(hint_type)
+                ));
+            }
+            ButtonInteraction::SettingsMenu(SettingsLayoutEntity::ToggleNearMisses) => {
+                near_miss_settings.show_feedback = !near_miss_settings.show_feedback;
+                if near_miss_settings.show_feedback {
+                    crate::platform_specific::show_toast_sync("Near misses turned on");
+                } else {
+                    crate::platform_specific::show_toast_sync("Near misses turned off");
+                }
+            }
+            ButtonInteraction::SettingsMenu(SettingsLayoutEntity::ToggleHardMode) => {
+                hard_mode_settings.enabled = !hard_mode_settings.enabled;
+                if hard_mode_settings.enabled {
+                    crate::platform_specific::show_toast_sync("Hard mode on for new levels");
+                } else {
+                    crate::platform_specific::show_toast_sync("Hard mode off for new levels");
+                }
+            }
+            ButtonInteraction::SettingsMenu(SettingsLayoutEntity::ToggleZenMode) => {
+                zen_mode_settings.enabled = !zen_mode_settings.enabled;
+                if zen_mode_settings.enabled {
+                    crate::platform_specific::show_toast_sync("Zen mode on for new levels");
+                } else {
+                    crate::platform_specific::show_toast_sync("Zen mode off for new levels");
+                }
+            }
+            ButtonInteraction::SettingsMenu(SettingsLayoutEntity::TimeAttack) => {
+                if let Some(run) = time_attack.finish_run() {
+                    level_time.stop_time_attack(chrono::Utc::now());
+                    crate::platform_specific::show_toast_sync(format!(
+                        "Time attack ended with a score of {}",
+                        run.score()
+                    ));
+                } else {
+                    time_attack.start_run();
+                    menu_state.close();
+                    crate::platform_specific::show_toast_sync("Time attack started");
+                }
+            }
+            ButtonInteraction::SettingsMenu(SettingsLayoutEntity::EndlessMode) => {
+                let seed = new_endless_seed();
+                change_level_events.send(CurrentLevel::Endless { seed }.into());
+                menu_state.close();
+            }
+            ButtonInteraction::SettingsMenu(SettingsLayoutEntity::VersusPlayers) => {
+                versus_settings.cycle_players();
+                if versus_settings.players == 0 {
+                    crate::platform_specific::show_toast_sync("Versus mode off for new levels");
+                } else {
+                    crate::platform_specific::show_toast_sync(format!(
+                        "{} player versus for new levels",
+                        versus_settings.players
+                    ));
+                }
+            }
+            ButtonInteraction::SettingsMenu(SettingsLayoutEntity::ToggleTimedTurns) => {
+                versus_settings.timed_turns = !versus_settings.timed_turns;
+                if versus_settings.timed_turns {
+                    crate::platform_specific::show_toast_sync("Versus turns are timed");
+                } else {
+                    crate::platform_specific::show_toast_sync("Versus turns are one word each");
+                }
+            }
             ButtonInteraction::SettingsMenu(SettingsLayoutEntity::AdsConsent) => {
                 ad_request_events.send(AdRequestEvent::RequestConsent);
             }
@@ -408,9 +516,9 @@ impl ButtonInteraction {
             }
 
             ButtonInteraction::TimerButton => {
-                if level_time.is_paused() {
+                if level_time.is_stopped() {
                     level_time.as_mut().resume_timer();
-                } else if level_time.is_running() {
+                } else if level_time.is_ticking() {
                     level_time.as_mut().pause_timer();
                 }
             }
@@ -434,7 +542,26 @@ impl ButtonInteraction {
                 }
             },
             ButtonInteraction::WordButton(word) => {
-                hint_events.send(HintEvent { word_index: word.0 });
+                let hint_type = hint_settings.hint_type;
+                let is_found = found_words
+                    .word_completions
+                    .get(word.0)
+                    .is_some_and(|x| x.is_complete());
+
+                // Holding a word which is already found hints the most useful word instead
+                let event = match current_level.level(daily_challenges) {
+                    itertools::Either::Left(level) if is_found => {
+                        HintEvent::recommended(level, found_words, hint_type)
+                    }
+                    _ => Some(HintEvent {
+                        word_index: word.0,
+                        hint_type,
+                    }),
+                };
+
+                if let Some(event) = event {
+                    hint_events.send(event);
+                }
             }
 
             ButtonInteraction::ToggleRecordingButton => {
@@ -539,9 +666,27 @@ impl ButtonInteraction {
                                 .into(),
                             );
                         }
-                        NonLevel::DailyChallengeLoading { .. } => {
+                        NonLevel::DailyChallengeLoading { .. }
+                        | NonLevel::EndlessGenerating { .. } => {
                             //This button should not exist
                         }
+                        NonLevel::TimeAttackOver { .. } => {
+                            time_attack.start_run();
+                            let next_level = daily_challenge_completion
+                                .get_next_incomplete_daily_challenge_from_today(daily_challenges)
+                                .actual_level()
+                                .or_else(|| {
+                                    sequence_completion
+                                        .get_next_level_sequence(None, purchases)
+                                        .map(|(sequence, level_index)| CurrentLevel::Fixed {
+                                            level_index,
+                                            sequence,
+                                        })
+                                })
+                                .unwrap_or(CurrentLevel::NonLevel(NonLevel::DailyChallengeReset));
+
+                            change_level_events.send(next_level.into());
+                        }
                         NonLevel::AdBreak(..) => {}
                         NonLevel::AdFailed { next_level, since } => {
                             if since.is_none() {
@@ -615,7 +760,7 @@ impl ButtonInteraction {
                 if let Some(share_text) = try_generate_share_text(
                     current_level,
                     level_time.as_ref(),
-                    _found_words,
+                    found_words,
                     daily_challenges,
                 ) {
                     crate::wasm::share(share_text);
@@ -644,36 +789,3 @@ impl ButtonInteraction {
         }
     }
 }
-
-#[allow(dead_code)]
-
-fn try_generate_share_text(
-    current_level: &CurrentLevel,
-    time: &LevelTime,
-    found_words_state: &FoundWordsState,
-    daily_challenges: &DailyChallenges,
-) -> Option<String> {
-    let level = current_level.level(daily_challenges).left()?;
-    let first_lines = match level.numbering {
-        Some(Numbering::WordSaladNumber(num)) => format!("Word Salad #{num}\n{}", level.name),
-        Some(Numbering::SequenceNumber(..)) => level.full_name().to_string(),
-        None => level.full_name().to_string(),
-    };
-    let total_secs = time.total_elapsed().as_secs();
-    let minutes = total_secs / 60;
-    let seconds = total_secs % 60;
-    let hints = found_words_state.hints_used;
-    let second_line = format!("⌛{minutes}m {seconds}s, ❓{hints}");
-
-    let url = match current_level {
-        CurrentLevel::DailyChallenge { index } => {
-            format!("https://wordsalad.online/daily/{}", index + 1)
-        }
-        _ => {
-            //todo data for sequence levels?
-            "https://wordsalad.online/".to_string()
-        }
-    };
-
-    Some(format!("{url}\n{first_lines}\n{second_line}"))
-}
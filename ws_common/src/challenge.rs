@@ -0,0 +1,226 @@
+use std::time::Duration;
+
+use crate::prelude::*;
+
+pub struct ChallengePlugin;
+
+impl Plugin for ChallengePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ChallengeState>();
+
+        #[cfg(target_arch = "wasm32")]
+        app.add_systems(Startup, load_challenge_from_location);
+
+        app.add_systems(
+            Update,
+            attach_received_challenge.run_if(|s: Res<ChallengeState>| s.received.is_some()),
+        );
+    }
+}
+
+/// A challenge from a link the player opened
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceivedChallenge {
+    pub level: CurrentLevel,
+    pub challenge: Challenge,
+}
+
+/// A challenge waiting for its level to be started
+#[derive(Debug, Clone, Resource, PartialEq, Eq, Default)]
+pub struct ChallengeState {
+    pub received: Option<ReceivedChallenge>,
+}
+
+impl ReceivedChallenge {
+    /// Whether the challenge can be played on the current level
+    pub fn fits(&self, current_level: &CurrentLevel, found_words: &FoundWordsState) -> bool {
+        self.level == *current_level
+            && !found_words.is_level_complete()
+            && !found_words.zen_mode
+            && found_words.versus.is_none()
+            && self
+                .challenge
+                .fits_word_count(found_words.word_completions.len())
+    }
+}
+
+/// Read the level and challenge from a daily challenge or custom level link
+pub fn try_received_challenge_from_path(path: &str) -> Option<ReceivedChallenge> {
+    let challenge = Challenge::try_from_path(path)?;
+
+    let level = if let Some(index) = try_daily_index_from_path(path) {
+        CurrentLevel::DailyChallenge { index }
+    } else {
+        let level = DesignedLevel::try_from_path(path)?;
+        CurrentLevel::Custom {
+            name: level.full_name(),
+        }
+    };
+
+    Some(ReceivedChallenge { level, challenge })
+}
+
+/// The challenge for someone else to beat this result
+pub fn challenge_from_result(elapsed: Duration, found_words: &FoundWordsState) -> Challenge {
+    Challenge {
+        name: None,
+        seconds: elapsed.as_secs().try_into().unwrap_or(u32::MAX),
+        hints_used: found_words.hints_used.try_into().unwrap_or(u8::MAX),
+        found_order: found_order(found_words),
+    }
+}
+
+/// For each word, the position it was found in
+pub fn found_order(found_words: &FoundWordsState) -> Vec<Option<u8>> {
+    found_words
+        .word_completions
+        .iter()
+        .map(|completion| match completion {
+            Completion::Complete { index, .. } => Some(*index),
+            _ => None,
+        })
+        .collect()
+}
+
+pub fn rival_name(challenge: &Challenge) -> &str {
+    challenge.name.as_deref().unwrap_or("your rival")
+}
+
+/// The text shown while playing a level from a challenge link
+pub fn challenge_banner_text(challenge: &Challenge) -> String {
+    format!(
+        "Beat {}'s {}",
+        rival_name(challenge),
+        format_seconds(challenge.seconds as u64)
+    )
+}
+
+/// Give the challenge to the level once it has started
+fn attach_received_challenge(
+    mut state: ResMut<ChallengeState>,
+    current_level: Res<CurrentLevel>,
+    mut found_words: ResMut<FoundWordsState>,
+) {
+    let Some(received) = state.received.as_ref() else {
+        return;
+    };
+    if !received.fits(&current_level, &found_words) {
+        return;
+    }
+
+    if let Some(received) = state.received.take() {
+        found_words.challenge = Some(received.challenge);
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn load_challenge_from_location(mut state: ResMut<ChallengeState>) {
+    if let Some(received) = crate::wasm::get_challenge_from_location() {
+        info!("Loaded challenge from path");
+        state.received = Some(received);
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    /* spellchecker:disable */
+    const SPORTS: &str = "ASHPKILOEUIOGNDT\tSports\tPOLO\tSHOOTING\tKENDO\tSAILING\tLUGE\tSKIING";
+
+    fn challenge() -> Challenge {
+        Challenge {
+            name: Some("Alex".to_string()),
+            seconds: 83,
+            hints_used: 1,
+            found_order: vec![Some(0), Some(1), Some(2), Some(3), Some(4), Some(5)],
+        }
+    }
+
+    #[test]
+    pub fn test_custom_level_challenge() {
+        let level = DesignedLevel::from_tsv_line(SPORTS).unwrap();
+        let path = challenge()
+            .to_path(format!("/game/{}", level.share_data()).as_str())
+            .unwrap();
+
+        let received = try_received_challenge_from_path(&path).unwrap();
+        assert_eq!(
+            received.level,
+            CurrentLevel::Custom {
+                name: level.full_name()
+            }
+        );
+        assert_eq!(received.challenge, challenge());
+        assert_eq!(
+            challenge_banner_text(&received.challenge),
+            "Beat Alex's 01:23"
+        );
+    }
+
+    #[test]
+    pub fn test_daily_challenge() {
+        let path = challenge().to_path("/daily/1").unwrap();
+
+        let received = try_received_challenge_from_path(&path).unwrap();
+        assert_eq!(received.level, CurrentLevel::DailyChallenge { index: 0 });
+        assert_eq!(try_daily_index_from_path(&path), Some(0));
+    }
+
+    #[test]
+    pub fn test_challenge_is_attached_to_level() {
+        let level = DesignedLevel::from_tsv_line(SPORTS).unwrap();
+        let current_level = CurrentLevel::DailyChallenge { index: 0 };
+
+        let mut app = App::new();
+        app.insert_resource(CurrentLevel::DailyChallenge { index: 1 });
+        app.insert_resource(FoundWordsState::new_from_level(&level));
+        app.insert_resource(ChallengeState {
+            received: Some(ReceivedChallenge {
+                level: current_level.clone(),
+                challenge: challenge(),
+            }),
+        });
+        app.add_plugins(ChallengePlugin);
+
+        app.update();
+        assert_eq!(app.world.resource::<FoundWordsState>().challenge, None);
+
+        app.insert_resource(current_level);
+        app.update();
+        assert_eq!(
+            app.world.resource::<FoundWordsState>().challenge,
+            Some(challenge())
+        );
+        assert_eq!(app.world.resource::<ChallengeState>().received, None);
+    }
+
+    #[test]
+    pub fn test_zen_mode_levels_are_not_challenged() {
+        let level = DesignedLevel::from_tsv_line(SPORTS).unwrap();
+        let received = ReceivedChallenge {
+            level: CurrentLevel::DailyChallenge { index: 0 },
+            challenge: challenge(),
+        };
+        let found_words = FoundWordsState::new_from_level(&level);
+
+        assert!(received.fits(&received.level, &found_words));
+        assert!(!received.fits(&received.level, &found_words.with_zen_mode(true)));
+    }
+
+    #[test]
+    pub fn test_challenge_from_result() {
+        let level = DesignedLevel::from_tsv_line(SPORTS).unwrap();
+        let mut found_words = FoundWordsState::new_level_complete(&level, 3);
+        found_words.word_completions[0] = Completion::Complete {
+            index: 5,
+            player: None,
+        };
+
+        let challenge = challenge_from_result(Duration::from_secs(61), &found_words);
+        assert_eq!(challenge.seconds, 61);
+        assert_eq!(challenge.hints_used, 3);
+        assert_eq!(challenge.found_order[0], Some(5));
+        assert_eq!(challenge.found_order.len(), level.words.len());
+    }
+}
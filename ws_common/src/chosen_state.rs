@@ -26,25 +26,40 @@ impl ChosenState {
         level: &DesignedLevel,
         found_words: &FoundWordsState,
     ) -> bool {
+        self.close_word_index(level, found_words).is_some()
+    }
+
+    /// The index of the first unfound word that the selection is close to but not equal to
+    pub fn close_word_index(
+        &self,
+        level: &DesignedLevel,
+        found_words: &FoundWordsState,
+    ) -> Option<usize> {
         let solution = self.current_solution();
         if solution.len() < 5 {
-            return false;
+            return None;
         }
 
-        let chars: CharsArray = solution.iter().map(|t| level.grid[*t]).collect();
+        let chars = level.selection_characters(solution);
 
-        for (word, completion) in level.words.iter().zip(found_words.word_completions.iter()) {
+        for (word_index, (word, completion)) in level
+            .words
+            .iter()
+            .zip(found_words.word_completions.iter())
+            .enumerate()
+        {
             if completion.is_complete() {
                 continue;
             }
             if word.characters.first() == chars.first()
+                && word.characters != chars
                 && Self::lev_distance_one_or_less(&chars, &word.characters)
             {
-                return true;
+                return Some(word_index);
             }
         }
 
-        false
+        None
     }
 
     fn lev_distance_one_or_less(l_chars: &CharsArray, r_chars: &CharsArray) -> bool {
@@ -127,4 +142,40 @@ pub mod tests {
             assert!(!actual, "Should not return true")
         }
     }
+
+    #[test]
+    fn test_close_word_index() {
+        let level = DesignedLevel::from_tsv_line(
+            // spellchecker:disable-next-line
+            "ASHPKILOEUIOGNDT\tSports\tPOLO\tSHOOTING\tKENDO\tSAILING\tLUGE\tSKIING",
+        )
+        .unwrap();
+        let found_words = FoundWordsState::new_from_level(&level);
+        let shooting = level
+            .words
+            .iter()
+            .position(|x| x.text.as_str().eq_ignore_ascii_case("shooting"));
+
+        let mut chosen_state = ChosenState {
+            solution: Solution::from_iter([
+                Tile::new_const::<1, 0>(),
+                Tile::new_const::<2, 0>(),
+                Tile::new_const::<3, 1>(),
+                Tile::new_const::<3, 2>(),
+                Tile::new_const::<3, 3>(),
+            ]),
+            is_just_finished: false,
+        };
+
+        assert_eq!(chosen_state.close_word_index(&level, &found_words), None);
+
+        chosen_state.solution.push(Tile::new_const::<2, 2>());
+        chosen_state.solution.push(Tile::new_const::<1, 3>());
+
+        assert_eq!(
+            chosen_state.close_word_index(&level, &found_words),
+            shooting
+        );
+        assert!(chosen_state.is_close_to_a_solution(&level, &found_words));
+    }
 }
@@ -1,3 +1,5 @@
+use std::collections::BTreeSet;
+
 use bevy::{prelude::*, utils::HashMap};
 use maveric::helpers::MavericContext;
 use nice_bevy_utils::TrackableResource;
@@ -14,6 +16,9 @@ use crate::{
 #[derive(Debug, PartialEq, Serialize, Deserialize, Default, Clone, Resource, MavericContext)]
 pub struct SequenceCompletion {
     pub completions: HashMap<LevelSequence, LevelCompletion>,
+    /// The indices of the levels in each sequence which have been completed in hard mode
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub hard_mode_completions: HashMap<LevelSequence, BTreeSet<usize>>,
 }
 
 impl TrackableResource for SequenceCompletion {
@@ -38,6 +43,12 @@ pub struct LevelResult {
 #[derive(Debug, PartialEq, Serialize, Deserialize, Default, Clone, Resource, MavericContext)]
 pub struct DailyChallengeCompletion {
     pub results: HashMap<usize, LevelResult>,
+    /// Results for daily challenges completed in hard mode, outside of zen mode
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub hard_mode_results: HashMap<usize, LevelResult>,
+    /// The hints used for daily challenges completed in zen mode, which are not timed
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub zen_mode_results: HashMap<usize, u32>,
 }
 
 impl TrackableResource for DailyChallengeCompletion {
@@ -100,6 +111,33 @@ impl SequenceCompletion {
         }
     }
 
+    /// Record that a level was completed.
+    /// Returns whether this was the first time it was completed
+    pub fn record_completion(
+        &mut self,
+        sequence: LevelSequence,
+        level_index: usize,
+        hard_mode: bool,
+    ) -> bool {
+        let number_complete = level_index + 1;
+
+        if hard_mode {
+            self.hard_mode_completions
+                .entry(sequence)
+                .or_default()
+                .insert(level_index);
+        }
+
+        let completion = self.completions.entry(sequence).or_default();
+        completion.current_index += 1;
+        if completion.total_complete < number_complete {
+            completion.total_complete = number_complete;
+            true
+        } else {
+            false
+        }
+    }
+
     pub fn restart_level_sequence_completion(&mut self, sequence: LevelSequence) {
         self.completions.entry(sequence).or_default().current_index = 0;
     }
@@ -141,6 +179,17 @@ impl SequenceCompletion {
             .map(|x| self.get_number_complete(x))
             .sum()
     }
+
+    pub fn get_number_complete_hard_mode(&self, sequence: &LevelSequence) -> usize {
+        self.hard_mode_completions
+            .get(sequence)
+            .map(|x| x.len())
+            .unwrap_or_default()
+    }
+
+    pub fn get_total_complete_hard_mode(&self) -> usize {
+        self.hard_mode_completions.values().map(|x| x.len()).sum()
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -187,11 +236,13 @@ impl From<NextDailyChallengeResult> for CurrentLevel {
 impl DailyChallengeCompletion {
     pub fn reset_daily_challenge_completion(&mut self) {
         self.results.clear();
+        self.hard_mode_results.clear();
+        self.zen_mode_results.clear();
         //keep total completions
     }
 
     pub fn is_daily_challenge_complete(&self, index: usize) -> bool {
-        self.results.contains_key(&index)
+        self.results.contains_key(&index) || self.zen_mode_results.contains_key(&index)
     }
 
     pub fn get_next_incomplete_daily_challenge_from_today(
@@ -215,7 +266,7 @@ impl DailyChallengeCompletion {
         }
 
         loop {
-            if !self.results.contains_key(&current_index) {
+            if !self.is_daily_challenge_complete(current_index) {
                 return NextDailyChallengeResult::Level(current_index);
             }
             match current_index.checked_sub(1) {
@@ -227,6 +278,15 @@ impl DailyChallengeCompletion {
 
     pub fn get_daily_challenges_complete(&self) -> usize {
         self.results.len()
+            + self
+                .zen_mode_results
+                .keys()
+                .filter(|index| !self.results.contains_key(index))
+                .count()
+    }
+
+    pub fn get_daily_challenges_complete_hard_mode(&self) -> usize {
+        self.hard_mode_results.len()
     }
 }
 
@@ -256,22 +316,14 @@ pub fn track_level_completion(
             level_index,
             sequence,
         } => {
-            let number_complete = level_index + 1;
-
-            let completion = sequence_completion
-                .completions
-                .entry(*sequence)
-                .or_default();
-            completion.current_index += 1;
-            if completion.total_complete < number_complete {
-                first_time = true;
-                completion.total_complete = number_complete;
+            first_time = sequence_completion.record_completion(
+                *sequence,
+                *level_index,
+                found_words.hard_mode,
+            );
 
-                if completion.total_complete % 5 == 0 {
-                    crate::platform_specific::request_review();
-                }
-            } else {
-                first_time = false;
+            if first_time && (level_index + 1) % 5 == 0 {
+                crate::platform_specific::request_review();
             }
         }
 
@@ -280,6 +332,10 @@ pub fn track_level_completion(
             crate::platform_specific::request_review();
             first_time = false;
         }
+        CurrentLevel::Endless { .. } => {
+            // Every endless level is new
+            first_time = true;
+        }
         CurrentLevel::Tutorial { index } => {
             const TUTORIAL_LEVEL_COUNT: usize = 2;
 
@@ -296,7 +352,7 @@ pub fn track_level_completion(
         }
 
         CurrentLevel::DailyChallenge { index } => {
-            if !daily_challenge_completion.results.contains_key(index) {
+            if !daily_challenge_completion.is_daily_challenge_complete(*index) {
                 first_time = true;
                 // daily_challenge_completion
                 //     .total_completion
@@ -311,10 +367,12 @@ pub fn track_level_completion(
                         info!("Streak increased by one");
                         streak.current += 1;
 
-                        crate::platform_specific::submit_score(SubmitScoreData {
-                            leaderboard_id: "Word_Salad_Daily_Challenge".to_string(),
-                            total_score_amount: level_time.total_elapsed().as_secs() as i32,
-                        });
+                        if !found_words.zen_mode && found_words.versus.is_none() {
+                            crate::platform_specific::submit_score(SubmitScoreData {
+                                leaderboard_id: "Word_Salad_Daily_Challenge".to_string(),
+                                total_score_amount: level_time.total_elapsed().as_secs() as i32,
+                            });
+                        }
 
                         #[cfg(feature = "web")]
                         {
@@ -342,13 +400,29 @@ pub fn track_level_completion(
             } else {
                 first_time = false;
             }
-            daily_challenge_completion.results.insert(
-                *index,
-                LevelResult {
-                    seconds: level_time.total_elapsed().as_secs() as u32,
-                    hints_used: found_words.hints_used as u32,
-                },
-            );
+            let result = LevelResult {
+                seconds: level_time.total_elapsed().as_secs() as u32,
+                hints_used: found_words.hints_used as u32,
+            };
+            if found_words.zen_mode {
+                daily_challenge_completion
+                    .zen_mode_results
+                    .insert(*index, result.hints_used);
+            } else {
+                daily_challenge_completion.results.insert(*index, result);
+            }
+
+            if found_words.hard_mode && !found_words.zen_mode && found_words.versus.is_none() {
+                let previous = daily_challenge_completion
+                    .hard_mode_results
+                    .insert(*index, result);
+                if previous.is_none() && *index == DailyChallenges::get_today_index() {
+                    crate::platform_specific::submit_score(SubmitScoreData {
+                        leaderboard_id: "Word_Salad_Daily_Challenge_Hard".to_string(),
+                        total_score_amount: result.seconds as i32,
+                    });
+                }
+            }
         }
         CurrentLevel::NonLevel(..) => first_time = false,
     }
@@ -380,6 +454,7 @@ pub fn track_level_completion(
 pub mod test {
 
     use crate::prelude::*;
+    use ws_levels::level_sequence::LevelSequence;
 
     #[test]
     pub fn test_daily_challenge_completion_serde() {
@@ -458,4 +533,55 @@ pub mod test {
             completion.get_next_incomplete_daily_challenge(3, &daily_challenges)
         );
     }
+
+    #[test]
+    pub fn test_zen_mode_daily_challenge_completion() {
+        let mut completion = DailyChallengeCompletion::default();
+        let mut daily_challenges = DailyChallenges::default();
+        daily_challenges.levels = Some(vec![DesignedLevel::unknown(); 2]);
+
+        completion.zen_mode_results.insert(1, 3);
+
+        assert!(completion.is_daily_challenge_complete(1));
+        assert_eq!(completion.get_daily_challenges_complete(), 1);
+        assert_eq!(
+            NextDailyChallengeResult::Level(0),
+            completion.get_next_incomplete_daily_challenge(1, &daily_challenges)
+        );
+
+        completion.results.insert(
+            0,
+            LevelResult {
+                seconds: 10,
+                hints_used: 0,
+            },
+        );
+        assert_eq!(completion.get_daily_challenges_complete(), 2);
+        assert_eq!(
+            NextDailyChallengeResult::AllFinished,
+            completion.get_next_incomplete_daily_challenge(1, &daily_challenges)
+        );
+
+        completion.reset_daily_challenge_completion();
+        assert_eq!(completion.get_daily_challenges_complete(), 0);
+    }
+
+    #[test]
+    pub fn test_hard_mode_sequence_completion() {
+        let mut completion = SequenceCompletion::default();
+        let sequence = LevelSequence::FIRST;
+
+        for level_index in 0..19 {
+            assert!(completion.record_completion(sequence, level_index, false));
+        }
+        assert!(completion.record_completion(sequence, 19, true));
+
+        assert_eq!(completion.get_number_complete(&sequence), 20);
+        assert_eq!(completion.get_number_complete_hard_mode(&sequence), 1);
+
+        assert!(!completion.record_completion(sequence, 19, true));
+        assert!(!completion.record_completion(sequence, 3, true));
+        assert_eq!(completion.get_number_complete_hard_mode(&sequence), 2);
+        assert_eq!(completion.get_total_complete_hard_mode(), 2);
+    }
 }
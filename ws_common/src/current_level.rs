@@ -5,7 +5,9 @@ use serde::{Deserialize, Serialize};
 use std::sync::RwLock;
 use strum::EnumIs;
 use ws_core::level_type::LevelType;
-use ws_levels::{all_levels::get_tutorial_level, level_sequence::LevelSequence};
+use ws_levels::{
+    all_levels::get_tutorial_level, endless::next_endless_seed, level_sequence::LevelSequence,
+};
 
 use crate::{
     completion::{DailyChallengeCompletion, SequenceCompletion},
@@ -27,6 +29,10 @@ pub enum CurrentLevel {
     Custom {
         name: Ustr,
     },
+    /// A level generated on the device from a seed
+    Endless {
+        seed: u64,
+    },
     NonLevel(NonLevel),
 }
 
@@ -56,6 +62,16 @@ pub enum NonLevel {
         next_level: NextLevel,
         since: Option<DateTime<Utc>>,
     },
+
+    TimeAttackOver {
+        run: TimeAttackRun,
+        /// The best score from before this run
+        best_score: usize,
+    },
+
+    EndlessGenerating {
+        seed: u64,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, EnumIs)]
@@ -67,6 +83,9 @@ pub enum NextLevel {
         sequence: LevelSequence,
         level_index: usize,
     },
+    Endless {
+        seed: u64,
+    },
 }
 
 impl From<NextLevel> for CurrentLevel {
@@ -80,6 +99,7 @@ impl From<NextLevel> for CurrentLevel {
                 level_index,
                 sequence,
             },
+            NextLevel::Endless { seed } => CurrentLevel::Endless { seed },
         }
     }
 }
@@ -101,6 +121,7 @@ impl<'a> TryFrom<&'a CurrentLevel> for NextLevel {
                 Ok(NextLevel::DailyChallenge { index: *index })
             }
             CurrentLevel::Custom { .. } => Err(()),
+            CurrentLevel::Endless { seed } => Ok(NextLevel::Endless { seed: *seed }),
             CurrentLevel::NonLevel(_) => Err(()),
         }
     }
@@ -144,6 +165,7 @@ impl CurrentLevel {
             CurrentLevel::Fixed { .. } => true,
             CurrentLevel::DailyChallenge { .. } => true,
             CurrentLevel::Custom { .. } => true,
+            CurrentLevel::Endless { .. } => true,
             CurrentLevel::NonLevel(_) => false,
         }
     }
@@ -161,6 +183,7 @@ impl CurrentLevel {
             }
             CurrentLevel::DailyChallenge { .. } => true,
             CurrentLevel::Custom { .. } => false,
+            CurrentLevel::Endless { .. } => true,
             CurrentLevel::NonLevel(_) => false,
         }
     }
@@ -171,6 +194,7 @@ impl CurrentLevel {
             CurrentLevel::Fixed { .. } => LevelType::Fixed,
             CurrentLevel::DailyChallenge { .. } => LevelType::DailyChallenge,
             CurrentLevel::Custom { .. } => LevelType::Custom,
+            CurrentLevel::Endless { .. } => LevelType::Endless,
             CurrentLevel::NonLevel(_) => LevelType::NonLevel,
         }
     }
@@ -193,6 +217,10 @@ impl CurrentLevel {
                 },
                 Err(_) => Either::Right(NonLevel::AfterCustomLevel),
             },
+            CurrentLevel::Endless { seed } => match daily_challenges.endless_level(*seed) {
+                Some(level) => Either::Left(level),
+                None => Either::Right(NonLevel::EndlessGenerating { seed: *seed }),
+            },
             CurrentLevel::Tutorial { index } => match get_tutorial_level(*index) {
                 Some(cl) => Either::Left(cl),
                 None => Either::Right(NonLevel::BeforeTutorial),
@@ -232,6 +260,9 @@ impl CurrentLevel {
                 .get_next_incomplete_daily_challenge_from_today(daily_challenges)
                 .into(),
             CurrentLevel::Custom { .. } => NonLevel::AfterCustomLevel.into(),
+            CurrentLevel::Endless { seed } => CurrentLevel::Endless {
+                seed: next_endless_seed(*seed),
+            },
             CurrentLevel::NonLevel(x) => (*x).into(), //No change
         }
     }
@@ -36,6 +36,10 @@ pub struct DailyChallenges {
     level_data: Option<String>,
     #[serde(skip)]
     pub levels: Option<Vec<DesignedLevel>>,
+    /// The most recently generated endless level and its seed.
+    /// It lives here because every level lookup already has the daily challenges.
+    #[serde(skip)]
+    pub endless_level: Option<(u64, DesignedLevel)>,
 }
 
 impl DailyChallenges {
@@ -45,6 +49,14 @@ impl DailyChallenges {
             None => (*DAILY_CHALLENGE_NUMBERED).as_slice(),
         }
     }
+
+    /// The generated endless level for this seed, if it has been generated
+    pub fn endless_level(&self, seed: u64) -> Option<&DesignedLevel> {
+        match &self.endless_level {
+            Some((level_seed, level)) if *level_seed == seed => Some(level),
+            _ => None,
+        }
+    }
 }
 
 impl TrackableResource for DailyChallenges {
@@ -141,6 +153,7 @@ fn load_levels(writer: AsyncEventWriter<DailyChallengeDataLoadedEvent>, dc: Res<
 
 pub fn try_daily_index_from_path(mut path: &str) -> Option<usize> {
     path = path.trim_start_matches("https://wordsalad.online");
+    path = split_challenge_path(path).0;
     //info!("{path}");
     if path.is_empty() || path.eq_ignore_ascii_case("/") {
         return None;
@@ -0,0 +1,162 @@
+use crate::prelude::*;
+use chrono::{DateTime, Utc};
+use ws_levels::endless::{next_endless_seed, EndlessGenerator, EndlessStep};
+
+pub struct EndlessPlugin;
+
+impl Plugin for EndlessPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            generate_endless_level.run_if(|c: Res<CurrentLevel>, d: Res<DailyChallenges>| {
+                c.level(&d)
+                    .right()
+                    .is_some_and(|x| x.is_endless_generating())
+            }),
+        );
+    }
+}
+
+/// Milliseconds spent generating each frame, so the game stays responsive without threads.
+/// At least one attempt is made every frame, however long it takes.
+const FRAME_MILLIS: i64 = 8;
+
+/// A seed for a new endless run
+pub fn new_endless_seed() -> u64 {
+    Utc::now().timestamp_millis() as u64
+}
+
+pub fn try_endless_seed_from_path(mut path: &str) -> Option<u64> {
+    path = path.trim_start_matches("https://wordsalad.online");
+
+    if path.to_ascii_lowercase().starts_with("/endless/") {
+        return path[9..].trim().parse::<u64>().ok();
+    }
+    None
+}
+
+fn generate_endless_level(
+    mut current_level: ResMut<CurrentLevel>,
+    mut generator: Local<Option<EndlessGenerator>>,
+    mut daily_challenges: ResMut<DailyChallenges>,
+    mut found_words: ResMut<FoundWordsState>,
+    mut level_time: ResMut<LevelTime>,
+    mut change_level_events: EventWriter<ChangeLevelEvent>,
+    modes: (
+        Res<HardModeSettings>,
+        Res<ZenModeSettings>,
+        Res<VersusSettings>,
+    ),
+) {
+    let CurrentLevel::Endless { seed } = *current_level else {
+        return;
+    };
+
+    if generator.as_ref().map(|x| x.seed()) != Some(seed) {
+        *generator = Some(EndlessGenerator::new(seed));
+    }
+
+    let Some(generation) = generator.as_mut() else {
+        return;
+    };
+
+    let deadline: DateTime<Utc> =
+        Utc::now() + chrono::Duration::try_milliseconds(FRAME_MILLIS).unwrap_or_default();
+
+    loop {
+        match generation.step() {
+            EndlessStep::Generated(level) => {
+                // A level being resumed after a restart already has the right number of words
+                if found_words.word_completions.len() != level.words.len() {
+                    *found_words = FoundWordsState::new_from_level(&level)
+                        .with_hard_mode(modes.0.enabled)
+                        .with_zen_mode(modes.1.enabled)
+                        .with_versus(modes.2.rules());
+                    *level_time = LevelTime::default();
+                }
+                daily_challenges.endless_level = Some((seed, level));
+                current_level.set_changed();
+                *generator = None;
+                return;
+            }
+            EndlessStep::Pending => {
+                if Utc::now() >= deadline {
+                    return;
+                }
+            }
+            EndlessStep::Failed => {
+                warn!("Could not generate an endless level from seed {seed}");
+                *generator = None;
+                change_level_events.send(
+                    CurrentLevel::Endless {
+                        seed: next_endless_seed(seed),
+                    }
+                    .into(),
+                );
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use itertools::Either;
+
+    #[test]
+    pub fn test_seed_from_path() {
+        assert_eq!(
+            try_endless_seed_from_path("https://wordsalad.online/endless/12345"),
+            Some(12345)
+        );
+        assert_eq!(try_endless_seed_from_path("/Endless/7"), Some(7));
+        assert_eq!(try_endless_seed_from_path("/daily/7"), None);
+        assert_eq!(try_endless_seed_from_path("/endless/abc"), None);
+    }
+
+    #[test]
+    pub fn test_level_is_generated_across_frames() {
+        const SEED: u64 = 31415;
+        let mut app = App::new();
+
+        app.insert_resource(CurrentLevel::Endless { seed: SEED });
+        app.insert_resource(DailyChallenges::default());
+        app.insert_resource(FoundWordsState::default());
+        app.insert_resource(LevelTime::default());
+        app.insert_resource(HardModeSettings::default());
+        app.insert_resource(ZenModeSettings::default());
+        app.insert_resource(VersusSettings::default());
+        app.add_event::<ChangeLevelEvent>();
+        app.add_plugins(EndlessPlugin);
+
+        let mut frames = 0;
+        while app
+            .world
+            .resource::<CurrentLevel>()
+            .level(app.world.resource::<DailyChallenges>())
+            .is_right()
+        {
+            frames += 1;
+            assert!(frames < 10_000, "Level should be generated");
+            app.update();
+        }
+
+        let Either::Left(level) = app
+            .world
+            .resource::<CurrentLevel>()
+            .level(app.world.resource::<DailyChallenges>())
+        else {
+            unreachable!()
+        };
+
+        assert_eq!(Some(level), EndlessGenerator::generate(SEED).as_ref());
+        assert_eq!(
+            app.world
+                .resource::<FoundWordsState>()
+                .word_completions
+                .len(),
+            level.words.len()
+        );
+    }
+}
@@ -7,6 +7,8 @@ pub struct GridInputState {
     last_tile: Option<Tile>,
     multi_click: Option<MultiClick>,
     last_truncate: Option<Tile>,
+    /// The last selection which was reported as a near miss
+    last_near_miss: Option<Solution>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -164,6 +166,15 @@ impl GridInputState {
         self.last_tile = None;
     }
 
+    /// Whether this selection is a near miss which has not already been reported
+    pub fn is_new_near_miss(&mut self, solution: &Solution) -> bool {
+        if self.last_near_miss.as_ref() == Some(solution) {
+            return false;
+        }
+        self.last_near_miss = Some(solution.clone());
+        true
+    }
+
     pub fn handle_input_end_no_location(&mut self) {
         //info!("hie no location");
         self.last_tile = None;
@@ -218,19 +229,101 @@ pub mod tests {
         let grid = Grid::from_fn(|_| Character::A);
 
         for input in input_list.into_iter() {
-            match input {
-                Input::EndNoLocation => state.handle_input_end_no_location(),
-                Input::Start(tile) => {
-                    state.handle_input_start(&mut chosen_state, tile, &grid, &found_words)
-                }
-                Input::Move(tile) => {
-                    state.handle_input_move(&mut chosen_state, tile, &grid, &found_words)
+            handle_input(&mut state, &mut chosen_state, input, &grid, &found_words);
+        }
+
+        assert_eq!(chosen_state.solution, expected);
+    }
+
+    /* spellchecker:disable */
+    const SPORTS: &str = "ASHPKILOEUIOGNDT\tSports\tPOLO\tSHOOTING\tKENDO\tSAILING\tLUGE\tSKIING";
+
+    #[test_case("", "")]
+    #[test_case("s10 m00 m11 m21 e21", "SAIL")]
+    #[test_case("s10 m00 m11 m21 e", "SAIL")]
+    #[test_case("s10 m00 m11 e11", "")]
+    #[test_case("s10 m00 m11 m21", "")]
+    #[test_case("s30 m31 m21 m32 e32", "")]
+    #[test_case("s31 m22 m21 m10 e10", "OILS")]
+    #[test_case("s10 m00 m11 m21 e21 s03 e03 s30 m31 m32 m21 e21", "SAIL POOL")]
+    #[test_case("s10 m00 m11 m21 e21 m11 e11 m21 e21", "SAIL")]
+    pub fn test_bonus_words(input: &str, expected: &str) {
+        let level = DesignedLevel::from_tsv_line(SPORTS).unwrap();
+        let input_list = parse_input_list(input);
+
+        let mut state = GridInputState::default();
+        let mut bonus_words = BonusWordsState::default();
+        let found_words = FoundWordsState::new_from_level(&level);
+
+        let mut chosen_state = TestResMut {
+            value: &mut ChosenState::default(),
+            added: false,
+            last_changed: None,
+        };
+
+        for input in input_list.into_iter() {
+            handle_input(
+                &mut state,
+                &mut chosen_state,
+                input,
+                &level.grid,
+                &found_words,
+            );
+
+            if matches!(input, Input::End(_) | Input::EndNoLocation) {
+                if let Some(word) =
+                    bonus_words.find_bonus_word(&level, chosen_state.current_solution())
+                {
+                    bonus_words.record(&level, &word);
                 }
-                Input::End(tile) => state.handle_input_end(&mut chosen_state, tile),
             }
         }
 
-        assert_eq!(chosen_state.solution, expected);
+        assert_eq!(bonus_words.words_for_level(&level).join(" "), expected);
+        assert_eq!(bonus_words.count_for_level(&level), bonus_words.total_found);
+    }
+
+    #[test]
+    pub fn test_near_miss_is_reported_once() {
+        let level = DesignedLevel::from_tsv_line(SPORTS).unwrap();
+        let mut state = GridInputState::default();
+        let found_words = FoundWordsState::new_from_level(&level);
+
+        let mut chosen_state = TestResMut {
+            value: &mut ChosenState::default(),
+            added: false,
+            last_changed: None,
+        };
+
+        for input in parse_input_list("s10 m00 m11 m21 m22 m13 e13") {
+            handle_input(
+                &mut state,
+                &mut chosen_state,
+                input,
+                &level.grid,
+                &found_words,
+            );
+        }
+
+        let solution = chosen_state.current_solution().clone();
+        assert_eq!(chosen_state.close_word_index(&level, &found_words), Some(3));
+        assert!(state.is_new_near_miss(&solution));
+        assert!(!state.is_new_near_miss(&solution));
+    }
+
+    fn handle_input(
+        state: &mut GridInputState,
+        chosen_state: &mut TestResMut<ChosenState>,
+        input: Input,
+        grid: &Grid,
+        found_words: &FoundWordsState,
+    ) {
+        match input {
+            Input::EndNoLocation => state.handle_input_end_no_location(),
+            Input::Start(tile) => state.handle_input_start(chosen_state, tile, grid, found_words),
+            Input::Move(tile) => state.handle_input_move(chosen_state, tile, grid, found_words),
+            Input::End(tile) => state.handle_input_end(chosen_state, tile),
+        }
     }
 
     fn parse_input_list(input: &str) -> Vec<Input> {
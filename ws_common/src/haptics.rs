@@ -6,6 +6,7 @@ pub enum HapticEvent {
     UseHint,
     FinishWord,
     FinishPuzzle,
+    NearMiss,
 }
 
 impl HapticEvent {
@@ -33,6 +34,13 @@ impl HapticEvent {
                         }),
                     );
                 }
+                HapticEvent::NearMiss => {
+                    crate::logging::do_or_report_error(
+                        capacitor_bindings::haptics::Haptics::impact(ImpactOptions {
+                            style: ImpactStyle::Medium,
+                        }),
+                    );
+                }
                 HapticEvent::FinishPuzzle => {
                     crate::logging::do_or_report_error(
                         capacitor_bindings::haptics::Haptics::impact(ImpactOptions {
@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use nice_bevy_utils::{CanInitTrackedResource, TrackableResource};
+use serde::{Deserialize, Serialize};
+
+pub struct HardModePlugin;
+
+impl Plugin for HardModePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_tracked_resource::<HardModeSettings>();
+    }
+}
+
+/// Hard mode turns off falling tiles, inadvisable tile shading and word lengths.
+/// It applies to levels started after it is changed.
+#[derive(
+    Debug, Clone, Resource, Serialize, Deserialize, MavericContext, PartialEq, Eq, Default,
+)]
+pub struct HardModeSettings {
+    pub enabled: bool,
+}
+
+impl TrackableResource for HardModeSettings {
+    const KEY: &'static str = "HardModeSettings";
+}
@@ -335,6 +335,8 @@ impl InputType {
         time: &Time,
         user_signed_in: &UserSignedIn,
         insets: &InsetsResource,
+        bonus_words: &mut ResMut<BonusWordsState>,
+        near_miss_events: &mut EventWriter<NearMissEvent>,
     ) {
         startup::ADDITIONAL_TRACKING.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
 
@@ -352,6 +354,8 @@ impl InputType {
             }
         };
 
+        let mut ended_on_grid = false;
+
         let button_interaction: Option<ButtonInteraction> = match self {
             InputType::Start(position) => {
                 if let Some(interaction) = InteractionEntity::try_find(
@@ -363,7 +367,7 @@ impl InputType {
                     daily_challenges,
                     video_resource,
                     is_level_complete,
-                    timer.is_paused(),
+                    timer.is_stopped(),
                     None,
                     user_signed_in,
                     insets.0,
@@ -396,7 +400,7 @@ impl InputType {
                     daily_challenges,
                     video_resource,
                     is_level_complete,
-                    timer.is_paused(),
+                    timer.is_stopped(),
                     Some(MOVE_TOLERANCE),
                     user_signed_in,
                     insets.0,
@@ -430,7 +434,7 @@ impl InputType {
                     daily_challenges,
                     video_resource,
                     is_level_complete,
-                    timer.is_paused(),
+                    timer.is_stopped(),
                     None,
                     user_signed_in,
                     insets.0,
@@ -455,6 +459,7 @@ impl InputType {
                         }
                         InteractionEntity::Tile(tile) => {
                             input_state.handle_input_end(chosen_state, tile);
+                            ended_on_grid = true;
                             None
                         }
                     }
@@ -469,6 +474,20 @@ impl InputType {
             }
         };
 
+        if ended_on_grid && current_state == StartPressState::Gameplay {
+            if let Some(level) = current_level.level(daily_challenges).left() {
+                if let Some(word) =
+                    bonus_words.find_bonus_word(level, chosen_state.current_solution())
+                {
+                    bonus_words.record(level, &word);
+                } else if let Some(word_index) = chosen_state.close_word_index(level, found_words) {
+                    if input_state.is_new_near_miss(chosen_state.current_solution()) {
+                        near_miss_events.send(NearMissEvent { word_index });
+                    }
+                }
+            }
+        }
+
         match button_interaction {
             Some(new_interaction) => {
                 let should_change = match pressed_button.as_ref() {
@@ -543,9 +562,15 @@ pub fn handle_mouse_input(
     daily_challenges: Res<DailyChallenges>,
     timer: Res<LevelTime>,
     mut event_writer: EventWriter<ButtonActivated>,
-    extras: (Res<Time>, Res<UserSignedIn>, Res<InsetsResource>),
+    extras: (
+        Res<Time>,
+        Res<UserSignedIn>,
+        Res<InsetsResource>,
+        ResMut<BonusWordsState>,
+        EventWriter<NearMissEvent>,
+    ),
 ) {
-    let (time, user_signed_in, insets) = extras;
+    let (time, user_signed_in, insets, mut bonus_words, mut near_miss_events) = extras;
 
     let input_type = if mouse_input.just_released(MouseButton::Left) {
         let position_option = get_cursor_position(q_windows);
@@ -580,6 +605,8 @@ pub fn handle_mouse_input(
         &time,
         &user_signed_in,
         &insets,
+        &mut bonus_words,
+        &mut near_miss_events,
     );
 }
 
@@ -599,9 +626,15 @@ pub fn handle_touch_input(
     daily_challenges: Res<DailyChallenges>,
     timer: Res<LevelTime>,
     mut event_writer: EventWriter<ButtonActivated>,
-    extras: (Res<Time>, Res<UserSignedIn>, Res<InsetsResource>),
+    extras: (
+        Res<Time>,
+        Res<UserSignedIn>,
+        Res<InsetsResource>,
+        ResMut<BonusWordsState>,
+        EventWriter<NearMissEvent>,
+    ),
 ) {
-    let (time, user_signed_in, insets) = extras;
+    let (time, user_signed_in, insets, mut bonus_words, mut near_miss_events) = extras;
 
     for ev in touch_events.read() {
         let input_type: InputType = match ev.phase {
@@ -640,6 +673,8 @@ pub fn handle_touch_input(
             &time,
             &user_signed_in,
             &insets,
+            &mut bonus_words,
+            &mut near_miss_events,
         );
     }
 }
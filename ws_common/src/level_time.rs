@@ -14,7 +14,7 @@ impl Plugin for LevelTimePlugin {
         app.add_systems(PostUpdate, manage_timer);
         app.add_systems(
             Update,
-            count_up.run_if(|timer: Res<LevelTime>| timer.is_running()),
+            count_up.run_if(|timer: Res<LevelTime>| timer.is_ticking()),
         );
         app.add_systems(
             Update,
@@ -40,14 +40,24 @@ pub enum LevelTime {
     Finished {
         elapsed: Duration,
     },
+    /// Counting down during a time attack run
+    TimeAttack {
+        /// When the timer was last resumed, or `None` while it is paused
+        since: Option<DateTime<Utc>>,
+        additional: Duration,
+        /// The time allowed for this level, including any bonuses
+        budget: Duration,
+    },
 }
 
 impl LevelTime {
     pub fn total_elapsed(&self) -> std::time::Duration {
+        self.total_elapsed_at(chrono::Utc::now())
+    }
+
+    pub fn total_elapsed_at(&self, now: DateTime<Utc>) -> std::time::Duration {
         match self {
             LevelTime::Running { since, additional } => {
-                let now = chrono::Utc::now();
-
                 let additional =
                     chrono::Duration::from_std(*additional).unwrap_or(chrono::Duration::zero());
                 //info!("{now:?}");
@@ -57,19 +67,112 @@ impl LevelTime {
             }
             LevelTime::Paused { elapsed } => *elapsed,
             LevelTime::Finished { elapsed } => *elapsed,
+            LevelTime::TimeAttack {
+                since, additional, ..
+            } => {
+                let running = since
+                    .and_then(|since| now.signed_duration_since(since).to_std().ok())
+                    .unwrap_or_default();
+                *additional + running
+            }
+        }
+    }
+
+    /// The time left before a time attack level runs out
+    pub fn remaining_at(&self, now: DateTime<Utc>) -> Option<Duration> {
+        match self {
+            LevelTime::TimeAttack { budget, .. } => {
+                Some(budget.saturating_sub(self.total_elapsed_at(now)))
+            }
+            _ => None,
         }
     }
 
+    pub fn remaining(&self) -> Option<Duration> {
+        self.remaining_at(chrono::Utc::now())
+    }
+
+    /// Whether the timer is currently counting
+    pub fn is_ticking(&self) -> bool {
+        matches!(
+            self,
+            LevelTime::Running { .. }
+                | LevelTime::TimeAttack {
+                    since: Some(..),
+                    ..
+                }
+        )
+    }
+
+    /// Whether the timer is paused, including a paused time attack
+    pub fn is_stopped(&self) -> bool {
+        matches!(
+            self,
+            LevelTime::Paused { .. } | LevelTime::TimeAttack { since: None, .. }
+        )
+    }
+
     pub fn pause_timer(&mut self) {
-        *self = LevelTime::Paused {
-            elapsed: self.total_elapsed(),
+        self.pause_timer_at(chrono::Utc::now())
+    }
+
+    pub fn pause_timer_at(&mut self, now: DateTime<Utc>) {
+        let elapsed = self.total_elapsed_at(now);
+        *self = match *self {
+            LevelTime::TimeAttack { budget, .. } => LevelTime::TimeAttack {
+                since: None,
+                additional: elapsed,
+                budget,
+            },
+            _ => LevelTime::Paused { elapsed },
         }
     }
 
     pub fn resume_timer(&mut self) {
-        *self = LevelTime::Running {
-            since: chrono::Utc::now(),
-            additional: self.total_elapsed(),
+        self.resume_timer_at(chrono::Utc::now())
+    }
+
+    pub fn resume_timer_at(&mut self, now: DateTime<Utc>) {
+        let additional = self.total_elapsed_at(now);
+        *self = match *self {
+            LevelTime::TimeAttack { budget, .. } => LevelTime::TimeAttack {
+                since: Some(now),
+                additional,
+                budget,
+            },
+            _ => LevelTime::Running {
+                since: now,
+                additional,
+            },
+        }
+    }
+
+    /// Start counting down from `budget`, keeping the timer paused if it is paused
+    pub fn start_time_attack(&mut self, budget: Duration, now: DateTime<Utc>) {
+        *self = LevelTime::TimeAttack {
+            since: self.is_ticking().then_some(now),
+            additional: Duration::ZERO,
+            budget,
+        }
+    }
+
+    /// Go back to counting up, keeping the time elapsed so far
+    pub fn stop_time_attack(&mut self, now: DateTime<Utc>) {
+        if let LevelTime::TimeAttack { since, .. } = *self {
+            let elapsed = self.total_elapsed_at(now);
+            *self = match since {
+                Some(..) => LevelTime::Running {
+                    since: now,
+                    additional: elapsed,
+                },
+                None => LevelTime::Paused { elapsed },
+            }
+        }
+    }
+
+    pub fn add_time_attack_bonus(&mut self, bonus: Duration) {
+        if let LevelTime::TimeAttack { budget, .. } = self {
+            *budget += bonus;
         }
     }
 }
@@ -80,16 +183,29 @@ impl TrackableResource for LevelTime {
     const KEY: &'static str = "Timer";
 
     fn on_loaded(&mut self) {
-        if let LevelTime::Running { since, additional } = self {
-            let now = chrono::Utc::now();
-            let flush_time = chrono::Duration::try_seconds(FLUSH_SECONDS).unwrap();
-            if now.signed_duration_since(since) > flush_time {
-                let new_additional = *additional + Duration::from_secs(FLUSH_SECONDS as u64);
-                *self = LevelTime::Running {
-                    since: now,
-                    additional: new_additional,
+        let now = chrono::Utc::now();
+        let flush_time = chrono::Duration::try_seconds(FLUSH_SECONDS).unwrap();
+        match self {
+            LevelTime::Running { since, additional } => {
+                if now.signed_duration_since(since) > flush_time {
+                    let new_additional = *additional + Duration::from_secs(FLUSH_SECONDS as u64);
+                    *self = LevelTime::Running {
+                        since: now,
+                        additional: new_additional,
+                    }
+                }
+            }
+            LevelTime::TimeAttack {
+                since: Some(since),
+                additional,
+                ..
+            } => {
+                if now.signed_duration_since(*since) > flush_time {
+                    *additional += Duration::from_secs(FLUSH_SECONDS as u64);
+                    *since = now;
                 }
             }
+            _ => {}
         }
     }
 }
@@ -117,12 +233,12 @@ fn manage_timer(
             *timer.as_mut() = LevelTime::Finished {
                 elapsed: timer.total_elapsed(),
             };
-        } else if timer.is_paused() {
+        } else if timer.is_stopped() {
             timer.resume_timer()
         }
     }
 
-    if chosen_state.is_changed() && timer.is_paused() {
+    if chosen_state.is_changed() && timer.is_stopped() {
         timer.resume_timer()
     }
 
@@ -136,7 +252,11 @@ fn manage_timer(
         }
     }
 
-    if let LevelTime::Running { since, .. } = timer.as_ref() {
+    if let LevelTime::Running { since, .. }
+    | LevelTime::TimeAttack {
+        since: Some(since), ..
+    } = timer.as_ref()
+    {
         if chrono::Utc::now().signed_duration_since(since)
             >= chrono::Duration::try_seconds(FLUSH_SECONDS).unwrap()
         {
@@ -146,7 +266,7 @@ fn manage_timer(
 }
 
 fn count_up(mut query: Query<&mut Text, With<TimeCounterMarker>>, timer: Res<LevelTime>) {
-    let elapsed = timer.total_elapsed();
+    let elapsed = timer.remaining().unwrap_or_else(|| timer.total_elapsed());
 
     for mut text in query.iter_mut() {
         if let Some(section) = text.sections.first_mut() {
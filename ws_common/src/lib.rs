@@ -3,31 +3,41 @@ pub mod achievements;
 pub mod ads_common;
 pub mod animated_solutions;
 pub mod asynchronous;
+pub mod bonus_words;
 pub mod button;
 pub mod button_node;
+pub mod challenge;
 pub mod chosen_state;
 pub mod clear_color;
 pub mod compatibility;
 pub mod completion;
 pub mod constants;
 pub mod current_level;
+pub mod endless;
 pub mod grid_input;
 pub mod haptics;
+pub mod hard_mode;
 pub mod input;
 pub mod insets_resource;
 pub mod level_time;
 pub mod logging;
 pub mod menu_layout;
 pub mod motion_blur;
+pub mod near_miss;
+pub mod nudges;
 pub mod platform_specific;
 pub mod rounding;
 pub mod shapes;
+pub mod share_text;
 pub mod startup;
 pub mod state;
 pub mod streak;
+pub mod time_attack;
+pub mod versus;
 pub mod video;
 pub mod view;
 pub mod window_size;
+pub mod zen_mode;
 
 #[cfg(target_arch = "wasm32")]
 pub mod wasm;
@@ -43,8 +53,10 @@ pub mod prelude {
     pub use crate::ads_common::*;
     pub use crate::animated_solutions::*;
     pub use crate::asynchronous::*;
+    pub use crate::bonus_words::*;
     pub use crate::button::*;
     pub use crate::button_node::*;
+    pub use crate::challenge::*;
     pub use crate::chosen_state::*;
     pub use crate::clear_color::*;
     pub use crate::compatibility::*;
@@ -52,20 +64,28 @@ pub mod prelude {
     pub use crate::constants::*;
     pub use crate::current_level::*;
     pub use crate::daily_challenge::*;
+    pub use crate::endless::*;
     pub use crate::grid_input::*;
     pub use crate::haptics::*;
+    pub use crate::hard_mode::*;
     pub use crate::insets_resource::*;
     pub use crate::level_time::*;
     pub use crate::logging::*;
     pub use crate::menu_layout::*;
+    pub use crate::near_miss::*;
+    pub use crate::nudges::*;
     pub use crate::platform_specific::*;
     pub use crate::purchase_common::*;
     pub use crate::shapes::*;
+    pub use crate::share_text::*;
     pub use crate::state::*;
     pub use crate::streak::*;
+    pub use crate::time_attack::*;
+    pub use crate::versus::*;
     pub use crate::video::*;
     pub use crate::view::*;
     pub use crate::window_size::*;
+    pub use crate::zen_mode::*;
 
     pub use bevy::prelude::*;
 
@@ -197,6 +197,11 @@ pub enum LoggableEvent {
         first_time: bool,
     },
 
+    NearMiss {
+        level: String,
+        word_index: usize,
+    },
+
     // GoAppStore {
     //     store: String,
     //     level: String,
@@ -9,6 +9,16 @@ use crate::achievements::UserSignedIn;
 pub enum SettingsLayoutEntity {
     AdsConsent,
     RestorePurchases,
+    ToggleNudges,
+    ChangeNudgeTiming,
+    ChangeHintType,
+    ToggleNearMisses,
+    ToggleHardMode,
+    ToggleZenMode,
+    TimeAttack,
+    EndlessMode,
+    VersusPlayers,
+    ToggleTimedTurns,
     SeeAchievements,
     SyncAchievements,
 }
@@ -20,6 +30,16 @@ impl SettingsLayoutEntity {
             SettingsLayoutEntity::SeeAchievements => true,
             SettingsLayoutEntity::SyncAchievements => true,
             SettingsLayoutEntity::RestorePurchases => false,
+            SettingsLayoutEntity::ToggleNudges => false,
+            SettingsLayoutEntity::ChangeNudgeTiming => false,
+            SettingsLayoutEntity::ChangeHintType => false,
+            SettingsLayoutEntity::ToggleNearMisses => false,
+            SettingsLayoutEntity::ToggleHardMode => false,
+            SettingsLayoutEntity::ToggleZenMode => false,
+            SettingsLayoutEntity::TimeAttack => false,
+            SettingsLayoutEntity::EndlessMode => false,
+            SettingsLayoutEntity::VersusPlayers => false,
+            SettingsLayoutEntity::ToggleTimedTurns => false,
         }
     }
 }
@@ -57,6 +77,46 @@ impl LayoutStructureWithTextOrImage for SettingsLayoutEntity {
             SettingsLayoutEntity::RestorePurchases => ws_core::TextOrImage::Text {
                 text: "Restore Purchases",
             },
+
+            SettingsLayoutEntity::ToggleNudges => ws_core::TextOrImage::Text {
+                text: "Toggle Hint Nudges",
+            },
+
+            SettingsLayoutEntity::ChangeNudgeTiming => ws_core::TextOrImage::Text {
+                text: "Change Nudge Timing",
+            },
+
+            SettingsLayoutEntity::ChangeHintType => ws_core::TextOrImage::Text {
+                text: "Change Hint Type",
+            },
+
+            SettingsLayoutEntity::ToggleNearMisses => ws_core::TextOrImage::Text {
+                text: "Toggle Near Misses",
+            },
+
+            SettingsLayoutEntity::ToggleHardMode => ws_core::TextOrImage::Text {
+                text: "Toggle Hard Mode",
+            },
+
+            SettingsLayoutEntity::ToggleZenMode => ws_core::TextOrImage::Text {
+                text: "Toggle Zen Mode",
+            },
+
+            SettingsLayoutEntity::TimeAttack => ws_core::TextOrImage::Text {
+                text: "Start / End Time Attack",
+            },
+
+            SettingsLayoutEntity::EndlessMode => ws_core::TextOrImage::Text {
+                text: "Play Endless Mode",
+            },
+
+            SettingsLayoutEntity::VersusPlayers => ws_core::TextOrImage::Text {
+                text: "Change Versus Players",
+            },
+
+            SettingsLayoutEntity::ToggleTimedTurns => ws_core::TextOrImage::Text {
+                text: "Toggle Timed Turns",
+            },
         }
     }
 }
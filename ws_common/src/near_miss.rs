@@ -0,0 +1,83 @@
+use crate::prelude::*;
+use nice_bevy_utils::{CanInitTrackedResource, TrackableResource};
+use serde::{Deserialize, Serialize};
+
+pub struct NearMissPlugin;
+
+impl Plugin for NearMissPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_tracked_resource::<NearMissSettings>();
+        app.init_resource::<NearMissFeedback>();
+        app.add_event::<NearMissEvent>();
+
+        app.add_systems(
+            Update,
+            handle_near_misses.run_if(|ev: EventReader<NearMissEvent>| !ev.is_empty()),
+        );
+        app.add_systems(
+            Update,
+            clear_near_miss_feedback.run_if(|c: Res<CurrentLevel>| c.is_changed()),
+        );
+    }
+}
+
+/// Sent when the player finishes a selection which is almost an unfound word
+#[derive(Debug, Clone, Copy, Event, PartialEq, Eq)]
+pub struct NearMissEvent {
+    pub word_index: usize,
+}
+
+#[derive(Debug, Clone, Resource, Serialize, Deserialize, MavericContext, PartialEq, Eq)]
+pub struct NearMissSettings {
+    /// Show the player which word they almost found
+    pub show_feedback: bool,
+}
+
+impl Default for NearMissSettings {
+    fn default() -> Self {
+        Self {
+            show_feedback: true,
+        }
+    }
+}
+
+impl TrackableResource for NearMissSettings {
+    const KEY: &'static str = "NearMissSettings";
+}
+
+/// The word the player most recently almost found
+#[derive(Debug, Clone, Resource, MavericContext, PartialEq, Eq, Default)]
+pub struct NearMissFeedback {
+    pub word_index: Option<usize>,
+    /// The number of near misses shown, so that missing the same word twice animates twice
+    pub count: usize,
+}
+
+fn handle_near_misses(
+    mut events: EventReader<NearMissEvent>,
+    settings: Res<NearMissSettings>,
+    mut feedback: ResMut<NearMissFeedback>,
+    current_level: Res<CurrentLevel>,
+    daily_challenges: Res<DailyChallenges>,
+    video_resource: Res<VideoResource>,
+) {
+    for event in events.read() {
+        if let Some(level) = current_level.level(&daily_challenges).left() {
+            LoggableEvent::NearMiss {
+                level: level.full_name().to_string(),
+                word_index: event.word_index,
+            }
+            .try_log1();
+        }
+
+        if settings.show_feedback {
+            feedback.word_index = Some(event.word_index);
+            feedback.count += 1;
+            HapticEvent::NearMiss.try_activate(video_resource.selfie_mode());
+        }
+    }
+}
+
+fn clear_near_miss_feedback(mut feedback: ResMut<NearMissFeedback>) {
+    feedback.word_index = None;
+}
@@ -0,0 +1,348 @@
+use std::time::Duration;
+
+use crate::prelude::*;
+use itertools::Either;
+use nice_bevy_utils::{CanInitTrackedResource, TrackableResource};
+use serde::{Deserialize, Serialize};
+use strum::EnumIs;
+
+pub struct NudgePlugin;
+
+impl Plugin for NudgePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_tracked_resource::<NudgeSettings>();
+        app.init_resource::<NudgeState>();
+        app.add_systems(Update, update_nudges);
+    }
+}
+
+/// Settings for gently nudging players who seem to be stuck
+#[derive(Debug, Clone, Resource, Serialize, Deserialize, MavericContext, PartialEq, Eq)]
+pub struct NudgeSettings {
+    pub enabled: bool,
+    /// Seconds without finding a word before a useful tile is highlighted
+    pub highlight_tile_seconds: u64,
+    /// Seconds without finding a word before the player is reminded about hints
+    pub hints_reminder_seconds: u64,
+    /// Seconds without finding a word before a break is suggested
+    pub suggest_break_seconds: u64,
+    /// Near misses before a useful tile is highlighted, regardless of time
+    pub near_misses: usize,
+}
+
+impl Default for NudgeSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            highlight_tile_seconds: 60,
+            hints_reminder_seconds: 120,
+            suggest_break_seconds: 300,
+            near_misses: 3,
+        }
+    }
+}
+
+impl TrackableResource for NudgeSettings {
+    const KEY: &'static str = "NudgeSettings";
+}
+
+/// The choices for `highlight_tile_seconds`, the later thresholds are multiples of it
+const HIGHLIGHT_TILE_SECONDS_CHOICES: [u64; 4] = [30, 60, 120, 240];
+const HINTS_REMINDER_MULTIPLE: u64 = 2;
+const SUGGEST_BREAK_MULTIPLE: u64 = 5;
+
+impl NudgeSettings {
+    /// Make nudges start later, going back to the soonest choice after the latest
+    pub fn cycle_timing(&mut self) {
+        let next = HIGHLIGHT_TILE_SECONDS_CHOICES
+            .into_iter()
+            .find(|x| *x > self.highlight_tile_seconds)
+            .unwrap_or(HIGHLIGHT_TILE_SECONDS_CHOICES[0]);
+
+        self.highlight_tile_seconds = next;
+        self.hints_reminder_seconds = next * HINTS_REMINDER_MULTIPLE;
+        self.suggest_break_seconds = next * SUGGEST_BREAK_MULTIPLE;
+    }
+
+    /// The strongest nudge for how long the player has been stuck
+    pub fn choose_nudge(&self, idle: Duration, near_misses: usize) -> Option<NudgeType> {
+        if !self.enabled {
+            None
+        } else if idle >= Duration::from_secs(self.suggest_break_seconds) {
+            Some(NudgeType::SuggestBreak)
+        } else if idle >= Duration::from_secs(self.hints_reminder_seconds) {
+            Some(NudgeType::HintsReminder)
+        } else if idle >= Duration::from_secs(self.highlight_tile_seconds)
+            || near_misses >= self.near_misses
+        {
+            Some(NudgeType::HighlightTile)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIs)]
+pub enum NudgeType {
+    /// Briefly highlight the first tile of a word worth looking for
+    HighlightTile,
+    /// Remind the player that words can be held to get a hint
+    HintsReminder,
+    SuggestBreak,
+}
+
+impl NudgeType {
+    pub fn text(&self) -> Option<&'static str> {
+        match self {
+            NudgeType::HighlightTile => None,
+            NudgeType::HintsReminder => Some("Stuck? Hold a word to get a hint"),
+            NudgeType::SuggestBreak => Some("Maybe take a break and come back later"),
+        }
+    }
+}
+
+/// How long a highlighted tile stays highlighted
+const HIGHLIGHT_TILE_DURATION: Duration = Duration::from_secs(3);
+
+/// How long the player has been stuck on the current level
+#[derive(Debug, Clone, Resource, MavericContext, PartialEq, Eq, Default)]
+pub struct NudgeState {
+    /// When the player last found a word or started the level
+    pub last_progress: Duration,
+    /// The number of times the player has selected something close to an unfound word
+    pub near_misses: usize,
+    pub nudge: Option<NudgeType>,
+    /// The tile briefly highlighted by a `HighlightTile` nudge
+    pub highlighted_tile: Option<Tile>,
+    highlighted_since: Duration,
+    found_words: usize,
+    was_close: bool,
+}
+
+impl NudgeState {
+    fn reset(&mut self, elapsed: Duration, found_words: usize) {
+        *self = Self {
+            last_progress: elapsed,
+            found_words,
+            ..Default::default()
+        };
+    }
+
+    /// The tiles to show as hinted on the grid
+    pub fn highlighted_tiles(&self) -> GridSet {
+        match self.highlighted_tile {
+            Some(tile) => GridSet::EMPTY.with_bit_set(&tile, true),
+            None => GridSet::EMPTY,
+        }
+    }
+}
+
+fn update_nudges(
+    mut state: ResMut<NudgeState>,
+    settings: Res<NudgeSettings>,
+    time: Res<Time>,
+    current_level: Res<CurrentLevel>,
+    daily_challenges: Res<DailyChallenges>,
+    chosen_state: Res<ChosenState>,
+    menu_state: Res<MenuState>,
+    found_words: Res<FoundWordsState>,
+) {
+    let elapsed = time.elapsed();
+    let found_count = found_words
+        .word_completions
+        .iter()
+        .filter(|x| x.is_complete())
+        .count();
+
+    if current_level.is_changed() || state.found_words != found_count {
+        state.reset(elapsed, found_count);
+        return;
+    }
+
+    let Either::Left(level) = current_level.level(&daily_challenges) else {
+        return;
+    };
+
+    if !settings.enabled || found_words.is_level_complete() || !menu_state.is_closed() {
+        // Being away from the puzzle does not count as being stuck
+        state.last_progress = elapsed;
+        state.nudge = None;
+        state.highlighted_tile = None;
+        return;
+    }
+
+    if state.highlighted_tile.is_some()
+        && elapsed.saturating_sub(state.highlighted_since) >= HIGHLIGHT_TILE_DURATION
+    {
+        state.highlighted_tile = None;
+    }
+
+    let is_close = chosen_state.is_close_to_a_solution(level, &found_words);
+    if is_close && !state.was_close {
+        state.near_misses += 1;
+    }
+    state.was_close = is_close;
+
+    let idle = elapsed.saturating_sub(state.last_progress);
+    let nudge = settings.choose_nudge(idle, state.near_misses);
+    if state.nudge == nudge {
+        return;
+    }
+    state.nudge = nudge;
+
+    if nudge.is_some_and(|x| x.is_highlight_tile()) {
+        let tile = found_words
+            .recommended_hint(level)
+            .and_then(|word_index| level.words.get(word_index))
+            .and_then(|word| level.find_word_solution(word, found_words.unneeded_tiles))
+            .and_then(|solution| solution.first().copied());
+
+        if let Some(tile) = tile {
+            state.highlighted_tile = Some(tile);
+            state.highlighted_since = elapsed;
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use ws_levels::level_sequence::LevelSequence;
+
+    fn test_app(settings: NudgeSettings) -> App {
+        let mut app = App::new();
+        let sequence = LevelSequence::EuropeanCountries;
+        let level = sequence.get_level(0).unwrap();
+
+        app.insert_resource(settings);
+        app.insert_resource(FoundWordsState::new_from_level(level));
+        app.insert_resource(CurrentLevel::Fixed {
+            level_index: 0,
+            sequence,
+        });
+        app.init_resource::<NudgeState>();
+        app.init_resource::<Time>();
+        app.init_resource::<DailyChallenges>();
+        app.init_resource::<ChosenState>();
+        app.init_resource::<MenuState>();
+        app.add_systems(Update, update_nudges);
+        app.update();
+        app
+    }
+
+    fn advance(app: &mut App, seconds: u64) {
+        app.world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(seconds));
+        app.update();
+    }
+
+    fn nudge(app: &App) -> Option<NudgeType> {
+        app.world.resource::<NudgeState>().nudge
+    }
+
+    fn highlighted_tile(app: &App) -> Option<Tile> {
+        app.world.resource::<NudgeState>().highlighted_tile
+    }
+
+    #[test]
+    pub fn test_nudges_after_thresholds() {
+        let mut app = test_app(NudgeSettings::default());
+
+        advance(&mut app, 59);
+        assert_eq!(nudge(&app), None);
+        assert_eq!(highlighted_tile(&app), None);
+
+        advance(&mut app, 1);
+        assert_eq!(nudge(&app), Some(NudgeType::HighlightTile));
+        assert!(highlighted_tile(&app).is_some());
+        assert!(app
+            .world
+            .resource::<FoundWordsState>()
+            .revealed_tiles
+            .is_empty());
+
+        advance(&mut app, 3);
+        assert_eq!(highlighted_tile(&app), None);
+
+        advance(&mut app, 57);
+        assert_eq!(nudge(&app), Some(NudgeType::HintsReminder));
+
+        advance(&mut app, 180);
+        assert_eq!(nudge(&app), Some(NudgeType::SuggestBreak));
+    }
+
+    #[test]
+    pub fn test_finding_a_word_resets_nudges() {
+        let mut app = test_app(NudgeSettings::default());
+
+        advance(&mut app, 150);
+        assert_eq!(nudge(&app), Some(NudgeType::HintsReminder));
+
+        app.world.resource_mut::<FoundWordsState>().word_completions[0] = Completion::Complete {
+            index: 0,
+            player: None,
+        };
+        advance(&mut app, 1);
+        assert_eq!(nudge(&app), None);
+
+        advance(&mut app, 60);
+        assert_eq!(nudge(&app), Some(NudgeType::HighlightTile));
+    }
+
+    #[test]
+    pub fn test_open_menu_pauses_nudges() {
+        let mut app = test_app(NudgeSettings::default());
+
+        *app.world.resource_mut::<MenuState>() = MenuState::ShowMainMenu;
+        advance(&mut app, 500);
+        assert_eq!(nudge(&app), None);
+
+        *app.world.resource_mut::<MenuState>() = MenuState::Closed;
+        advance(&mut app, 59);
+        assert_eq!(nudge(&app), None);
+    }
+
+    #[test]
+    pub fn test_disabled() {
+        let mut app = test_app(NudgeSettings {
+            enabled: false,
+            ..Default::default()
+        });
+
+        advance(&mut app, 1000);
+        assert_eq!(nudge(&app), None);
+    }
+
+    #[test]
+    pub fn test_near_misses() {
+        let settings = NudgeSettings::default();
+
+        assert_eq!(settings.choose_nudge(Duration::from_secs(10), 2), None);
+        assert_eq!(
+            settings.choose_nudge(Duration::from_secs(10), 3),
+            Some(NudgeType::HighlightTile)
+        );
+        assert_eq!(
+            settings.choose_nudge(Duration::from_secs(200), 3),
+            Some(NudgeType::HintsReminder)
+        );
+    }
+
+    #[test]
+    pub fn test_cycle_timing() {
+        let mut settings = NudgeSettings::default();
+
+        settings.cycle_timing();
+        assert_eq!(settings.highlight_tile_seconds, 120);
+        assert_eq!(settings.hints_reminder_seconds, 240);
+        assert_eq!(settings.suggest_break_seconds, 600);
+
+        settings.cycle_timing();
+        settings.cycle_timing();
+        assert_eq!(settings.highlight_tile_seconds, 30);
+
+        settings.cycle_timing();
+        assert_eq!(settings, NudgeSettings::default());
+    }
+}
@@ -0,0 +1,233 @@
+use std::time::Duration;
+
+use crate::prelude::*;
+
+/// A spoiler free summary of the current level, to be shared once it is complete
+pub fn try_generate_share_text(
+    current_level: &CurrentLevel,
+    time: &LevelTime,
+    found_words_state: &FoundWordsState,
+    daily_challenges: &DailyChallenges,
+) -> Option<String> {
+    let level = current_level.level(daily_challenges).left()?;
+    generate_share_text(
+        current_level,
+        level,
+        time.total_elapsed(),
+        found_words_state,
+    )
+}
+
+/// A summary of the puzzle number, time, hints used and the order words were found in.
+/// Words are shown in the order they appear in the level so none of them are revealed
+pub fn generate_share_text(
+    current_level: &CurrentLevel,
+    level: &DesignedLevel,
+    elapsed: Duration,
+    found_words_state: &FoundWordsState,
+) -> Option<String> {
+    let (first_lines, url) = match current_level {
+        CurrentLevel::DailyChallenge { index } => {
+            let number = match level.numbering {
+                Some(Numbering::WordSaladNumber(num)) => num,
+                _ => index + 1,
+            };
+            (
+                format!("Word Salad #{number}\n{}", level.name),
+                format!("https://wordsalad.online/daily/{}", index + 1),
+            )
+        }
+        CurrentLevel::Fixed { .. } => (
+            level.full_name().to_string(),
+            "https://wordsalad.online/".to_string(),
+        ),
+        CurrentLevel::Custom { .. } => (
+            level.full_name().to_string(),
+            format!("https://wordsalad.online/game/{}", level.share_data()),
+        ),
+        CurrentLevel::Endless { seed } => (
+            format!("Word Salad Endless\n{}", level.name),
+            format!("https://wordsalad.online/endless/{seed}"),
+        ),
+        CurrentLevel::Tutorial { .. } | CurrentLevel::NonLevel(..) => return None,
+    };
+
+    let url = challenge_url(current_level, url, elapsed, found_words_state);
+
+    let total_secs = elapsed.as_secs();
+    let minutes = total_secs / 60;
+    let seconds = total_secs % 60;
+    let hints = found_words_state.hints_used;
+    let time_line = if found_words_state.zen_mode {
+        format!("🧘 ❓{hints}")
+    } else {
+        format!("⌛{minutes}m {seconds}s, ❓{hints}")
+    };
+
+    let order_line: String = found_words_state
+        .word_completions
+        .iter()
+        .map(|completion| match completion {
+            Completion::Unstarted => "⬜".to_string(),
+            Completion::LengthHinted | Completion::StartHinted | Completion::ManualHinted(_) => {
+                "🟨".to_string()
+            }
+            Completion::Complete { index, .. } => order_emoji(*index as usize + 1),
+        })
+        .collect();
+
+    Some(format!("{first_lines}\n{time_line}\n{order_line}\n{url}"))
+}
+
+/// Daily challenge and custom level links carry the result so the recipient can try to beat it
+fn challenge_url(
+    current_level: &CurrentLevel,
+    url: String,
+    elapsed: Duration,
+    found_words_state: &FoundWordsState,
+) -> String {
+    if !matches!(
+        current_level,
+        CurrentLevel::DailyChallenge { .. } | CurrentLevel::Custom { .. }
+    ) || found_words_state.zen_mode
+        || found_words_state.versus.is_some()
+        || !found_words_state.is_level_complete()
+    {
+        return url;
+    }
+
+    match challenge_from_result(elapsed, found_words_state).to_path(&url) {
+        Ok(path) => format!("https://wordsalad.online{path}"),
+        Err(err) => {
+            warn!("Could not make challenge link: {err}");
+            url
+        }
+    }
+}
+
+fn order_emoji(position: usize) -> String {
+    match position {
+        0..=9 => format!("{position}\u{fe0f}\u{20e3}"),
+        10 => "🔟".to_string(),
+        _ => format!("({position})"),
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use ws_levels::level_sequence::LevelSequence;
+
+    /* spellchecker:disable */
+    const SPORTS: &str = "ASHPKILOEUIOGNDT\tSports\tPOLO\tSHOOTING\tKENDO\tSAILING\tLUGE\tSKIING";
+
+    fn found_words(level: &DesignedLevel) -> FoundWordsState {
+        let mut state = FoundWordsState::new_level_complete(level, 2);
+        for (index, completion) in state.word_completions.iter_mut().rev().enumerate() {
+            *completion = Completion::Complete {
+                index: index as u8,
+                player: None,
+            };
+        }
+        state
+    }
+
+    #[test]
+    pub fn test_daily_challenge() {
+        let mut level = DesignedLevel::from_tsv_line(SPORTS).unwrap();
+        level.numbering = Some(Numbering::WordSaladNumber(12));
+
+        let text = generate_share_text(
+            &CurrentLevel::DailyChallenge { index: 11 },
+            &level,
+            Duration::from_secs(83),
+            &found_words(&level),
+        )
+        .unwrap();
+
+        let (first_lines, url) = text.split_at(text.find("https://").unwrap());
+        assert_eq!(
+            first_lines,
+            "Word Salad #12\nSports\n⌛1m 23s, ❓2\n6️⃣5️⃣4️⃣3️⃣2️⃣1️⃣\n"
+        );
+
+        let received = try_received_challenge_from_path(url).unwrap();
+        assert_eq!(received.level, CurrentLevel::DailyChallenge { index: 11 });
+        assert_eq!(received.challenge.seconds, 83);
+        assert_eq!(received.challenge.hints_used, 2);
+        assert_eq!(
+            received.challenge.found_order,
+            found_order(&found_words(&level))
+        );
+    }
+
+    #[test]
+    pub fn test_incomplete_level_has_no_challenge() {
+        let level = DesignedLevel::from_tsv_line(SPORTS).unwrap();
+
+        let text = generate_share_text(
+            &CurrentLevel::Custom { name: level.name },
+            &level,
+            Duration::from_secs(83),
+            &FoundWordsState::new_from_level(&level),
+        )
+        .unwrap();
+
+        assert!(!text.contains(CHALLENGE_PATH_SEPARATOR), "{text}");
+    }
+
+    #[test]
+    pub fn test_zen_mode() {
+        let level = DesignedLevel::from_tsv_line(SPORTS).unwrap();
+
+        let text = generate_share_text(
+            &CurrentLevel::Custom { name: level.name },
+            &level,
+            Duration::from_secs(83),
+            &found_words(&level).with_zen_mode(true),
+        )
+        .unwrap();
+
+        assert!(text.contains("🧘 ❓2"), "{text}");
+        assert!(!text.contains('⌛'), "{text}");
+    }
+
+    #[test]
+    pub fn test_no_spoilers() {
+        let level = DesignedLevel::from_tsv_line(SPORTS).unwrap();
+
+        for current_level in [
+            CurrentLevel::Fixed {
+                level_index: 0,
+                sequence: LevelSequence::USStates,
+            },
+            CurrentLevel::Custom { name: level.name },
+        ] {
+            let text = generate_share_text(
+                &current_level,
+                &level,
+                Duration::from_secs(10),
+                &found_words(&level),
+            )
+            .unwrap();
+
+            for word in level.words.iter() {
+                assert!(!text.contains(word.text.as_str()), "{text} contains {word}");
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_tutorial() {
+        let level = DesignedLevel::from_tsv_line(SPORTS).unwrap();
+
+        let text = generate_share_text(
+            &CurrentLevel::Tutorial { index: 0 },
+            &level,
+            Duration::ZERO,
+            &found_words(&level),
+        );
+
+        assert_eq!(text, None);
+    }
+}
@@ -65,7 +65,18 @@ pub fn setup_app(extra_setup: impl FnOnce(&mut App)) {
     app.register_maveric::<RecordingButtonRoot>();
     app.register_maveric::<MenuRoot>();
     app.add_plugins(HintsRemainingPlugin);
+    app.add_plugins(NudgeViewPlugin);
+    app.add_plugins(NearMissViewPlugin);
     app.add_plugins(StatePlugin);
+    app.add_plugins(BonusWordsPlugin);
+    app.add_plugins(NudgePlugin);
+    app.add_plugins(NearMissPlugin);
+    app.add_plugins(HardModePlugin);
+    app.add_plugins(ZenModePlugin);
+    app.add_plugins(TimeAttackPlugin);
+    app.add_plugins(EndlessPlugin);
+    app.add_plugins(VersusPlugin);
+    app.add_plugins(ChallengePlugin);
     app.add_plugins(LevelTimePlugin);
     app.add_plugins(ShapesPlugin);
     app.add_plugins(PopupPlugin);
@@ -176,6 +187,17 @@ pub fn choose_level_on_game_load(
                 }
             }
 
+            if let Some(seed) = crate::wasm::get_endless_seed_from_location() {
+                info!("Loaded endless seed {seed} from path");
+
+                let new_level = CurrentLevel::Endless { seed };
+                if new_level != *current_level {
+                    return Some(new_level);
+                } else {
+                    return None;
+                }
+            }
+
             if let Some(level) = crate::wasm::get_game_from_location() {
                 info!("Loaded custom level from path");
 
@@ -194,7 +216,9 @@ pub fn choose_level_on_game_load(
         }
 
         match current_level.as_ref() {
-            CurrentLevel::Tutorial { .. } | CurrentLevel::NonLevel(NonLevel::BeforeTutorial) => {
+            CurrentLevel::Tutorial { .. }
+            | CurrentLevel::Endless { .. }
+            | CurrentLevel::NonLevel(NonLevel::BeforeTutorial) => {
                 return None;
             }
             _ => {}
@@ -21,6 +21,7 @@ impl Plugin for StatePlugin {
         app.init_tracked_resource::<DailyChallengeCompletion>();
         app.init_tracked_resource::<TutorialCompletion>();
         app.init_tracked_resource::<HintState>();
+        app.init_tracked_resource::<HintSettings>();
         app.init_tracked_resource::<SavedLevelsState>();
 
         app.add_event::<WordFoundEvent>();
@@ -46,6 +47,7 @@ impl Plugin for StatePlugin {
             Update,
             handle_hint_event.run_if(|ev: EventReader<HintEvent>| !ev.is_empty()),
         );
+        app.add_systems(Update, hide_revealed_tiles);
     }
 }
 #[derive(Debug, Event, Clone)]
@@ -57,6 +59,72 @@ pub enum ChangeLevelEvent {
 #[derive(Debug, Clone, Copy, Event, PartialEq)]
 pub struct HintEvent {
     pub word_index: usize,
+    pub hint_type: HintType,
+}
+
+/// The different kinds of hint a player can buy, see `HintState::cost`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, EnumIs)]
+pub enum HintType {
+    /// Reveal the next letter of the word
+    #[default]
+    NextLetter,
+    /// Highlight the tile the word starts on
+    StartingTile,
+    /// Briefly show every tile in the word
+    FullPath,
+    /// Briefly show every tile still needed by an unfound word
+    NeededTiles,
+    /// Show the length of the word, for levels which hide word lengths
+    WordLength,
+}
+
+impl HintType {
+    /// The next hint type, wrapping around to the first
+    pub const fn next(self) -> Self {
+        match self {
+            HintType::NextLetter => HintType::StartingTile,
+            HintType::StartingTile => HintType::FullPath,
+            HintType::FullPath => HintType::NeededTiles,
+            HintType::NeededTiles => HintType::WordLength,
+            HintType::WordLength => HintType::NextLetter,
+        }
+    }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            HintType::NextLetter => "Next Letter",
+            HintType::StartingTile => "Starting Tile",
+            HintType::FullPath => "Full Path",
+            HintType::NeededTiles => "Needed Tiles",
+            HintType::WordLength => "Word Length",
+        }
+    }
+}
+
+/// The kind of hint given when a word button is held
+#[derive(
+    Debug, Clone, Resource, Serialize, Deserialize, MavericContext, PartialEq, Eq, Default,
+)]
+pub struct HintSettings {
+    pub hint_type: HintType,
+}
+
+impl TrackableResource for HintSettings {
+    const KEY: &'static str = "HintSettings";
+}
+
+impl HintEvent {
+    /// A hint for whichever unfound word would help the player most
+    pub fn recommended(
+        level: &DesignedLevel,
+        found_words: &FoundWordsState,
+        hint_type: HintType,
+    ) -> Option<Self> {
+        found_words.recommended_hint(level).map(|word_index| Self {
+            word_index,
+            hint_type,
+        })
+    }
 }
 
 impl From<CurrentLevel> for ChangeLevelEvent {
@@ -76,12 +144,12 @@ fn handle_hint_event(
     mut popup_state: ResMut<PopupState>,
     video_resource: Res<VideoResource>,
 ) {
-    for HintEvent { word_index } in events.read() {
+    for event in events.read() {
         if let Either::Left(level) = current_level.level(&daily_challenges) {
-            found_words.try_hint_word(
+            found_words.try_use_hint(
                 hint_state.as_mut(),
                 level,
-                *word_index,
+                *event,
                 chosen_state.as_mut(),
                 &mut animate_solution_events,
                 video_resource.selfie_mode(),
@@ -92,7 +160,35 @@ fn handle_hint_event(
     }
 }
 
-fn handle_change_level_event(
+/// How long tiles revealed by a hint stay highlighted
+const REVEALED_TILES_DURATION: Duration = Duration::from_secs(3);
+
+fn hide_revealed_tiles(
+    mut found_words: ResMut<FoundWordsState>,
+    time: Res<Time>,
+    mut shown: Local<Option<(GridSet, Duration)>>,
+) {
+    let revealed_tiles = found_words.revealed_tiles;
+    if revealed_tiles.is_empty() {
+        *shown = None;
+        return;
+    }
+
+    let since = match *shown {
+        Some((tiles, since)) if tiles == revealed_tiles => since,
+        _ => {
+            *shown = Some((revealed_tiles, time.elapsed()));
+            time.elapsed()
+        }
+    };
+
+    if time.elapsed().saturating_sub(since) >= REVEALED_TILES_DURATION {
+        found_words.revealed_tiles = GridSet::EMPTY;
+        *shown = None;
+    }
+}
+
+pub(crate) fn handle_change_level_event(
     mut events: EventReader<ChangeLevelEvent>,
     mut current_level: ResMut<CurrentLevel>,
     daily_challenges: Res<DailyChallenges>,
@@ -105,6 +201,11 @@ fn handle_change_level_event(
     interstitial_progress_state: Res<InterstitialProgressState>,
     mut request_ad_events: EventWriter<AdRequestEvent>,
     purchases: Res<Purchases>,
+    modes: (
+        Res<HardModeSettings>,
+        Res<ZenModeSettings>,
+        Res<VersusSettings>,
+    ),
 ) {
     for event in events.read() {
         let new_level = match event {
@@ -148,7 +249,10 @@ fn handle_change_level_event(
                     }
                     .try_log1();
                     *time = LevelTime::default();
-                    *found_words = FoundWordsState::new_from_level(level);
+                    *found_words = FoundWordsState::new_from_level(level)
+                        .with_hard_mode(modes.0.enabled)
+                        .with_zen_mode(modes.1.enabled)
+                        .with_versus(modes.2.rules());
                     *chosen = ChosenState::default();
                 }
 
@@ -174,12 +278,26 @@ fn handle_change_level_event(
 
         if let CurrentLevel::DailyChallenge { index } = new_level {
             if let Either::Left(level) = new_level.level(&daily_challenges) {
-                if let Some(level_result) = daily_challenge_completions.results.get(&index) {
+                let completed = match daily_challenge_completions.results.get(&index) {
+                    Some(result) => Some((*result, false)),
+                    None => daily_challenge_completions
+                        .zen_mode_results
+                        .get(&index)
+                        .map(|hints_used| {
+                            let result = LevelResult {
+                                seconds: 0,
+                                hints_used: *hints_used,
+                            };
+                            (result, true)
+                        }),
+                };
+                if let Some((level_result, zen_mode)) = completed {
                     *current_level = new_level.clone();
                     *found_words = FoundWordsState::new_level_complete(
                         level,
                         level_result.hints_used as usize,
-                    );
+                    )
+                    .with_zen_mode(zen_mode);
                     *time = LevelTime::Paused {
                         elapsed: Duration::from_secs(level_result.seconds as u64),
                     };
@@ -198,7 +316,10 @@ fn handle_change_level_event(
         let saved_state = match new_level.level(daily_challenges.as_ref()) {
             Either::Left(level) => loaded_level.unwrap_or_else(|| SavedState {
                 elapsed: Duration::ZERO,
-                found_words_state: FoundWordsState::new_from_level(level),
+                found_words_state: FoundWordsState::new_from_level(level)
+                    .with_hard_mode(modes.0.enabled)
+                    .with_zen_mode(modes.1.enabled)
+                    .with_versus(modes.2.rules()),
             }),
             Either::Right(..) => SavedState {
                 elapsed: Duration::ZERO,
@@ -243,6 +364,7 @@ impl SavedLevelKey {
                 Some(SavedLevelKey::DailyChallenge { index: *index })
             }
             CurrentLevel::Custom { .. } => None,
+            CurrentLevel::Endless { .. } => None,
             CurrentLevel::NonLevel(_) => None,
         }
     }
@@ -313,6 +435,14 @@ impl TrackableResource for HintState {
 }
 
 impl HintState {
+    /// The number of hints spent on a hint of this type
+    pub const fn cost(hint_type: HintType) -> usize {
+        match hint_type {
+            HintType::NextLetter | HintType::StartingTile | HintType::WordLength => 1,
+            HintType::FullPath | HintType::NeededTiles => 2,
+        }
+    }
+
     pub fn as_text(&self) -> String {
         match self.hints_remaining {
             0 => "No Hints Left".to_string(),
@@ -329,6 +459,21 @@ pub struct FoundWordsState {
     pub unneeded_tiles: GridSet,
     pub word_completions: Vec<Completion>,
     pub hints_used: usize,
+    /// Tiles briefly shown by a path or needed tiles hint
+    #[serde(skip)]
+    pub revealed_tiles: GridSet,
+    /// Tiles do not fall and no help is given with which tiles to choose
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub hard_mode: bool,
+    /// The timer is hidden and the time taken is not recorded
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub zen_mode: bool,
+    /// Players take turns and each found word is credited to one of them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub versus: Option<VersusRules>,
+    /// The result of another player to beat, from a challenge link
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub challenge: Option<Challenge>,
 }
 
 impl TrackableResource for FoundWordsState {
@@ -344,9 +489,11 @@ impl FoundWordsState {
             .iter()
             .enumerate()
             .filter_map(|(word_index, completion)| match completion {
-                Completion::Unstarted => None,
-                Completion::ManualHinted(_) => None,
-                Completion::Complete { index } => Some((word_index, index)),
+                Completion::Unstarted
+                | Completion::LengthHinted
+                | Completion::StartHinted
+                | Completion::ManualHinted(_) => None,
+                Completion::Complete { index, .. } => Some((word_index, index)),
             })
             .sorted_by_key(|x| x.1)
             .map(|x| x.0)
@@ -367,47 +514,64 @@ impl FoundWordsState {
             unneeded_tiles: GridSet::EMPTY,
             word_completions: vec![Completion::Unstarted; level.words.len()],
             hints_used: 0,
+            revealed_tiles: GridSet::EMPTY,
+            hard_mode: false,
+            zen_mode: false,
+            versus: None,
+            challenge: None,
         }
     }
 
+    pub fn with_hard_mode(self, hard_mode: bool) -> Self {
+        Self { hard_mode, ..self }
+    }
+
+    pub fn with_zen_mode(self, zen_mode: bool) -> Self {
+        Self { zen_mode, ..self }
+    }
+
+    pub fn with_versus(self, versus: Option<VersusRules>) -> Self {
+        Self { versus, ..self }
+    }
+
     pub fn new_level_complete(level: &DesignedLevel, hints_used: usize) -> Self {
         Self {
             unneeded_tiles: GridSet::ALL,
-            word_completions: vec![Completion::Complete { index: 0 }; level.words.len()],
+            word_completions: vec![
+                Completion::Complete {
+                    index: 0,
+                    player: None
+                };
+                level.words.len()
+            ],
             hints_used,
+            revealed_tiles: GridSet::EMPTY,
+            hard_mode: false,
+            zen_mode: false,
+            versus: None,
+            challenge: None,
         }
     }
 
-    /// Grid with unneeded characters blanked
-    fn adjusted_grid(&self, level: &DesignedLevel) -> Grid {
-        let mut grid = level.grid;
-
-        for tile in self.unneeded_tiles.iter_true_tiles() {
-            grid[tile] = Character::Blank;
-        }
-
-        grid
-    }
-
     pub fn manual_hint_set(&self, level: &DesignedLevel, solution: &Solution) -> GridSet {
         self.hint_set::<true>(level, solution)
+            .union(&self.revealed_tiles)
     }
 
     fn hint_set<const MANUAL: bool>(&self, level: &DesignedLevel, solution: &Solution) -> GridSet {
         let mut set = GridSet::default();
-        let adjusted_grid = self.adjusted_grid(level);
 
         if solution.is_empty() {
             //hint all known first letters
             for (word, completion) in level.words.iter().zip(self.word_completions.iter()) {
                 if !(
-                    MANUAL && completion.is_manual_hinted()
+                    MANUAL && (completion.is_manual_hinted() || completion.is_start_hinted())
                     // || (!MANUAL && completion.is_auto_hinted())
                 ) {
                     continue;
                 }
 
-                if let Some(solution) = word.find_solution(&adjusted_grid) {
+                if let Some(solution) = level.find_word_solution(word, self.unneeded_tiles) {
                     if let Some(first) = solution.first() {
                         set.set_bit(first, true)
                     }
@@ -423,11 +587,12 @@ impl FoundWordsState {
                     }
                 };
 
-                if let Some(word_solution) = word.find_solution(&adjusted_grid) {
-                    let len = hints.get().min(solution.len());
+                if let Some(word_solution) = level.find_word_solution(word, self.unneeded_tiles) {
+                    let revealed = level.revealed_prefix(&word_solution, hints.get());
+                    let len = revealed.len().min(solution.len());
 
-                    if solution.iter().take(len).eq(word_solution.iter().take(len)) {
-                        for tile in word_solution.iter().take(hints.get()) {
+                    if solution.iter().take(len).eq(revealed.iter().take(len)) {
+                        for tile in revealed {
                             set.set_bit(tile, true)
                         }
                     }
@@ -449,7 +614,121 @@ impl FoundWordsState {
         *self
             .word_completions
             .get(word_index)
-            .unwrap_or(&Completion::Complete { index: 0 })
+            .unwrap_or(&Completion::Complete {
+                index: 0,
+                player: None,
+            })
+    }
+
+    /// Spend hints on a hint of any type.
+    /// Opens the buy more hints popup if there are not enough hints left
+    fn try_use_hint(
+        &mut self,
+        hint_state: &mut HintState,
+        level: &DesignedLevel,
+        event: HintEvent,
+        chosen_state: &mut ChosenState,
+        ew: &mut impl AnyEventWriter<WordFoundEvent>,
+        selfie_mode: SelfieMode,
+        popup_state: &mut PopupState,
+        should_spend_hints: bool,
+    ) -> bool {
+        if event.hint_type.is_next_letter() {
+            return self.try_hint_word(
+                hint_state,
+                level,
+                event.word_index,
+                chosen_state,
+                ew,
+                selfie_mode,
+                popup_state,
+                should_spend_hints,
+            );
+        }
+
+        let cost = HintState::cost(event.hint_type);
+        let new_hints = if should_spend_hints {
+            let Some(new_hints) = hint_state.hints_remaining.checked_sub(cost) else {
+                popup_state.0 = Some(PopupType::BuyMoreHints(event));
+                return false;
+            };
+            Some(new_hints)
+        } else {
+            None
+        };
+
+        if !self.apply_hint(level, event.word_index, event.hint_type) {
+            return false;
+        }
+
+        if let Some(new_hints) = new_hints {
+            hint_state.hints_remaining = new_hints;
+        }
+        self.hints_used += cost;
+
+        crate::haptics::HapticEvent::UseHint.try_activate(selfie_mode);
+
+        true
+    }
+
+    /// Apply a hint which does not reveal letters.
+    /// Returns false if the hint would not tell the player anything new
+    fn apply_hint(
+        &mut self,
+        level: &DesignedLevel,
+        word_index: usize,
+        hint_type: HintType,
+    ) -> bool {
+        let Some(completion) = self.word_completions.get(word_index).copied() else {
+            return false;
+        };
+        let Some(word) = level.words.get(word_index) else {
+            return false;
+        };
+
+        match hint_type {
+            HintType::NextLetter => false,
+            HintType::StartingTile => {
+                if !matches!(completion, Completion::Unstarted | Completion::LengthHinted) {
+                    return false;
+                }
+                self.word_completions[word_index] = Completion::StartHinted;
+                true
+            }
+            HintType::WordLength => {
+                if !level.metadata.hide_word_lengths || !completion.is_unstarted() {
+                    return false;
+                }
+                self.word_completions[word_index] = Completion::LengthHinted;
+                true
+            }
+            HintType::FullPath => {
+                if completion.is_complete() {
+                    return false;
+                }
+                let Some(solution) = level.find_word_solution(word, self.unneeded_tiles) else {
+                    return false;
+                };
+                self.revealed_tiles = GridSet::from_iter(solution);
+                true
+            }
+            HintType::NeededTiles => {
+                let needed = level
+                    .words
+                    .iter()
+                    .zip(self.word_completions.iter())
+                    .filter(|(_, completion)| !completion.is_complete())
+                    .filter_map(|(word, _)| level.find_word_solution(word, self.unneeded_tiles))
+                    .fold(GridSet::EMPTY, |acc, solution| {
+                        acc.union(&GridSet::from_iter(solution))
+                    });
+                if needed.is_empty() {
+                    return false;
+                }
+                self.revealed_tiles = needed;
+                true
+            }
+        }
     }
 
     fn try_hint_word(
@@ -464,8 +743,14 @@ impl FoundWordsState {
         should_spend_hints: bool,
     ) -> bool {
         let new_hints = if should_spend_hints {
-            let Some(new_hints) = hint_state.hints_remaining.checked_sub(1) else {
-                popup_state.0 = Some(PopupType::BuyMoreHints(HintEvent { word_index }));
+            let Some(new_hints) = hint_state
+                .hints_remaining
+                .checked_sub(HintState::cost(HintType::NextLetter))
+            else {
+                popup_state.0 = Some(PopupType::BuyMoreHints(HintEvent {
+                    word_index,
+                    hint_type: HintType::NextLetter,
+                }));
                 return false;
             };
             Some(new_hints)
@@ -493,7 +778,7 @@ impl FoundWordsState {
         };
 
         let new_count = match completion {
-            Completion::Unstarted => {
+            Completion::Unstarted | Completion::LengthHinted | Completion::StartHinted => {
                 *completion = Completion::ManualHinted(min_hint_count);
                 self.hints_used += 1;
                 if let Some(new_hints) = new_hints {
@@ -523,18 +808,23 @@ impl FoundWordsState {
             Completion::Complete { .. } => return false,
         };
 
-        if let Some(solution) = word.find_solution_with_tiles(&level.grid, self.unneeded_tiles) {
-            let new_selection: ArrayVec<Tile, 16> =
-                ArrayVec::from_iter(solution.iter().take(new_count).cloned());
-            chosen_state.solution = new_selection;
+        if let Some(solution) = level.find_word_solution(word, self.unneeded_tiles) {
+            let revealed = level.revealed_prefix(&solution, new_count);
+            // A digraph tile reveals both of its letters
+            if let Completion::ManualHinted(hints) = completion {
+                let revealed_count = level.selection_characters(revealed).len();
+                *hints = (*hints).max(NonZeroUsize::new(revealed_count).unwrap_or(*hints));
+            }
+            chosen_state.solution = ArrayVec::from_iter(revealed.iter().cloned());
 
-            if solution.len() > new_count {
+            if solution.len() > revealed.len() {
                 chosen_state.is_just_finished = false;
             } else {
                 //do not select the full word - let the user do that
 
                 *completion = Completion::Complete {
                     index: completion_index,
+                    player: None,
                 };
                 self.update_unneeded_tiles(level);
 
@@ -562,11 +852,18 @@ impl FoundWordsState {
 pub enum Completion {
     #[default]
     Unstarted,
+    /// The word's length is shown, for levels which hide word lengths
+    LengthHinted,
+    /// The word's first tile is highlighted but no letters are revealed
+    StartHinted,
     // AutoHinted(NonZeroUsize),
     ManualHinted(NonZeroUsize),
     Complete {
         /// the number of previously completed words
         index: u8,
+        /// The versus player who found the word
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        player: Option<u8>,
     },
 }
 
@@ -577,15 +874,16 @@ impl Completion {
         const COMPLETE: &Color = &convert_color_const(palette::WORD_BACKGROUND_COMPLETE);
 
         match self {
-            Completion::Unstarted => UNSTARTED,
-            Completion::ManualHinted(_) => MANUAL,
+            Completion::Unstarted | Completion::LengthHinted => UNSTARTED,
+            Completion::StartHinted | Completion::ManualHinted(_) => MANUAL,
             Completion::Complete { .. } => COMPLETE,
         }
     }
 
     pub fn known_characters<'w>(&self, word: &'w DisplayWord) -> Option<&'w [Character]> {
         match self {
-            Completion::Unstarted => None,
+            Completion::Unstarted | Completion::LengthHinted => None,
+            Completion::StartHinted => word.characters.get(..1),
             Completion::Complete { .. } => Some(&word.characters),
             Completion::ManualHinted(hints) => Some(
                 word.characters
@@ -610,8 +908,7 @@ fn track_found_words(
     let Either::Left(level) = current_level.level(&daily_challenges) else {
         return;
     };
-    let grid = level.grid;
-    let chars: CharsArray = chosen.solution.iter().map(|t| grid[*t]).collect();
+    let chars: CharsArray = level.selection_characters(&chosen.solution);
 
     let Some((word_index, word)) = level
         .words
@@ -635,7 +932,10 @@ fn track_found_words(
             .iter()
             .filter(|x| x.is_complete())
             .count() as u8;
-        found_words.word_completions[word_index] = Completion::Complete { index };
+        found_words.word_completions[word_index] = Completion::Complete {
+            index,
+            player: None,
+        };
 
         found_words.update_unneeded_tiles(level);
         let selfie_mode = video.selfie_mode();
@@ -700,12 +1000,29 @@ fn track_found_words(
 
 impl FoundWordsState {
     fn update_unneeded_tiles(&mut self, level: &DesignedLevel) {
-        self.unneeded_tiles = level.calculate_unneeded_tiles(self.unneeded_tiles, |index| {
-            self.word_completions
-                .get(index)
-                .map(|x| x.is_complete())
-                .unwrap_or(true)
-        });
+        if self.hard_mode {
+            return;
+        }
+        let mut found_words = self.found_words_mask();
+        //words without a completion are treated as found
+        for index in self.word_completions.len()..level.words.len() {
+            found_words |= found_word_bit(index);
+        }
+        self.unneeded_tiles = level.unneeded_tiles_after_finding(self.unneeded_tiles, found_words);
+    }
+
+    /// The index of the unfound word which a hint would help most with
+    pub fn recommended_hint(&self, level: &DesignedLevel) -> Option<usize> {
+        recommend_hint(level, self.unneeded_tiles, self.found_words_mask())
+    }
+
+    /// A bitmask of the words which have been found
+    pub fn found_words_mask(&self) -> FoundWordsMask {
+        self.word_completions
+            .iter()
+            .enumerate()
+            .filter(|(_, completion)| completion.is_complete())
+            .fold(0, |mask, (index, _)| mask | found_word_bit(index))
     }
 
     /// Inadvisable tiles are tiles that are selectable, but can't lead to a solution
@@ -714,6 +1031,9 @@ impl FoundWordsState {
         current_solution: &Solution,
         level: &DesignedLevel,
     ) -> GridSet {
+        if self.hard_mode {
+            return GridSet::EMPTY;
+        }
         let mut selectable = match current_solution.last() {
             Some(tile) => GridSet::from_iter(tile.iter_adjacent()),
             None => GridSet::ALL,
@@ -725,8 +1045,7 @@ impl FoundWordsState {
 
         let mut inadvisable = selectable.intersect(&self.unneeded_tiles.negate());
 
-        let chosen_characters: ArrayVec<Character, 16> =
-            current_solution.iter().map(|x| level.grid[*x]).collect();
+        let chosen_characters = level.selection_characters(current_solution);
 
         let mut slices = self
             .word_completions
@@ -807,11 +1126,11 @@ impl FoundWordsState {
             return 0;
         };
 
-        chosen
-            .solution
+        level
+            .selection_characters(&chosen.solution)
             .iter()
             .zip(word.characters.iter())
-            .take_while(|(tile, character)| level.grid[**tile] == **character)
+            .take_while(|(a, b)| a == b)
             .count()
     }
 
@@ -819,7 +1138,8 @@ impl FoundWordsState {
     fn count_inevitable_characters(&self, level: &DesignedLevel, word_index: usize) -> usize {
         if let Some(completion) = self.word_completions.get(word_index) {
             let prefix_characters = match completion {
-                Completion::Unstarted => 0,
+                Completion::Unstarted | Completion::LengthHinted => 0,
+                Completion::StartHinted => 1,
                 Completion::ManualHinted(a) => a.get(),
                 Completion::Complete { .. } => return 0,
             };
@@ -999,9 +1319,11 @@ pub mod tests {
     use crate::{
         chosen_state::ChosenState,
         prelude::{Completion, DesignedLevel, FoundWordsState},
-        state::HintState,
+        state::{HintEvent, HintState, HintType},
         view::PopupState,
     };
+    use strum::IntoEnumIterator;
+    use ws_levels::level_sequence::LevelSequence;
 
     #[test]
     pub fn test_inevitable_characters() {
@@ -1032,7 +1354,10 @@ pub mod tests {
 
         for (index, completion) in found_words.word_completions.iter_mut().enumerate() {
             if index != 1 {
-                *completion = Completion::Complete { index: 0 };
+                *completion = Completion::Complete {
+                    index: 0,
+                    player: None,
+                };
             }
         }
 
@@ -1082,4 +1407,244 @@ pub mod tests {
             &Completion::ManualHinted(NonZeroUsize::new(1).unwrap())
         );
     }
+
+    #[test]
+    pub fn test_digraph_hints() {
+        let level =
+            DesignedLevel::from_tsv_line("(QU)EENTIDEXXXXXXXX\tDigraphs\tQUEEN\tTIDE").unwrap();
+
+        let mut found_words = FoundWordsState::new_from_level(&level);
+        let mut popup_state = PopupState(None);
+        let mut hint_state = HintState {
+            hints_remaining: 10,
+            total_bought_hints: 0,
+        };
+        let mut chosen_state = ChosenState::default();
+        let mut event_writer = TestEventWriter::default();
+
+        for (expected_letters, expected_tiles) in [(2, 1), (3, 2)] {
+            assert!(found_words.try_hint_word(
+                &mut hint_state,
+                &level,
+                0,
+                &mut chosen_state,
+                &mut event_writer,
+                SelfieMode {
+                    is_selfie_mode: false,
+                },
+                &mut popup_state,
+                true,
+            ));
+
+            assert_eq!(
+                found_words.get_completion(0),
+                Completion::ManualHinted(NonZeroUsize::new(expected_letters).unwrap())
+            );
+            assert_eq!(chosen_state.solution.len(), expected_tiles);
+        }
+    }
+
+    #[test]
+    pub fn test_hint_types() {
+        let level = DesignedLevel::from_tsv_line(
+            // spellchecker:disable-next-line
+            "SWEDLVNEOMAI_RKA	5	Denmark 	Romania 	Slovakia	Slovenia	Sweden",
+        )
+        .unwrap();
+
+        let mut found_words = FoundWordsState::new_from_level(&level);
+        let mut popup_state = PopupState(None);
+        let mut hint_state = HintState {
+            hints_remaining: 10,
+            total_bought_hints: 0,
+        };
+        let mut chosen_state = ChosenState::default();
+        let mut event_writer = TestEventWriter::default();
+
+        let mut use_hint = |found_words: &mut FoundWordsState,
+                            hint_state: &mut HintState,
+                            popup_state: &mut PopupState,
+                            word_index: usize,
+                            hint_type: HintType| {
+            found_words.try_use_hint(
+                hint_state,
+                &level,
+                HintEvent {
+                    word_index,
+                    hint_type,
+                },
+                &mut chosen_state,
+                &mut event_writer,
+                SelfieMode {
+                    is_selfie_mode: false,
+                },
+                popup_state,
+                true,
+            )
+        };
+
+        // The level does not hide word lengths so there is nothing to reveal
+        assert!(!use_hint(
+            &mut found_words,
+            &mut hint_state,
+            &mut popup_state,
+            4,
+            HintType::WordLength
+        ));
+        assert_eq!(hint_state.hints_remaining, 10);
+
+        assert!(use_hint(
+            &mut found_words,
+            &mut hint_state,
+            &mut popup_state,
+            4,
+            HintType::StartingTile
+        ));
+        assert_eq!(hint_state.hints_remaining, 9);
+        assert_eq!(found_words.get_completion(4), Completion::StartHinted);
+        assert_eq!(
+            found_words
+                .manual_hint_set(&level, &Default::default())
+                .count(),
+            1
+        );
+
+        assert!(use_hint(
+            &mut found_words,
+            &mut hint_state,
+            &mut popup_state,
+            4,
+            HintType::NextLetter
+        ));
+        assert_eq!(hint_state.hints_remaining, 8);
+        assert_eq!(
+            found_words.get_completion(4),
+            Completion::ManualHinted(NonZeroUsize::new(1).unwrap())
+        );
+
+        assert!(use_hint(
+            &mut found_words,
+            &mut hint_state,
+            &mut popup_state,
+            0,
+            HintType::FullPath
+        ));
+        assert_eq!(hint_state.hints_remaining, 6);
+        assert_eq!(found_words.revealed_tiles.count(), 7);
+
+        assert!(use_hint(
+            &mut found_words,
+            &mut hint_state,
+            &mut popup_state,
+            0,
+            HintType::NeededTiles
+        ));
+        assert_eq!(hint_state.hints_remaining, 4);
+        assert_eq!(found_words.revealed_tiles.count(), 15);
+        assert_eq!(found_words.hints_used, 6);
+
+        hint_state.hints_remaining = 1;
+        assert!(!use_hint(
+            &mut found_words,
+            &mut hint_state,
+            &mut popup_state,
+            1,
+            HintType::FullPath
+        ));
+        assert_eq!(hint_state.hints_remaining, 1);
+        assert!(popup_state.0.is_some());
+    }
+
+    #[test]
+    pub fn test_follow_recommended_hints() {
+        for level in LevelSequence::iter().flat_map(|sequence| sequence.get_level(0)) {
+            let mut found_words = FoundWordsState::new_from_level(level);
+            let mut popup_state = PopupState(None);
+            let mut hint_state = HintState::default();
+            let mut chosen_state = ChosenState::default();
+            let mut event_writer = TestEventWriter::default();
+            let max_hints: usize = level.words.iter().map(|x| x.characters.len()).sum();
+
+            for _ in 0..max_hints {
+                let Some(event) = HintEvent::recommended(level, &found_words, HintType::NextLetter)
+                else {
+                    break;
+                };
+                assert!(!found_words.get_completion(event.word_index).is_complete());
+
+                let hinted = found_words.try_use_hint(
+                    &mut hint_state,
+                    level,
+                    event,
+                    &mut chosen_state,
+                    &mut event_writer,
+                    SelfieMode {
+                        is_selfie_mode: false,
+                    },
+                    &mut popup_state,
+                    false,
+                );
+                assert!(hinted, "Could not hint {event:?} in {level}");
+            }
+
+            assert!(found_words.is_level_complete(), "{level} was not completed");
+            assert_eq!(hint_state, HintState::default());
+        }
+    }
+
+    #[test]
+    pub fn test_hard_mode() {
+        let level = DesignedLevel::from_tsv_line(
+            // spellchecker:disable-next-line
+            "DNGLHUAOSTRPAIYC	Europe Countries 2	Austria 	Croatia 	Cyprus  	Hungary 	Poland  	Portugal",
+        )
+        .unwrap();
+
+        for hard_mode in [false, true] {
+            let mut found_words = FoundWordsState::new_from_level(&level).with_hard_mode(hard_mode);
+
+            for (index, completion) in found_words.word_completions.iter_mut().enumerate() {
+                if index != 1 {
+                    *completion = Completion::Complete {
+                        index: 0,
+                        player: None,
+                    };
+                }
+            }
+            found_words.update_unneeded_tiles(&level);
+
+            let inadvisable = found_words.calculate_inadvisable_tiles(&Solution::default(), &level);
+
+            if hard_mode {
+                assert!(found_words.unneeded_tiles.is_empty());
+                assert!(inadvisable.is_empty());
+            } else {
+                assert!(!found_words.unneeded_tiles.is_empty());
+                assert!(!inadvisable.is_empty());
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_hint_type_cycles_through_every_type() {
+        let mut hint_type = HintType::default();
+        let mut seen = vec![];
+        loop {
+            seen.push(hint_type.name());
+            hint_type = hint_type.next();
+            if hint_type == HintType::default() {
+                break;
+            }
+        }
+        assert_eq!(
+            seen,
+            [
+                "Next Letter",
+                "Starting Tile",
+                "Full Path",
+                "Needed Tiles",
+                "Word Length"
+            ]
+        );
+    }
 }
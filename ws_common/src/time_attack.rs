@@ -0,0 +1,358 @@
+use std::time::Duration;
+
+use crate::prelude::*;
+use itertools::Either;
+use nice_bevy_utils::{CanInitTrackedResource, TrackableResource};
+use serde::{Deserialize, Serialize};
+
+pub struct TimeAttackPlugin;
+
+impl Plugin for TimeAttackPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_tracked_resource::<TimeAttackState>();
+
+        app.add_systems(
+            Update,
+            start_time_attack_levels.run_if(|s: Res<TimeAttackState>| s.run.is_some()),
+        );
+        app.add_systems(
+            PostUpdate,
+            record_left_levels
+                .before(crate::state::handle_change_level_event)
+                .run_if(|t: Res<LevelTime>| t.is_time_attack()),
+        );
+        app.add_systems(
+            Update,
+            add_time_attack_bonuses.run_if(|e: EventReader<WordFoundEvent>| !e.is_empty()),
+        );
+        app.add_systems(
+            Update,
+            check_time_attack_timeout.run_if(|t: Res<LevelTime>| t.is_time_attack()),
+        );
+    }
+}
+
+/// Seconds allowed for a level with a difficulty score of zero
+const BASE_SECONDS: f32 = 30.0;
+/// Extra seconds allowed for each point of difficulty score
+const SECONDS_PER_DIFFICULTY: f32 = 30.0;
+/// Seconds added to the clock whenever a word is found
+pub const TIME_ATTACK_BONUS: Duration = Duration::from_secs(5);
+/// Points for completing a level, on top of a point for each word found
+const LEVEL_COMPLETE_POINTS: usize = 5;
+
+/// The time allowed for a level in a time attack run, based on its difficulty
+pub fn time_attack_budget(level: &DesignedLevel) -> Duration {
+    let difficulty = level.difficulty();
+    Duration::from_secs_f32(BASE_SECONDS + (difficulty.score * SECONDS_PER_DIFFICULTY))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TimeAttackRun {
+    pub levels_complete: usize,
+    pub words_found: usize,
+}
+
+impl TimeAttackRun {
+    pub fn score(&self) -> usize {
+        self.words_found + (self.levels_complete * LEVEL_COMPLETE_POINTS)
+    }
+}
+
+#[derive(
+    Debug, Clone, Resource, Serialize, Deserialize, MavericContext, PartialEq, Eq, Default,
+)]
+pub struct TimeAttackState {
+    /// The run in progress, if there is one
+    pub run: Option<TimeAttackRun>,
+    /// The highest score of any finished run
+    pub best_score: usize,
+    /// The time left on each level which was left during the run before its countdown ended
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub left_levels: Vec<(CurrentLevel, Duration)>,
+}
+
+impl TrackableResource for TimeAttackState {
+    const KEY: &'static str = "TimeAttack";
+}
+
+impl TimeAttackState {
+    pub fn start_run(&mut self) {
+        self.run = Some(TimeAttackRun::default());
+        self.left_levels.clear();
+    }
+
+    /// Ends the run in progress and records its score
+    pub fn finish_run(&mut self) -> Option<TimeAttackRun> {
+        let run = self.run.take()?;
+        self.best_score = self.best_score.max(run.score());
+        self.left_levels.clear();
+        Some(run)
+    }
+
+    /// Remember the time left on a level which is being left mid-countdown
+    pub fn leave_level(&mut self, level: &CurrentLevel, remaining: Duration) {
+        if self.run.is_none() {
+            return;
+        }
+        self.left_levels.retain(|(l, _)| l != level);
+        self.left_levels.push((level.clone(), remaining));
+    }
+
+    /// The time left on a level when it was last left during this run
+    pub fn take_left_level(&mut self, level: &CurrentLevel) -> Option<Duration> {
+        let index = self.left_levels.iter().position(|(l, _)| l == level)?;
+        Some(self.left_levels.remove(index).1)
+    }
+}
+
+/// Start the countdown for each unfinished level played during a run
+/// A level which was left mid-countdown carries on from the time it had left
+fn start_time_attack_levels(
+    current_level: Res<CurrentLevel>,
+    daily_challenges: Res<DailyChallenges>,
+    found_words: Res<FoundWordsState>,
+    mut state: ResMut<TimeAttackState>,
+    mut level_time: ResMut<LevelTime>,
+) {
+    if level_time.is_time_attack() || level_time.is_finished() || found_words.is_level_complete() {
+        return;
+    }
+
+    let Either::Left(level) = current_level.level(&daily_challenges) else {
+        return;
+    };
+
+    let budget = state
+        .take_left_level(&current_level)
+        .unwrap_or_else(|| time_attack_budget(level));
+    level_time.start_time_attack(budget, chrono::Utc::now());
+}
+
+fn record_left_levels(
+    mut events: EventReader<ChangeLevelEvent>,
+    current_level: Res<CurrentLevel>,
+    level_time: Res<LevelTime>,
+    mut state: ResMut<TimeAttackState>,
+) {
+    let leaving = events.read().any(|event| match event {
+        ChangeLevelEvent::ChangeTo(level) => level != current_level.as_ref(),
+        ChangeLevelEvent::Reset => false,
+    });
+
+    if let Some(remaining) = level_time.remaining().filter(|_| leaving) {
+        state.leave_level(&current_level, remaining);
+    }
+}
+
+fn add_time_attack_bonuses(
+    mut events: EventReader<WordFoundEvent>,
+    mut state: ResMut<TimeAttackState>,
+    mut level_time: ResMut<LevelTime>,
+    found_words: Res<FoundWordsState>,
+) {
+    let mut words_found = 0;
+    for event in events.read() {
+        if event.is_first_time {
+            words_found += 1;
+        }
+    }
+
+    let Some(run) = state.run.as_mut() else {
+        return;
+    };
+    if words_found == 0 {
+        return;
+    }
+
+    run.words_found += words_found;
+    if found_words.is_level_complete() {
+        run.levels_complete += 1;
+    }
+    level_time.add_time_attack_bonus(TIME_ATTACK_BONUS * words_found as u32);
+}
+
+fn check_time_attack_timeout(
+    mut state: ResMut<TimeAttackState>,
+    mut level_time: ResMut<LevelTime>,
+    mut change_level_events: EventWriter<ChangeLevelEvent>,
+) {
+    if level_time.remaining() != Some(Duration::ZERO) {
+        return;
+    }
+
+    *level_time = LevelTime::Finished {
+        elapsed: level_time.total_elapsed(),
+    };
+
+    let best_score = state.best_score;
+    if let Some(run) = state.finish_run() {
+        change_level_events
+            .send(CurrentLevel::NonLevel(NonLevel::TimeAttackOver { run, best_score }).into());
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+
+    fn at(start: DateTime<Utc>, seconds: i64) -> DateTime<Utc> {
+        start + chrono::Duration::try_seconds(seconds).unwrap()
+    }
+
+    #[test]
+    pub fn test_countdown() {
+        let start = Utc::now();
+        let mut level_time = LevelTime::Running {
+            since: start,
+            additional: Duration::from_secs(100),
+        };
+
+        level_time.start_time_attack(Duration::from_secs(60), start);
+        assert_eq!(
+            level_time.remaining_at(at(start, 10)),
+            Some(Duration::from_secs(50))
+        );
+
+        level_time.pause_timer_at(at(start, 10));
+        assert!(level_time.is_stopped());
+        assert_eq!(
+            level_time.remaining_at(at(start, 1000)),
+            Some(Duration::from_secs(50))
+        );
+
+        level_time.resume_timer_at(at(start, 1000));
+        level_time.add_time_attack_bonus(TIME_ATTACK_BONUS);
+        assert!(level_time.is_ticking());
+        assert_eq!(
+            level_time.remaining_at(at(start, 1020)),
+            Some(Duration::from_secs(35))
+        );
+        assert_eq!(
+            level_time.remaining_at(at(start, 2000)),
+            Some(Duration::ZERO)
+        );
+
+        level_time.stop_time_attack(at(start, 1020));
+        assert_eq!(level_time.remaining_at(at(start, 1020)), None);
+        assert_eq!(
+            level_time.total_elapsed_at(at(start, 1030)),
+            Duration::from_secs(40)
+        );
+    }
+
+    #[test]
+    pub fn test_paused_timer_starts_paused() {
+        let start = Utc::now();
+        let mut level_time = LevelTime::Paused {
+            elapsed: Duration::ZERO,
+        };
+
+        level_time.start_time_attack(Duration::from_secs(60), start);
+        assert!(level_time.is_stopped());
+        assert_eq!(
+            level_time.remaining_at(at(start, 30)),
+            Some(Duration::from_secs(60))
+        );
+    }
+
+    #[test]
+    pub fn test_budget_depends_on_difficulty() {
+        let levels = [
+            // spellchecker:disable-next-line
+            "SWEDLVNEOMAI_RKA	5	Denmark 	Romania 	Slovakia	Slovenia	Sweden",
+            // spellchecker:disable-next-line
+            "DNGLHUAOSTRPAIYC	Europe Countries 2	Austria 	Croatia 	Cyprus  	Hungary 	Poland  	Portugal",
+        ]
+        .map(|line| DesignedLevel::from_tsv_line(line).unwrap());
+
+        let [first, second] = levels.map(|level| {
+            let budget = time_attack_budget(&level);
+            assert!(budget >= Duration::from_secs_f32(BASE_SECONDS));
+            (calculate_difficulty(&level).score, budget)
+        });
+
+        assert_eq!(first.0 < second.0, first.1 < second.1);
+    }
+
+    #[test]
+    pub fn test_finish_run() {
+        let mut state = TimeAttackState::default();
+        assert_eq!(state.finish_run(), None);
+
+        state.start_run();
+        if let Some(run) = state.run.as_mut() {
+            run.words_found = 7;
+            run.levels_complete = 1;
+        }
+        let run = state.finish_run().unwrap();
+        assert_eq!(run.score(), 12);
+        assert_eq!(state.best_score, 12);
+        assert_eq!(state.run, None);
+
+        state.start_run();
+        state.finish_run();
+        assert_eq!(state.best_score, 12);
+    }
+
+    #[test]
+    pub fn test_running_out_of_time_ends_the_run() {
+        let mut app = App::new();
+        let mut state = TimeAttackState::default();
+        state.start_run();
+
+        app.insert_resource(state);
+        app.insert_resource(LevelTime::TimeAttack {
+            since: Some(Utc::now()),
+            additional: Duration::from_secs(10),
+            budget: Duration::from_secs(10),
+        });
+        app.add_event::<ChangeLevelEvent>();
+        app.add_systems(Update, check_time_attack_timeout);
+        app.update();
+
+        assert_eq!(app.world.resource::<TimeAttackState>().run, None);
+        assert!(app.world.resource::<LevelTime>().is_finished());
+
+        let events = app.world.resource::<Events<ChangeLevelEvent>>();
+        let mut reader = events.get_reader();
+        let sent: Vec<_> = reader.read(events).collect();
+        assert!(matches!(
+            sent.as_slice(),
+            [ChangeLevelEvent::ChangeTo(CurrentLevel::NonLevel(
+                NonLevel::TimeAttackOver { best_score: 0, .. }
+            ))]
+        ));
+    }
+
+    #[test]
+    pub fn test_leaving_a_level_keeps_its_time() {
+        let level = CurrentLevel::DailyChallenge { index: 3 };
+        let mut app = App::new();
+        let mut state = TimeAttackState::default();
+        state.start_run();
+
+        app.insert_resource(state);
+        app.insert_resource(level.clone());
+        app.insert_resource(LevelTime::TimeAttack {
+            since: None,
+            additional: Duration::from_secs(20),
+            budget: Duration::from_secs(60),
+        });
+        app.add_event::<ChangeLevelEvent>();
+        app.add_systems(Update, record_left_levels);
+
+        app.world
+            .send_event::<ChangeLevelEvent>(CurrentLevel::DailyChallenge { index: 4 }.into());
+        app.update();
+
+        let mut state = app.world.resource_mut::<TimeAttackState>();
+        assert_eq!(
+            state.left_levels,
+            vec![(level.clone(), Duration::from_secs(40))]
+        );
+        assert_eq!(state.take_left_level(&level), Some(Duration::from_secs(40)));
+        assert_eq!(state.take_left_level(&level), None);
+    }
+}
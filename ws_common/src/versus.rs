@@ -0,0 +1,448 @@
+use std::time::Duration;
+
+use crate::prelude::*;
+use nice_bevy_utils::{CanInitTrackedResource, TrackableResource};
+use serde::{Deserialize, Serialize};
+use strum::EnumIs;
+
+pub struct VersusPlugin;
+
+impl Plugin for VersusPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_tracked_resource::<VersusSettings>();
+        app.init_resource::<VersusTurn>();
+
+        app.add_systems(
+            Update,
+            reset_versus_turn.run_if(|c: Res<CurrentLevel>| c.is_changed()),
+        );
+        app.add_systems(
+            Update,
+            credit_versus_words.run_if(|e: EventReader<WordFoundEvent>| !e.is_empty()),
+        );
+        app.add_systems(
+            Update,
+            (pass_timed_out_turns, update_turn_text)
+                .chain()
+                .run_if(|f: Res<FoundWordsState>| f.versus.is_some()),
+        );
+    }
+}
+
+pub const MIN_PLAYERS: u8 = 2;
+pub const MAX_PLAYERS: u8 = 4;
+
+const PLAYER_NAMES: [&str; MAX_PLAYERS as usize] = ["Player 1", "Player 2", "Player 3", "Player 4"];
+
+pub fn player_name(player: u8) -> &'static str {
+    PLAYER_NAMES[player as usize % PLAYER_NAMES.len()]
+}
+
+pub fn player_color(player: u8) -> BasicColor {
+    palette::VERSUS_PLAYERS[player as usize % palette::VERSUS_PLAYERS.len()]
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, EnumIs)]
+pub enum VersusTurns {
+    /// The turn passes when the player finds a word or runs out of time
+    #[default]
+    OneWord,
+    /// Each player has the grid until their time runs out
+    Timed,
+}
+
+impl VersusTurns {
+    pub fn turn_duration(&self) -> Duration {
+        match self {
+            VersusTurns::OneWord => Duration::from_secs(30),
+            VersusTurns::Timed => Duration::from_secs(60),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VersusRules {
+    pub players: u8,
+    pub turns: VersusTurns,
+}
+
+impl VersusRules {
+    /// The number of words found by each player
+    pub fn scores(&self, word_completions: &[Completion]) -> Vec<usize> {
+        let mut scores = vec![0; self.players as usize];
+        for completion in word_completions {
+            if let Completion::Complete {
+                player: Some(player),
+                ..
+            } = completion
+            {
+                if let Some(score) = scores.get_mut(*player as usize) {
+                    *score += 1;
+                }
+            }
+        }
+        scores
+    }
+}
+
+/// Versus mode lets players on one device take turns to find words.
+/// It applies to levels started after it is changed.
+#[derive(
+    Debug, Clone, Resource, Serialize, Deserialize, MavericContext, PartialEq, Eq, Default,
+)]
+pub struct VersusSettings {
+    /// The number of players, or zero if versus mode is off
+    pub players: u8,
+    pub timed_turns: bool,
+}
+
+impl TrackableResource for VersusSettings {
+    const KEY: &'static str = "VersusSettings";
+}
+
+impl VersusSettings {
+    pub fn rules(&self) -> Option<VersusRules> {
+        if self.players < MIN_PLAYERS {
+            return None;
+        }
+        let turns = if self.timed_turns {
+            VersusTurns::Timed
+        } else {
+            VersusTurns::OneWord
+        };
+        Some(VersusRules {
+            players: self.players.min(MAX_PLAYERS),
+            turns,
+        })
+    }
+
+    /// Go from off to two, three and four players, then back to off
+    pub fn cycle_players(&mut self) {
+        self.players = match self.players {
+            p if p < MIN_PLAYERS => MIN_PLAYERS,
+            p if p >= MAX_PLAYERS => 0,
+            p => p + 1,
+        };
+    }
+}
+
+/// Whose turn it is.
+/// Turns are timed using the level time so they stop while the level timer is paused.
+#[derive(Debug, Clone, Resource, PartialEq, Eq, Default)]
+pub struct VersusTurn {
+    pub active_player: u8,
+    /// The level time when the active player's turn began
+    pub since: Option<Duration>,
+}
+
+impl VersusTurn {
+    pub fn new(level_elapsed: Duration) -> Self {
+        Self {
+            active_player: 0,
+            since: Some(level_elapsed),
+        }
+    }
+
+    pub fn remaining_at(&self, rules: &VersusRules, level_elapsed: Duration) -> Duration {
+        let elapsed = self
+            .since
+            .map(|since| level_elapsed.saturating_sub(since))
+            .unwrap_or_default();
+        rules.turns.turn_duration().saturating_sub(elapsed)
+    }
+
+    /// Hand the device to the next player
+    pub fn pass(&mut self, rules: &VersusRules, level_elapsed: Duration) {
+        self.active_player = (self.active_player + 1) % rules.players.max(1);
+        self.since = Some(level_elapsed);
+    }
+
+    /// Returns the player to credit with the word
+    pub fn on_word_found(&mut self, rules: &VersusRules, level_elapsed: Duration) -> u8 {
+        let player = self.active_player;
+        if rules.turns.is_one_word() {
+            self.pass(rules, level_elapsed);
+        }
+        player
+    }
+
+    pub fn is_out_of_time(&self, rules: &VersusRules, level_elapsed: Duration) -> bool {
+        self.since.is_some() && self.remaining_at(rules, level_elapsed).is_zero()
+    }
+}
+
+fn reset_versus_turn(mut turn: ResMut<VersusTurn>, level_time: Res<LevelTime>) {
+    *turn = VersusTurn::new(level_time.total_elapsed());
+}
+
+fn credit_versus_words(
+    mut events: EventReader<WordFoundEvent>,
+    mut found_words: ResMut<FoundWordsState>,
+    mut turn: ResMut<VersusTurn>,
+    level_time: Res<LevelTime>,
+) {
+    let rules = found_words.versus;
+    for event in events.read() {
+        let Some(rules) = rules else {
+            continue;
+        };
+        if !event.is_first_time {
+            continue;
+        }
+        let Some(word_index) = event
+            .level
+            .words
+            .iter()
+            .position(|word| word.characters == event.word.characters)
+        else {
+            continue;
+        };
+
+        let credited = turn.on_word_found(&rules, level_time.total_elapsed());
+        if let Some(Completion::Complete { player, .. }) =
+            found_words.word_completions.get_mut(word_index)
+        {
+            *player = Some(credited);
+        }
+    }
+}
+
+fn pass_timed_out_turns(
+    found_words: Res<FoundWordsState>,
+    mut turn: ResMut<VersusTurn>,
+    level_time: Res<LevelTime>,
+) {
+    let Some(rules) = found_words.versus else {
+        return;
+    };
+    if found_words.is_level_complete() {
+        return;
+    }
+
+    let elapsed = level_time.total_elapsed();
+    // The level time goes backwards when the level is reset
+    if turn.since.map_or(true, |since| since > elapsed) {
+        *turn = VersusTurn::new(elapsed);
+    } else if turn.is_out_of_time(&rules, elapsed) {
+        turn.pass(&rules, elapsed);
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Component)]
+pub struct VersusTurnMarker;
+
+fn update_turn_text(
+    mut query: Query<&mut Text, With<VersusTurnMarker>>,
+    found_words: Res<FoundWordsState>,
+    turn: Res<VersusTurn>,
+    level_time: Res<LevelTime>,
+) {
+    let Some(rules) = found_words.versus else {
+        return;
+    };
+    let remaining = turn.remaining_at(&rules, level_time.total_elapsed());
+    let value = format!(
+        "{} {}",
+        player_name(turn.active_player),
+        format_seconds(remaining.as_secs())
+    );
+    let color = player_color(turn.active_player).convert_color();
+
+    for mut text in query.iter_mut() {
+        if let Some(section) = text.sections.first_mut() {
+            section.value.clone_from(&value);
+            section.style.color = color;
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    const THREE_PLAYERS: VersusRules = VersusRules {
+        players: 3,
+        turns: VersusTurns::OneWord,
+    };
+
+    fn at(seconds: u64) -> Duration {
+        Duration::from_secs(seconds)
+    }
+
+    #[test]
+    pub fn test_one_word_turns() {
+        let mut turn = VersusTurn::new(at(0));
+
+        assert_eq!(turn.on_word_found(&THREE_PLAYERS, at(5)), 0);
+        assert_eq!(turn.on_word_found(&THREE_PLAYERS, at(10)), 1);
+        assert_eq!(turn.on_word_found(&THREE_PLAYERS, at(15)), 2);
+        assert_eq!(turn.on_word_found(&THREE_PLAYERS, at(20)), 0);
+        assert_eq!(turn.active_player, 1);
+        assert_eq!(
+            turn.remaining_at(&THREE_PLAYERS, at(30)),
+            Duration::from_secs(20)
+        );
+    }
+
+    #[test]
+    pub fn test_timed_turns() {
+        let rules = VersusRules {
+            players: 2,
+            turns: VersusTurns::Timed,
+        };
+        let mut turn = VersusTurn::new(at(0));
+
+        assert_eq!(turn.on_word_found(&rules, at(5)), 0);
+        assert_eq!(turn.on_word_found(&rules, at(10)), 0);
+        assert!(!turn.is_out_of_time(&rules, at(59)));
+        assert!(turn.is_out_of_time(&rules, at(60)));
+
+        turn.pass(&rules, at(60));
+        assert_eq!(turn.on_word_found(&rules, at(61)), 1);
+        turn.pass(&rules, at(120));
+        assert_eq!(turn.active_player, 0);
+    }
+
+    #[test]
+    pub fn test_cycle_players() {
+        let mut settings = VersusSettings::default();
+        assert_eq!(settings.rules(), None);
+
+        let players: Vec<_> = (0..5)
+            .map(|_| {
+                settings.cycle_players();
+                settings.rules().map(|rules| rules.players)
+            })
+            .collect();
+        assert_eq!(players, vec![Some(2), Some(3), Some(4), None, Some(2)]);
+    }
+
+    #[test]
+    pub fn test_scores() {
+        let completions = [
+            Completion::Complete {
+                index: 0,
+                player: Some(1),
+            },
+            Completion::Complete {
+                index: 1,
+                player: Some(2),
+            },
+            Completion::Complete {
+                index: 2,
+                player: Some(1),
+            },
+            Completion::Unstarted,
+        ];
+
+        assert_eq!(THREE_PLAYERS.scores(&completions), vec![0, 2, 1]);
+    }
+
+    // spellchecker:disable-next-line
+    const LEVEL: &str = "SWEDLVNEOMAI_RKA	5	Denmark 	Romania 	Slovakia	Slovenia	Sweden";
+
+    fn test_app(rules: VersusRules) -> App {
+        let level = DesignedLevel::from_tsv_line(LEVEL).unwrap();
+        let mut app = App::new();
+        app.insert_resource(FoundWordsState::new_from_level(&level).with_versus(Some(rules)));
+        app.insert_resource(CurrentLevel::default());
+        app.init_resource::<VersusTurn>();
+        app.insert_resource(LevelTime::Paused {
+            elapsed: Duration::ZERO,
+        });
+        app.add_event::<WordFoundEvent>();
+        app.add_systems(Update, credit_versus_words);
+        app.add_systems(Update, pass_timed_out_turns);
+        app
+    }
+
+    fn find_word(app: &mut App, word_index: usize) {
+        let level = DesignedLevel::from_tsv_line(LEVEL).unwrap();
+        let word = level.words[word_index].clone();
+        let solution = word.find_solution(&level.grid).unwrap();
+
+        let mut found_words = app.world.resource_mut::<FoundWordsState>();
+        found_words.word_completions[word_index] = Completion::Complete {
+            index: 0,
+            player: None,
+        };
+        app.world.send_event(WordFoundEvent {
+            solution,
+            is_first_time: true,
+            was_hinted: false,
+            word,
+            level,
+        });
+        app.update();
+    }
+
+    #[test]
+    pub fn test_found_words_are_credited_in_turn() {
+        let mut app = test_app(VersusRules {
+            players: 2,
+            turns: VersusTurns::OneWord,
+        });
+        app.update();
+
+        find_word(&mut app, 0);
+        find_word(&mut app, 3);
+        find_word(&mut app, 1);
+
+        let found_words = app.world.resource::<FoundWordsState>();
+        let players: Vec<_> = found_words
+            .word_completions
+            .iter()
+            .map(|completion| match completion {
+                Completion::Complete { player, .. } => *player,
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(players, vec![Some(0), Some(0), None, Some(1), None]);
+        assert_eq!(app.world.resource::<VersusTurn>().active_player, 1);
+    }
+
+    #[test]
+    pub fn test_turn_passes_when_time_runs_out() {
+        let rules = VersusRules {
+            players: 2,
+            turns: VersusTurns::Timed,
+        };
+        let mut app = test_app(rules);
+        app.update();
+        assert_eq!(app.world.resource::<VersusTurn>().active_player, 0);
+
+        app.insert_resource(LevelTime::Paused { elapsed: at(61) });
+        app.update();
+
+        let turn = app.world.resource::<VersusTurn>();
+        assert_eq!(turn.active_player, 1);
+        assert!(!turn.is_out_of_time(&rules, at(61)));
+    }
+
+    #[test]
+    pub fn test_turn_does_not_run_out_while_paused() {
+        let rules = VersusRules {
+            players: 2,
+            turns: VersusTurns::Timed,
+        };
+        let mut app = test_app(rules);
+        app.insert_resource(LevelTime::Paused { elapsed: at(30) });
+        app.insert_resource(VersusTurn::new(at(30)));
+
+        for _ in 0..3 {
+            app.update();
+        }
+        let turn = app.world.resource::<VersusTurn>();
+        assert_eq!(turn.active_player, 0);
+        assert_eq!(turn.remaining_at(&rules, at(30)), at(60));
+
+        let long_ago = chrono::Utc::now() - chrono::Duration::try_seconds(61).unwrap();
+        app.insert_resource(LevelTime::Running {
+            since: long_ago,
+            additional: at(30),
+        });
+        app.update();
+        assert_eq!(app.world.resource::<VersusTurn>().active_player, 1);
+    }
+}
@@ -32,6 +32,7 @@ pub struct CongratsContext {
     pub menu_state: MenuState,
     pub insets_resource: InsetsResource,
     pub chosen_state: ChosenState,
+    pub bonus_words: BonusWordsState,
 }
 
 impl<'a, 'w: 'a> From<&'a ViewContextWrapper<'w>> for CongratsContextWrapper<'w> {
@@ -49,6 +50,7 @@ impl<'a, 'w: 'a> From<&'a ViewContextWrapper<'w>> for CongratsContextWrapper<'w>
             menu_state: Res::clone(&value.menu_state),
             insets_resource: Res::clone(&value.insets),
             chosen_state: Res::clone(&value.chosen_state),
+            bonus_words: Res::clone(&value.bonus_words),
         }
     }
 }
@@ -106,8 +108,22 @@ impl MavericNode for CongratsView {
             enum Data {
                 None,
                 JustHints,
-                TodaysChallenge { streak: usize, longest: usize },
-                Sequence { complete: usize, remaining: usize },
+                TodaysChallenge {
+                    streak: usize,
+                    longest: usize,
+                },
+                Sequence {
+                    complete: usize,
+                    remaining: usize,
+                },
+                Versus {
+                    scores: [usize; 4],
+                    players: u8,
+                },
+                Challenge {
+                    rival_hints: usize,
+                    same_order: usize,
+                },
             }
 
             let data = match context.current_level.as_ref() {
@@ -141,10 +157,34 @@ impl MavericNode for CongratsView {
                         remaining,
                     }
                 }
-                CurrentLevel::Custom { .. } => Data::JustHints,
+                CurrentLevel::Custom { .. } | CurrentLevel::Endless { .. } => Data::JustHints,
                 CurrentLevel::NonLevel(_) => Data::None,
             };
 
+            let data = match context.found_words_state.versus {
+                Some(rules) => {
+                    let mut scores = [0; 4];
+                    for (score, player_score) in scores
+                        .iter_mut()
+                        .zip(rules.scores(&context.found_words_state.word_completions))
+                    {
+                        *score = player_score;
+                    }
+                    Data::Versus {
+                        scores,
+                        players: rules.players,
+                    }
+                }
+                None => match &context.found_words_state.challenge {
+                    Some(challenge) => Data::Challenge {
+                        rival_hints: challenge.hints_used as usize,
+                        same_order: challenge
+                            .same_order_count(&found_order(&context.found_words_state)),
+                    },
+                    None => data,
+                },
+            };
+
             let (initial_scale, transition) = if !context.chosen_state.is_just_finished
                 || context.menu_state.is_changed()
             {
@@ -243,13 +283,18 @@ impl MavericNode for CongratsView {
             }
             .convert_color();
 
-            if !context.current_level.is_tutorial() {
+            if !context.current_level.is_tutorial() && !context.found_words_state.zen_mode {
                 let rect = size.get_rect(&CongratsLayoutEntity::Time, &congrats_context);
 
+                let mut text = format_seconds(context.level_time.total_elapsed().as_secs());
+                if let Some(challenge) = &context.found_words_state.challenge {
+                    text = format!("{text} vs {}", format_seconds(challenge.seconds as u64));
+                }
+
                 commands.add_child(
                     "Timer",
                     TimerNode {
-                        text: format_seconds(context.level_time.total_elapsed().as_secs()),
+                        text,
                         text_color: stat_text_color,
                         text_font_size: size.font_size(&CongratsTimer, &selfie_mode),
                     }
@@ -268,11 +313,31 @@ impl MavericNode for CongratsView {
             let stat_number_font_size = size.font_size(&StatisticNumber, &selfie_mode);
             let stat_text_font_size = size.font_size(&StatisticLabel, &selfie_mode);
 
+            let bonus_words = context
+                .current_level
+                .level(&context.daily_challenges)
+                .left()
+                .map(|level| context.bonus_words.count_for_level(level))
+                .unwrap_or_default();
+
             for (index, statistic) in CongratsStatistic::iter().enumerate() {
+                let text_color = match data {
+                    Data::Versus { .. } => crate::versus::player_color(index as u8).convert_color(),
+                    _ => stat_text_color,
+                };
                 let data = match (statistic, data) {
+                    (_, Data::Versus { scores, players }) => (index < players as usize)
+                        .then(|| (scores[index], crate::versus::player_name(index as u8))),
+                    (CongratsStatistic::Middle, Data::Challenge { rival_hints, .. }) => {
+                        Some((rival_hints, "Rival Hints"))
+                    }
+                    (CongratsStatistic::Right, Data::Challenge { same_order, .. }) => {
+                        Some((same_order, "Same Order"))
+                    }
                     (_, Data::None)
                     | (CongratsStatistic::Left, Data::JustHints)
                     | (CongratsStatistic::Right, Data::JustHints) => None,
+                    (CongratsStatistic::Bonus, _) => Some((bonus_words, "Bonus")),
                     (CongratsStatistic::Left, _) | (CongratsStatistic::Middle, Data::JustHints) => {
                         Some((context.found_words_state.hints_used, "Hints"))
                     }
@@ -304,7 +369,7 @@ impl MavericNode for CongratsView {
                         rect,
                         number,
                         text: label,
-                        text_color: stat_text_color,
+                        text_color,
                         number_font_size: stat_number_font_size,
                         text_font_size: stat_text_font_size,
                     }
@@ -374,6 +439,7 @@ impl MavericNode for CongratsView {
                                     }
                                 }
                                 CurrentLevel::Custom { .. } => "Next".to_string(),
+                                CurrentLevel::Endless { .. } => "Next".to_string(),
                                 CurrentLevel::NonLevel(NonLevel::LevelSequenceAllFinished(_)) => {
                                     "Finish".to_string()
                                 }
@@ -12,6 +12,8 @@ use ws_core::Tile;
 use ws_core::{font_icons, prelude::*};
 
 pub const TILE_LINGER_SECONDS: f32 = 1.0;
+/// Digraph tiles have two letters so use a smaller font
+const DIGRAPH_FONT_SCALE: f32 = 0.7;
 
 #[derive(Debug, Clone, Copy, PartialEq, EnumIs)]
 pub enum Selectability {
@@ -93,6 +95,7 @@ pub struct GridTilesContext {
     pub video_resource: VideoResource,
     pub daily_challenges: DailyChallenges,
     pub insets: InsetsResource,
+    pub nudge_state: NudgeState,
 }
 
 impl<'a, 'w: 'a> From<&'a ViewContextWrapper<'w>> for GridTilesContextWrapper<'w> {
@@ -147,6 +150,7 @@ impl MavericNode for GridTiles {
                             .centre()
                             .extend(crate::z_indices::GRID_TILE);
                         let character = level.grid[tile];
+                        let digraph = level.digraphs.as_ref().and_then(|d| d[tile]);
                         let target_centre = size
                             .get_rect(
                                 &LayoutAnimatedTile { index },
@@ -215,6 +219,7 @@ impl MavericNode for GridTiles {
                             GridTile {
                                 tile,
                                 character,
+                                digraph,
                                 selectability: Selectability::Selectable,
                                 tile_size,
                                 font_size,
@@ -247,7 +252,11 @@ impl MavericNode for GridTiles {
                 .found_words_state
                 .calculate_inadvisable_tiles(solution, level);
 
-            let hint_set = &context.found_words_state.manual_hint_set(level, solution); //TODO this should reveal if a tile is previously hinted
+            //TODO this should reveal if a tile is previously hinted
+            let hint_set = &context
+                .found_words_state
+                .manual_hint_set(level, solution)
+                .union(&context.nudge_state.highlighted_tiles());
 
             let inadvisable_tiles = inadvisable_tiles.intersect(&hint_set.negate());
 
@@ -274,6 +283,7 @@ impl MavericNode for GridTiles {
                     GridTile {
                         tile,
                         character: *character,
+                        digraph: level.digraphs.as_ref().and_then(|d| d[tile]),
                         selectability,
                         tile_size,
                         font_size,
@@ -330,6 +340,7 @@ impl MavericNode for GridTiles {
 pub struct GridTile {
     pub tile: Tile,
     pub character: Character,
+    pub digraph: Option<Digraph>,
     pub selectability: Selectability,
     pub hint_status: HintStatus,
     pub tile_size: f32,
@@ -361,10 +372,18 @@ impl GridTile {
     fn get_letter_node(&self) -> impl MavericNode<Context = ()> {
         let color = Self::letter_color(self.is_selfie_mode, self.selectability.is_selected());
 
+        let (text, font_size) = match self.digraph {
+            Some(digraph) => (
+                digraph.to_tile_string(),
+                self.font_size * DIGRAPH_FONT_SCALE,
+            ),
+            None => (self.character.to_tile_string(), self.font_size),
+        };
+
         Text2DNode {
-            text: self.character.to_tile_string(),
+            text,
             font: TILE_FONT_PATH,
-            font_size: self.font_size,
+            font_size,
             color,
             justify_text: JustifyText::Center,
             linebreak_behavior: bevy::text::BreakLineOn::NoWrap,
@@ -22,6 +22,8 @@ struct HintsRemainingRoot;
 #[derive(Debug, NodeContext)]
 struct HintsRemainingContext {
     pub hints: HintState,
+    pub hint_settings: HintSettings,
+    pub found_words: FoundWordsState,
     pub window_size: MyWindowSize,
     pub video_resource: VideoResource,
     pub current_level: CurrentLevel,
@@ -57,11 +59,19 @@ impl MavericRootChildren for HintsRemainingRoot {
             return;
         }
 
-        if !is_word_button_pressed(&context.pressed_button) &&!context.current_level.is_changed() {
+        if !is_word_button_pressed(&context.pressed_button)
+            && !context.current_level.is_changed()
+            && context.found_words.revealed_tiles.is_empty()
+        {
             return;
         }
 
-        let text = context.hints.as_text();
+        let hint_type = context.hint_settings.hint_type;
+        let text = if hint_type.is_next_letter() {
+            context.hints.as_text()
+        } else {
+            format!("{}\n{}", context.hints.as_text(), hint_type.name())
+        };
 
         let font_size = context.window_size.font_size(&HintsRemainingLayout, &());
         let color = if context.video_resource.is_selfie_mode {
@@ -4,7 +4,9 @@ pub mod game_grid;
 pub mod hints;
 pub mod logo;
 pub mod menu;
+pub mod near_miss;
 pub mod non_level;
+pub mod nudges;
 pub mod popup;
 pub mod recording_button;
 pub mod theme_view;
@@ -17,7 +19,9 @@ pub use game_grid::*;
 pub use hints::*;
 pub use logo::*;
 pub use menu::*;
+pub use near_miss::*;
 pub use non_level::*;
+pub use nudges::*;
 pub use popup::*;
 pub use recording_button::*;
 pub use theme_view::*;
@@ -27,6 +31,7 @@ pub use words::*;
 
 use crate::{completion::*, prelude::*};
 use maveric::prelude::*;
+use ws_core::layout::entities::level_info_entity::ThemeLengths;
 
 /// Resource that will touched when a redraw is requested
 #[derive(Debug, Resource, MavericContext, Default, Clone, Copy)]
@@ -49,6 +54,7 @@ pub struct ViewContext {
     pub prices: Prices,
     pub redraw_marker: RedrawMarker,
     pub insets: InsetsResource,
+    pub bonus_words: BonusWordsState,
 }
 
 #[derive(MavericRoot)]
@@ -71,7 +77,7 @@ impl MavericRootChildren for ViewRoot {
 
         let pause_type = if !context.menu_state.is_closed() {
             PauseType::Blank
-        } else if context.level_time.is_paused() {
+        } else if context.level_time.is_stopped() {
             PauseType::BlankWithPlay
         } else {
             PauseType::NotPaused
@@ -109,7 +115,7 @@ impl MavericRootChildren for ViewRoot {
 
                     commands.add_child("words", WordsNode, &context.into());
 
-                    if !context.level_time.is_paused() {
+                    if !context.level_time.is_stopped() {
                         let close_to_solution = context
                             .chosen_state
                             .is_close_to_a_solution(level, context.found_words_state.as_ref());
@@ -149,15 +155,41 @@ impl MavericRootChildren for ViewRoot {
                         &context.window_size,
                     );
 
-                    commands.add_child(
-                        "timer",
-                        TimerView {
-                            background_type,
-                            selfie_mode,
-                            insets: context.insets.0,
-                        },
-                        &context.window_size,
-                    )
+                    if let Some(challenge) = &context.found_words_state.challenge {
+                        commands.add_child(
+                            "challenge_banner",
+                            ChallengeBannerView {
+                                text: challenge_banner_text(challenge),
+                                theme_lengths: ThemeLengths {
+                                    full_name_characters: full_name.len(),
+                                },
+                                selfie_mode,
+                                insets: context.insets.0,
+                            },
+                            &context.window_size,
+                        );
+                    }
+
+                    if context.found_words_state.versus.is_some() {
+                        commands.add_child(
+                            "versus_turn",
+                            VersusTurnView {
+                                selfie_mode,
+                                insets: context.insets.0,
+                            },
+                            &context.window_size,
+                        )
+                    } else if !context.found_words_state.zen_mode {
+                        commands.add_child(
+                            "timer",
+                            TimerView {
+                                background_type,
+                                selfie_mode,
+                                insets: context.insets.0,
+                            },
+                            &context.window_size,
+                        )
+                    }
                 }
             }
             itertools::Either::Right(non_level) => {
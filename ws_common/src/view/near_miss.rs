@@ -0,0 +1,89 @@
+use std::time::Duration;
+
+use maveric::transition::speed::calculate_speed;
+use ws_core::layout::entities::*;
+
+use crate::{prelude::*, shapes};
+
+pub struct NearMissViewPlugin;
+impl Plugin for NearMissViewPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_maveric::<NearMissRoot>();
+    }
+}
+
+const FADE_SECONDS: f32 = 1.5;
+
+#[derive(MavericRoot)]
+struct NearMissRoot;
+
+#[derive(Debug, NodeContext)]
+struct NearMissContext {
+    pub feedback: NearMissFeedback,
+    pub current_level: CurrentLevel,
+    pub daily_challenges: DailyChallenges,
+    pub found_words: FoundWordsState,
+    pub window_size: MyWindowSize,
+    pub video_resource: VideoResource,
+    pub menu: MenuState,
+    pub insets_resource: InsetsResource,
+}
+
+impl MavericRootChildren for NearMissRoot {
+    type Context = NearMissContext;
+
+    fn set_children(
+        context: &<Self::Context as NodeContext>::Wrapper<'_>,
+        commands: &mut impl ChildCommands,
+    ) {
+        if !context.menu.is_closed() {
+            return;
+        }
+
+        let Some(word_index) = context.feedback.word_index else {
+            return;
+        };
+
+        if context.found_words.get_completion(word_index).is_complete() {
+            return;
+        }
+
+        let Some(level) = context
+            .current_level
+            .level(&context.daily_challenges)
+            .left()
+        else {
+            return;
+        };
+
+        let selfie_mode = context.video_resource.selfie_mode();
+        let rect = context.window_size.get_rect(
+            &LayoutWordTile(word_index),
+            &(
+                level.words.as_slice(),
+                (selfie_mode, context.insets_resource.0),
+            ),
+        );
+
+        let color = palette::WORD_BACKGROUND_NEAR_MISS.convert_color();
+        let faded = color.with_a(0.0);
+
+        commands.add_child(
+            context.feedback.count as u32,
+            shapes::basic_box_node1(
+                rect.extents.x,
+                rect.extents.y,
+                rect.centre()
+                    .extend(crate::z_indices::WORD_BACKGROUND + 1.0),
+                color,
+                crate::rounding::WORD_BUTTON_NORMAL,
+            )
+            .with_transition_to::<ShaderColorLens>(
+                faded,
+                calculate_speed(&color, &faded, Duration::from_secs_f32(FADE_SECONDS)),
+                None,
+            ),
+            &(),
+        );
+    }
+}
@@ -113,6 +113,20 @@ impl MavericNode for NonLevelView {
 
 
                 },
+                NonLevel::TimeAttackOver { run, best_score } => {
+                    let best = if run.score() > best_score {
+                        "New Best!".to_string()
+                    } else {
+                        format!("Best {best_score}")
+                    };
+                    format!(
+                        "Time's Up!\n{} Levels, {} Words\nScore {}\n{best}",
+                        run.levels_complete,
+                        run.words_found,
+                        run.score()
+                    )
+                }
+                NonLevel::EndlessGenerating { .. } => "Generating Puzzle".to_string(),
             };
 
             let text_color = if selfie_mode {
@@ -176,6 +190,8 @@ impl MavericNode for NonLevelView {
                     }
                 },
                 NonLevel::PleaseBuyTheGame => Some("Get the App".to_string()),
+                NonLevel::TimeAttackOver { .. } => Some("Play Again".to_string()),
+                NonLevel::EndlessGenerating { .. } => None,
             };
 
             let (fill_color, border) = if selfie_mode {
@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+use bevy::text::Text2dBounds;
+use maveric::{widgets::text2d_node::Text2DNode, with_bundle::CanWithBundle};
+use ws_core::layout::entities::HintsRemainingLayout;
+
+use crate::{prelude::*, z_indices};
+
+pub struct NudgeViewPlugin;
+impl Plugin for NudgeViewPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_maveric::<NudgeRoot>();
+    }
+}
+
+const FADE_SECONDS: f32 = 1.0;
+
+#[derive(MavericRoot)]
+struct NudgeRoot;
+
+#[derive(Debug, NodeContext)]
+struct NudgeContext {
+    pub nudge_state: NudgeState,
+    pub window_size: MyWindowSize,
+    pub video_resource: VideoResource,
+    pub menu: MenuState,
+    pub insets_resource: InsetsResource,
+}
+
+impl MavericRootChildren for NudgeRoot {
+    type Context = NudgeContext;
+
+    fn set_children(
+        context: &<Self::Context as NodeContext>::Wrapper<'_>,
+        commands: &mut impl ChildCommands,
+    ) {
+        if !context.menu.is_closed() {
+            return;
+        }
+
+        let Some(text) = context.nudge_state.nudge.and_then(|x| x.text()) else {
+            return;
+        };
+
+        let font_size = context.window_size.font_size(&HintsRemainingLayout, &());
+        let color = if context.video_resource.is_selfie_mode {
+            palette::HINTS_REMAINING_TEXT_COLOR_SELFIE
+        } else {
+            palette::HINTS_REMAINING_TEXT_COLOR_NORMAL
+        }
+        .convert_color();
+
+        let rect = context.window_size.get_rect(
+            &HintsRemainingLayout,
+            &(
+                context.video_resource.selfie_mode(),
+                context.insets_resource.0,
+            ),
+        );
+
+        commands.add_child(
+            text,
+            Text2DNode {
+                text,
+                font: THEME_FONT_PATH,
+                font_size,
+                color,
+                justify_text: JustifyText::Center,
+                linebreak_behavior: bevy::text::BreakLineOn::WordBoundary,
+                text_anchor: bevy::sprite::Anchor::Center,
+                text_2d_bounds: Text2dBounds::UNBOUNDED,
+            }
+            .with_bundle(Transform::from_translation(
+                rect.centre().extend(z_indices::HINTS_REMAINING),
+            ))
+            .with_transition_in_out::<TextColorLens<0>>(
+                color.with_a(0.0),
+                color,
+                color.with_a(0.0),
+                Duration::from_secs_f32(FADE_SECONDS),
+                Duration::from_secs_f32(FADE_SECONDS),
+                None,
+                None,
+            ),
+            &(),
+        );
+    }
+}
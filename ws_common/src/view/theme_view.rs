@@ -60,6 +60,105 @@ impl MavericNode for TimerView {
     }
 }
 
+/// Shows whose turn it is in versus mode, in place of the timer
+#[derive(Debug, Clone, PartialEq)]
+pub struct VersusTurnView {
+    pub selfie_mode: SelfieMode,
+    pub insets: Insets,
+}
+
+impl MavericNode for VersusTurnView {
+    type Context = MyWindowSize;
+
+    fn set_components(mut commands: SetComponentCommands<Self, Self::Context>) {
+        commands.insert_static_bundle(SpatialBundle::default());
+    }
+
+    fn set_children<R: MavericRoot>(commands: SetChildrenCommands<Self, Self::Context, R>) {
+        commands.unordered_children_with_node_and_context(|node, context, commands| {
+            let font_size = context.font_size(&TimerLayoutEntity, &());
+
+            commands.add_child(
+                "turn",
+                Text2DNode {
+                    text: crate::versus::player_name(0),
+                    font_size,
+                    color: crate::versus::player_color(0).convert_color(),
+                    font: TIMER_FONT_PATH,
+                    justify_text: JustifyText::Center,
+                    linebreak_behavior: bevy::text::BreakLineOn::NoWrap,
+                    text_2d_bounds: Default::default(),
+                    text_anchor: bevy::sprite::Anchor::Center,
+                }
+                .with_bundle((
+                    Transform::from_translation(
+                        context
+                            .get_rect(&TimerLayoutEntity, &(node.selfie_mode, node.insets))
+                            .top_centre()
+                            .extend(crate::z_indices::THEME),
+                    ),
+                    VersusTurnMarker,
+                )),
+                &(),
+            );
+        })
+    }
+}
+
+/// Shows the result to beat when playing a level from a challenge link
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChallengeBannerView {
+    pub text: String,
+    pub theme_lengths: ThemeLengths,
+    pub selfie_mode: SelfieMode,
+    pub insets: Insets,
+}
+
+impl MavericNode for ChallengeBannerView {
+    type Context = MyWindowSize;
+
+    fn set_components(mut commands: SetComponentCommands<Self, Self::Context>) {
+        commands.insert_static_bundle(SpatialBundle::default());
+    }
+
+    fn set_children<R: MavericRoot>(commands: SetChildrenCommands<Self, Self::Context, R>) {
+        commands.unordered_children_with_node_and_context(|node, context, commands| {
+            let font_size =
+                context.font_size(&LevelInfoLayoutEntity::ThemeInfo, &node.theme_lengths);
+            let color = if node.selfie_mode.is_selfie_mode {
+                palette::THEME_INFO_COLOR_SELFIE
+            } else {
+                palette::THEME_TITLE_COLOR_INCOMPLETE_NORMAL
+            }
+            .convert_color();
+
+            // The theme info is on the left of the same line
+            let rect = context.get_rect(
+                &LevelInfoLayoutEntity::ThemeInfo,
+                &((node.selfie_mode, node.insets), IsLevelComplete(false)),
+            );
+
+            commands.add_child(
+                "banner",
+                Text2DNode {
+                    text: node.text.clone(),
+                    font_size,
+                    color,
+                    font: THEME_INFO_FONT_PATH,
+                    justify_text: JustifyText::Right,
+                    linebreak_behavior: bevy::text::BreakLineOn::NoWrap,
+                    text_2d_bounds: Default::default(),
+                    text_anchor: bevy::sprite::Anchor::CenterRight,
+                }
+                .with_bundle(Transform::from_translation(
+                    rect.centre_right().extend(crate::z_indices::THEME),
+                )),
+                &(),
+            );
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ThemeView {
     pub full_name: Ustr,
@@ -92,6 +92,7 @@ impl MavericNode for WordsNode {
                         CurrentLevel::Tutorial { index } => (2, *index as u16),
                         CurrentLevel::DailyChallenge { index } => (3, *index as u16),
                         CurrentLevel::NonLevel(..) => (4, 0),
+                        CurrentLevel::Endless { seed } => (5, *seed as u16),
                     };
 
                     commands.add_child(
@@ -104,6 +105,8 @@ impl MavericNode for WordsNode {
                             font_size,
                             selfie_mode,
                             menu_closed: context.menu_state.is_closed(),
+                            hide_length: level.metadata.hide_word_lengths
+                                || context.found_words_state.hard_mode,
                         },
                         &(),
                     );
@@ -121,6 +124,8 @@ pub struct WordNode {
     pub font_size: f32,
     pub selfie_mode: SelfieMode,
     pub menu_closed: bool,
+    /// Show a question mark instead of the word length until the word is started
+    pub hide_length: bool,
 }
 
 impl MavericNode for WordNode {
@@ -141,8 +146,10 @@ impl MavericNode for WordNode {
             let completion = node.completion;
 
             let progress = match completion {
-                Completion::Unstarted => 0.0,
-                Completion::ManualHinted(_) => 0.0,
+                Completion::Unstarted
+                | Completion::LengthHinted
+                | Completion::StartHinted
+                | Completion::ManualHinted(_) => 0.0,
                 Completion::Complete { .. } => 1.0,
             };
 
@@ -150,7 +157,10 @@ impl MavericNode for WordNode {
 
             if node.menu_closed {
                 let text = match node.completion {
-                    Completion::Unstarted => node.word.hidden_text.to_string(),
+                    Completion::Unstarted if node.hide_length => "?".to_string(),
+                    Completion::Unstarted | Completion::LengthHinted | Completion::StartHinted => {
+                        node.word.hidden_text.to_string()
+                    }
                     Completion::ManualHinted(hints) => node.word.hinted_text(hints).to_uppercase(),
 
                     Completion::Complete { .. } => node.word.text.to_uppercase().to_string(),
@@ -159,7 +169,9 @@ impl MavericNode for WordNode {
                 let text_translation = centre.extend(crate::z_indices::WORD_TEXT);
 
                 let text_color = match completion {
-                    Completion::Unstarted => palette::WORD_TEXT_NUMBER,
+                    Completion::Unstarted | Completion::LengthHinted | Completion::StartHinted => {
+                        palette::WORD_TEXT_NUMBER
+                    }
                     Completion::ManualHinted(_) | Completion::Complete { .. } => {
                         palette::WORD_TEXT_LETTERS
                     }
@@ -196,8 +208,10 @@ impl MavericNode for WordNode {
             let _shape_border_translation = centre.extend(crate::z_indices::WORD_BACKGROUND + 1.0);
 
             let transition_speed = match completion {
-                Completion::Unstarted => f32::MAX,
-                Completion::ManualHinted(_) => f32::MAX,
+                Completion::Unstarted
+                | Completion::LengthHinted
+                | Completion::StartHinted
+                | Completion::ManualHinted(_) => f32::MAX,
                 Completion::Complete { .. } => 1.0 / animated_solutions::TOTAL_SECONDS,
             };
 
@@ -252,6 +266,14 @@ pub struct WordButtonCompletion {
 
 pub const WORD_BUTTON_HOLD_SECONDS: f32 = 0.3;
 
+/// Words found in versus mode are coloured by the player who found them
+fn complete_background(player: Option<u8>) -> Color {
+    match player {
+        Some(player) => crate::versus::player_color(player).convert_color(),
+        None => palette::WORD_BACKGROUND_COMPLETE.convert_color(),
+    }
+}
+
 #[repr(C)]
 #[derive(Debug, Reflect, Clone, Copy, Default, PartialEq)]
 pub struct WordButtonBoxShader;
@@ -302,13 +324,13 @@ impl ExtractToShader for WordButtonBoxShader {
                 palette::WORD_BACKGROUND_UNSTARTED.convert_color()
             } else {
                 match completion {
-                    Completion::Unstarted => palette::WORD_BACKGROUND_UNSTARTED.convert_color(),
-                    Completion::ManualHinted(_) => {
-                        palette::WORD_BACKGROUND_MANUAL_HINT.convert_color()
+                    Completion::Unstarted | Completion::LengthHinted => {
+                        palette::WORD_BACKGROUND_UNSTARTED.convert_color()
                     }
-                    Completion::Complete { .. } => {
-                        palette::WORD_BACKGROUND_COMPLETE.convert_color()
+                    Completion::StartHinted | Completion::ManualHinted(_) => {
+                        palette::WORD_BACKGROUND_MANUAL_HINT.convert_color()
                     }
+                    Completion::Complete { player, .. } => complete_background(*player),
                 }
             };
 
@@ -316,13 +338,13 @@ impl ExtractToShader for WordButtonBoxShader {
                 palette::WORD_BACKGROUND_UNSTARTED.convert_color()
             } else {
                 match completion {
-                    Completion::Unstarted => palette::WORD_BACKGROUND_PROGRESS.convert_color(),
-                    Completion::ManualHinted(_) => {
-                        palette::WORD_BACKGROUND_MANUAL_HINT2.convert_color()
+                    Completion::Unstarted | Completion::LengthHinted => {
+                        palette::WORD_BACKGROUND_PROGRESS.convert_color()
                     }
-                    Completion::Complete { .. } => {
-                        palette::WORD_BACKGROUND_COMPLETE.convert_color()
+                    Completion::StartHinted | Completion::ManualHinted(_) => {
+                        palette::WORD_BACKGROUND_MANUAL_HINT2.convert_color()
                     }
+                    Completion::Complete { player, .. } => complete_background(*player),
                 }
             };
 
@@ -342,12 +364,16 @@ impl ExtractToShader for WordButtonBoxShader {
                 palette::WORD_BACKGROUND_UNSTARTED.convert_color()
             } else {
                 match completion {
-                    Completion::Unstarted => palette::WORD_BACKGROUND_UNSTARTED.convert_color(),
-                    Completion::ManualHinted(_) => {
+                    Completion::Unstarted | Completion::LengthHinted => {
+                        palette::WORD_BACKGROUND_UNSTARTED.convert_color()
+                    }
+                    Completion::StartHinted | Completion::ManualHinted(_) => {
                         palette::WORD_BACKGROUND_MANUAL_HINT.convert_color()
                     }
                     Completion::Complete { .. } => {
-                        if previous_completion.is_some_and(|x| x.is_manual_hinted()) {
+                        if previous_completion
+                            .is_some_and(|x| x.is_manual_hinted() || x.is_start_hinted())
+                        {
                             palette::WORD_BACKGROUND_MANUAL_HINT.convert_color()
                         } else {
                             palette::WORD_BACKGROUND_UNSTARTED.convert_color()
@@ -360,13 +386,13 @@ impl ExtractToShader for WordButtonBoxShader {
                 palette::WORD_BACKGROUND_UNSTARTED.convert_color()
             } else {
                 match completion {
-                    Completion::Unstarted => palette::WORD_BACKGROUND_UNSTARTED.convert_color(),
-                    Completion::ManualHinted(_) => {
-                        palette::WORD_BACKGROUND_MANUAL_HINT.convert_color()
+                    Completion::Unstarted | Completion::LengthHinted => {
+                        palette::WORD_BACKGROUND_UNSTARTED.convert_color()
                     }
-                    Completion::Complete { .. } => {
-                        palette::WORD_BACKGROUND_COMPLETE.convert_color()
+                    Completion::StartHinted | Completion::ManualHinted(_) => {
+                        palette::WORD_BACKGROUND_MANUAL_HINT.convert_color()
                     }
+                    Completion::Complete { player, .. } => complete_background(*player),
                 }
             };
 
@@ -115,6 +115,14 @@ pub fn get_daily_from_location() -> Option<usize> {
     try_daily_index_from_path(path.as_str())
 }
 
+pub fn get_endless_seed_from_location() -> Option<u64> {
+    let window = web_sys::window()?;
+    let location = window.location();
+    let path = location.pathname().ok()?;
+
+    try_endless_seed_from_path(path.as_str())
+}
+
 pub fn get_game_from_location() -> Option<DesignedLevel> {
     let window = web_sys::window()?;
     let location = window.location();
@@ -123,6 +131,14 @@ pub fn get_game_from_location() -> Option<DesignedLevel> {
     DesignedLevel::try_from_path(path.as_ref())
 }
 
+pub fn get_challenge_from_location() -> Option<ReceivedChallenge> {
+    let window = web_sys::window()?;
+    let location = window.location();
+    let path = location.pathname().ok()?;
+
+    try_received_challenge_from_path(path.as_str())
+}
+
 pub fn open_link(url: &str) {
     use web_sys::window;
 
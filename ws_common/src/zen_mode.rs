@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use nice_bevy_utils::{CanInitTrackedResource, TrackableResource};
+use serde::{Deserialize, Serialize};
+
+pub struct ZenModePlugin;
+
+impl Plugin for ZenModePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_tracked_resource::<ZenModeSettings>();
+    }
+}
+
+/// Zen mode hides the timer and skips anything based on how long a level took.
+/// It applies to levels started after it is changed.
+#[derive(
+    Debug, Clone, Resource, Serialize, Deserialize, MavericContext, PartialEq, Eq, Default,
+)]
+pub struct ZenModeSettings {
+    pub enabled: bool,
+}
+
+impl TrackableResource for ZenModeSettings {
+    const KEY: &'static str = "ZenModeSettings";
+}
@@ -1,11 +1,12 @@
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
 use ws_core::{
+    dictionary::WordAutomata,
     finder::{
         counter::FakeCounter,
-        helpers::{FinderSingleWord, LetterCounts},
+        helpers::{FinderDigraphs, FinderSingleWord, LetterCounts},
         node::try_make_grid_with_blank_filling,
     },
-    Character,
+    Character, DesignedLevel, WordTrait,
 };
 
 fn set_up(input: &str) -> (LetterCounts, Vec<FinderSingleWord>) {
@@ -76,6 +77,7 @@ pub fn criterion_benchmark(c: &mut Criterion) {
                     i.0,
                     &i.1,
                     &exclude_words,
+                    &FinderDigraphs::default(),
                     Character::E,
                     &mut FakeCounter,
                     &mut solution,
@@ -85,5 +87,62 @@ pub fn criterion_benchmark(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, criterion_benchmark);
+pub fn find_solutions_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Find Solutions");
+    // spellchecker:disable
+    let sports = (
+        "Sports",
+        "ASHPKILOEUIOGNDT\tSports\tPOLO\tSHOOTING\tKENDO\tSAILING\tLUGE\tSKIING",
+    );
+    let countries = (
+        "Counties of Ireland",
+        "KGRROEAYDNLAUBIW\tCounties of Ireland\tDerry\tDonegal\tDublin\tGalway\tKerry",
+    );
+    // spellchecker:enable
+
+    for (name, data) in [sports, countries] {
+        let level = DesignedLevel::from_tsv_line(data).unwrap();
+
+        group.bench_with_input(BenchmarkId::new("Reference: ", name), &level, |b, level| {
+            b.iter(|| {
+                level
+                    .words
+                    .iter()
+                    .map(|word| word.find_solutions_sized(&level.grid).len())
+                    .sum::<usize>()
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("Bitboard: ", name), &level, |b, level| {
+            b.iter(|| {
+                level
+                    .words
+                    .iter()
+                    .map(|word| word.iter_solutions(&level.grid).count())
+                    .sum::<usize>()
+            })
+        });
+    }
+}
+
+pub fn load_dictionary_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Load Dictionary");
+    group.sample_size(10);
+    let text = include_str!("../../grid_finder/english_words.txt");
+    let bytes = WordAutomata::from_word_list(text, 4).to_bytes();
+
+    group.bench_function("From text", |b| {
+        b.iter(|| WordAutomata::from_word_list(text, 4))
+    });
+    group.bench_function("From bytes", |b| {
+        b.iter(|| WordAutomata::try_from_bytes(&bytes).unwrap())
+    });
+}
+
+criterion_group!(
+    benches,
+    criterion_benchmark,
+    find_solutions_benchmark,
+    load_dictionary_benchmark
+);
 criterion_main!(benches);
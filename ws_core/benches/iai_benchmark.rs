@@ -2,10 +2,10 @@ use iai_callgrind::{library_benchmark, library_benchmark_group, main};
 use ws_core::{
     finder::{
         counter::FakeCounter,
-        helpers::{FinderSingleWord, LetterCounts},
+        helpers::{FinderDigraphs, FinderSingleWord, LetterCounts},
         node::try_make_grid_with_blank_filling,
     },
-    Character,
+    Character, DesignedLevel, WordTrait,
 };
 
 fn set_up(input: &str) -> (LetterCounts, Vec<FinderSingleWord>) {
@@ -55,11 +55,30 @@ fn solve_grid(input: (LetterCounts, Vec<FinderSingleWord>)) {
         input.0,
         &input.1,
         &exclude_words,
+        &FinderDigraphs::default(),
         Character::E,
         &mut FakeCounter,
         &mut solution,
     )
 }
 
+// spellchecker:disable
+#[library_benchmark]
+#[bench::sports(DesignedLevel::from_tsv_line(
+    "ASHPKILOEUIOGNDT\tSports\tPOLO\tSHOOTING\tKENDO\tSAILING\tLUGE\tSKIING"
+).unwrap())]
+#[bench::ireland(DesignedLevel::from_tsv_line(
+    "KGRROEAYDNLAUBIW\tCounties of Ireland\tDerry\tDonegal\tDublin\tGalway\tKerry"
+).unwrap())]
+// spellchecker:enable
+fn find_solutions(level: DesignedLevel) -> usize {
+    level
+        .words
+        .iter()
+        .map(|word| word.iter_solutions(&level.grid).count())
+        .sum()
+}
+
 library_benchmark_group!(name= solve_group; benchmarks=solve_grid);
-main!(library_benchmark_groups = solve_group);
+library_benchmark_group!(name= find_solutions_group; benchmarks=find_solutions);
+main!(library_benchmark_groups = solve_group, find_solutions_group);
@@ -0,0 +1,275 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use arrayvec::ArrayVec;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use strum::{EnumIter, IntoEnumIterator};
+
+use crate::Character;
+
+/// The game characters making up a single grapheme
+pub type GraphemeCharacters = ArrayVec<Character, 2>;
+
+/// The alphabet used to turn text into game characters.
+/// Letters with diacritics are folded to their base letter (é -> E)
+/// unless the alphabet keeps them as letters of their own (å -> Å)
+/// or replaces them with their conventional spelling (ü -> UE)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(try_from = "AlphabetData", into = "AlphabetData")]
+pub struct Alphabet {
+    kept_letters: BTreeSet<Character>,
+    transliterations: BTreeMap<char, GraphemeCharacters>,
+}
+
+/// Alphabets which levels can use by name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumIter)]
+pub enum AlphabetPreset {
+    English,
+    Spanish,
+    German,
+    Nordic,
+    Russian,
+}
+
+impl AlphabetPreset {
+    pub fn alphabet(&self) -> Alphabet {
+        use Character::*;
+        match self {
+            AlphabetPreset::English => Alphabet::default(),
+            AlphabetPreset::Spanish => Alphabet::default().with_kept_letters([NTilde]),
+            AlphabetPreset::German => Alphabet::default()
+                .with_transliteration('ä', [A, E].into())
+                .with_transliteration('ö', [O, E].into())
+                .with_transliteration('ü', [U, E].into())
+                .with_transliteration('ß', [S, S].into()),
+            AlphabetPreset::Nordic => Alphabet::default()
+                .with_kept_letters([ARing, AUmlaut, OUmlaut])
+                .with_transliteration('æ', [A, E].into())
+                .with_transliteration('ø', [O, E].into()),
+            AlphabetPreset::Russian => Alphabet::default().with_kept_letters([CyrillicShortI]),
+        }
+    }
+}
+
+impl From<AlphabetPreset> for Alphabet {
+    fn from(value: AlphabetPreset) -> Self {
+        value.alphabet()
+    }
+}
+
+impl Alphabet {
+    /// Keep these letters rather than folding them to their base letter
+    pub fn with_kept_letters(mut self, letters: impl IntoIterator<Item = Character>) -> Self {
+        self.kept_letters
+            .extend(letters.into_iter().filter(|x| !x.is_blank()));
+        self
+    }
+
+    /// Replace a letter (in either case) with these characters
+    pub fn with_transliteration(mut self, letter: char, characters: GraphemeCharacters) -> Self {
+        for c in letter.to_lowercase() {
+            self.transliterations.insert(c, characters.clone());
+        }
+        self
+    }
+
+    /// The preset this alphabet is equal to, if any
+    pub fn preset(&self) -> Option<AlphabetPreset> {
+        AlphabetPreset::iter().find(|x| x.alphabet() == *self)
+    }
+
+    /// Whether this alphabet keeps the letter rather than folding it
+    pub fn keeps(&self, character: Character) -> bool {
+        self.kept_letters.contains(&character)
+    }
+
+    pub fn kept_letters(&self) -> impl Iterator<Item = Character> + '_ {
+        self.kept_letters.iter().copied()
+    }
+
+    /// Each replaced letter, in lower case, with its replacement
+    pub fn transliterations(&self) -> impl Iterator<Item = (char, &GraphemeCharacters)> + '_ {
+        self.transliterations
+            .iter()
+            .map(|(c, characters)| (*c, characters))
+    }
+
+    /// Convert a grapheme into game characters.
+    /// Returns an empty array for blank graphemes such as spaces and punctuation
+    pub fn grapheme_characters(&self, grapheme: &str) -> Result<GraphemeCharacters, &'static str> {
+        let mut result = GraphemeCharacters::new();
+
+        if let Some(c) = Self::single_char(grapheme) {
+            if let Ok(character) = Character::try_from(c) {
+                if self.keeps(character) {
+                    result.push(character);
+                    return Ok(result);
+                }
+            }
+
+            let lower = c.to_lowercase().next().unwrap_or(c);
+            if let Some(characters) = self.transliterations.get(&lower) {
+                return Ok(characters.clone());
+            }
+        }
+
+        let mut normalized = unicode_normalization::UnicodeNormalization::nfd(grapheme);
+
+        let Some(c) = normalized.next() else {
+            return Ok(result);
+        };
+        let character = Character::try_from(c)?;
+        if !character.is_blank() {
+            result.push(character);
+        }
+        Ok(result)
+    }
+
+    /// The grapheme as a single composed char, if it is one
+    fn single_char(grapheme: &str) -> Option<char> {
+        let mut chars = unicode_normalization::UnicodeNormalization::nfc(grapheme);
+        let c = chars.next()?;
+        chars.next().is_none().then_some(c)
+    }
+}
+
+/// How alphabets are written in level files - either a preset name or the full configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+enum AlphabetData {
+    Preset(AlphabetPreset),
+    Custom {
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        kept_letters: String,
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        transliterations: BTreeMap<String, String>,
+    },
+}
+
+impl From<Alphabet> for AlphabetData {
+    fn from(value: Alphabet) -> Self {
+        if let Some(preset) = value.preset() {
+            return AlphabetData::Preset(preset);
+        }
+
+        AlphabetData::Custom {
+            kept_letters: value.kept_letters().map(|x| x.as_char()).collect(),
+            transliterations: value
+                .transliterations()
+                .map(|(letter, characters)| {
+                    (
+                        letter.to_string(),
+                        characters.iter().map(|x| x.as_char()).collect(),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<AlphabetData> for Alphabet {
+    type Error = String;
+
+    fn try_from(value: AlphabetData) -> Result<Self, Self::Error> {
+        let (kept_letters, transliterations) = match value {
+            AlphabetData::Preset(preset) => return Ok(preset.alphabet()),
+            AlphabetData::Custom {
+                kept_letters,
+                transliterations,
+            } => (kept_letters, transliterations),
+        };
+
+        let to_letter = |c: char| match Character::try_from(c) {
+            Ok(character) if !character.is_blank() => Ok(character),
+            _ => Err(format!("'{c}' is not a letter")),
+        };
+
+        let kept_letters: Vec<Character> = kept_letters.chars().map(to_letter).try_collect()?;
+        let mut alphabet = Alphabet::default().with_kept_letters(kept_letters);
+
+        for (letter, replacement) in transliterations {
+            let Some(letter) = letter.chars().exactly_one().ok() else {
+                return Err(format!("'{letter}' is not a single letter"));
+            };
+            let characters: Vec<Character> = replacement.chars().map(to_letter).try_collect()?;
+            let Ok(characters) = GraphemeCharacters::try_from(characters.as_slice()) else {
+                return Err(format!("'{replacement}' is longer than two letters"));
+            };
+            alphabet = alphabet.with_transliteration(letter, characters);
+        }
+
+        Ok(alphabet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::normalize_characters_array_with_alphabet;
+    use test_case::test_case;
+
+    /* spellchecker:disable */
+    #[test_case(AlphabetPreset::English, "Dalí", "DALI")]
+    #[test_case(AlphabetPreset::English, "Müller", "MULLER")]
+    #[test_case(AlphabetPreset::English, "Señor", "SENOR")]
+    #[test_case(AlphabetPreset::Spanish, "Señor", "SEÑOR")]
+    #[test_case(AlphabetPreset::Spanish, "Dalí", "DALI")]
+    #[test_case(AlphabetPreset::German, "Müller", "MUELLER")]
+    #[test_case(AlphabetPreset::German, "Straße", "STRASSE")]
+    #[test_case(AlphabetPreset::German, "Café", "CAFE")]
+    #[test_case(AlphabetPreset::English, "Ålesund", "ALESUND")]
+    #[test_case(AlphabetPreset::German, "Malmö", "MALMOE")]
+    #[test_case(AlphabetPreset::Nordic, "Ålesund", "ÅLESUND")]
+    #[test_case(AlphabetPreset::Nordic, "Malmö", "MALMÖ")]
+    #[test_case(AlphabetPreset::Nordic, "Ärla", "ÄRLA")]
+    #[test_case(AlphabetPreset::Nordic, "Færøerne", "FAEROEERNE")]
+    #[test_case(AlphabetPreset::Nordic, "Dalí", "DALI")]
+    #[test_case(AlphabetPreset::Russian, "Москва", "МОСКВА")]
+    #[test_case(AlphabetPreset::Russian, "Йошкар-Ола", "ЙОШКАРОЛА")]
+    #[test_case(AlphabetPreset::Russian, "Ёлка", "ЕЛКА")]
+    #[test_case(AlphabetPreset::English, "Йошкар-Ола", "ИОШКАРОЛА")]
+    fn test_alphabet(preset: AlphabetPreset, input: &str, expected: &str) {
+        let actual = normalize_characters_array_with_alphabet(input, &preset.alphabet())
+            .unwrap()
+            .iter()
+            .map(|x| x.as_char())
+            .join("");
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    pub fn test_custom_alphabet() {
+        let yaml = "kept_letters: ÅÖ\ntransliterations:\n  Þ: TH\n";
+        let alphabet: Alphabet = serde_yaml::from_str(yaml).unwrap();
+
+        let actual = normalize_characters_array_with_alphabet("Þórsmörk Åre", &alphabet)
+            .unwrap()
+            .iter()
+            .map(|x| x.as_char())
+            .join("");
+        assert_eq!(actual, "THORSMÖRKÅRE");
+
+        assert_eq!(alphabet.preset(), None);
+        let round_tripped: Alphabet =
+            serde_yaml::from_str(&serde_yaml::to_string(&alphabet).unwrap()).unwrap();
+        assert_eq!(round_tripped, alphabet);
+    }
+
+    #[test]
+    pub fn test_presets_serialize_by_name() {
+        for preset in AlphabetPreset::iter() {
+            let yaml = serde_yaml::to_string(&preset.alphabet()).unwrap();
+            assert_eq!(yaml.trim(), format!("{preset:?}"));
+
+            let alphabet: Alphabet = serde_yaml::from_str(&yaml).unwrap();
+            assert_eq!(alphabet.preset(), Some(preset));
+        }
+    }
+
+    #[test]
+    pub fn test_invalid_custom_alphabet() {
+        assert!(serde_yaml::from_str::<Alphabet>("kept_letters: Å1").is_err());
+        assert!(serde_yaml::from_str::<Alphabet>("transliterations:\n  Þ: THR\n").is_err());
+    }
+}
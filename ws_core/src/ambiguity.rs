@@ -0,0 +1,192 @@
+use itertools::Itertools;
+
+use crate::prelude::*;
+
+/// Extra tile sets beyond this many don't make a word any more confusing
+const MAX_COUNTED_ALTERNATIVES: usize = 3;
+/// Extra weight for ambiguity which changes which letters fall
+const FALLING_PENALTY: f32 = 1.0;
+
+/// A word which can be traced using more than one set of tiles
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordAmbiguity {
+    pub word_index: usize,
+    /// Each distinct set of tiles the word can be traced with
+    pub tile_sets: Vec<GridSet>,
+    /// Tiles which are used by some of the tile sets but not all of them
+    pub contested_tiles: GridSet,
+    /// Contested tiles which fall at the start of the level
+    pub falling_at_start: GridSet,
+    /// Contested tiles which fall once every other word has been found
+    pub falling_when_last: GridSet,
+}
+
+impl WordAmbiguity {
+    /// How this ambiguity interacts with hints and letters falling
+    pub fn explanation(&self, level: &DesignedLevel) -> String {
+        let word = &level.words[self.word_index].text;
+        let mut text = format!(
+            "'{word}' can be traced using {count} sets of tiles which differ on \
+{letters}. Hints show only one of these paths.",
+            count = self.tile_sets.len(),
+            letters = tiles_text(level, self.contested_tiles)
+        );
+
+        if !self.falling_at_start.is_empty() {
+            text.push_str(
+                format!(
+                    " {letters} will fall before any word is found so some paths are never usable.",
+                    letters = tiles_text(level, self.falling_at_start)
+                )
+                .as_str(),
+            );
+        }
+
+        if !self.falling_when_last.is_empty() {
+            text.push_str(
+                format!(
+                    " If this is the last word, {letters} will fall and only the remaining paths \
+will be hinted.",
+                    letters = tiles_text(level, self.falling_when_last)
+                )
+                .as_str(),
+            );
+        } else if self.falling_at_start.is_empty() {
+            text.push_str(
+                " None of these letters fall, so they stay on the grid until the word is found.",
+            );
+        }
+
+        text
+    }
+
+    /// The contribution of this word to the level's ambiguity score
+    pub fn score(&self) -> f32 {
+        let alternatives = self
+            .tile_sets
+            .len()
+            .saturating_sub(1)
+            .min(MAX_COUNTED_ALTERNATIVES) as f32;
+
+        if self.falling_at_start.is_empty() && self.falling_when_last.is_empty() {
+            alternatives
+        } else {
+            alternatives + FALLING_PENALTY
+        }
+    }
+}
+
+/// All the ambiguous words in a level
+#[derive(Debug, Clone, PartialEq)]
+pub struct LevelAmbiguity {
+    pub words: Vec<WordAmbiguity>,
+    /// The mean ambiguity score over every word in the level
+    pub score: f32,
+}
+
+/// Find every word in a level which can be traced using more than one set of tiles
+pub fn analyze_ambiguity(level: &DesignedLevel) -> LevelAmbiguity {
+    let unneeded_at_start = level.calculate_unneeded_tiles(GridSet::EMPTY, |_| false);
+
+    let words = level
+        .words
+        .iter()
+        .enumerate()
+        .filter_map(|(word_index, word)| {
+            let mut tile_sets: Vec<GridSet> = vec![];
+            for solution in level.find_all_word_solutions(word) {
+                let tiles = GridSet::from_iter(solution);
+                if !tile_sets.contains(&tiles) {
+                    tile_sets.push(tiles);
+                }
+            }
+
+            if tile_sets.len() <= 1 {
+                return None;
+            }
+
+            let union = tile_sets.iter().fold(GridSet::EMPTY, |a, b| a.union(b));
+            let intersection = tile_sets.iter().fold(GridSet::ALL, |a, b| a.intersect(b));
+            let contested_tiles = union.intersect(&intersection.negate());
+
+            let unneeded_when_last =
+                level.calculate_unneeded_tiles(unneeded_at_start, |wi| wi != word_index);
+
+            Some(WordAmbiguity {
+                word_index,
+                tile_sets,
+                contested_tiles,
+                falling_at_start: contested_tiles.intersect(&unneeded_at_start),
+                falling_when_last: contested_tiles
+                    .intersect(&unneeded_when_last)
+                    .intersect(&unneeded_at_start.negate()),
+            })
+        })
+        .collect_vec();
+
+    let score = if level.words.is_empty() {
+        0.0
+    } else {
+        words.iter().map(|x| x.score()).sum::<f32>() / level.words.len() as f32
+    };
+
+    LevelAmbiguity { words, score }
+}
+
+fn tiles_text(level: &DesignedLevel, tiles: GridSet) -> String {
+    tiles
+        .iter_true_tiles()
+        .map(|tile| level.grid[tile].as_char())
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /* spellchecker:disable */
+    #[test]
+    pub fn test_ambiguous_word() {
+        // C|A|T|S
+        // T|S|X|X
+        let level = DesignedLevel::from_tsv_line("CATSTSXXXXXXXXXX\tAnimals\tCATS").unwrap();
+
+        let ambiguity = analyze_ambiguity(&level);
+
+        assert_eq!(ambiguity.words.len(), 1);
+        let word = &ambiguity.words[0];
+        assert!(word.tile_sets.len() > 1);
+        assert!(!word.contested_tiles.is_empty());
+        assert!(ambiguity.score > 0.0);
+        assert!(word.explanation(&level).starts_with("'CATS' can be traced"));
+    }
+
+    #[test]
+    pub fn test_explanation_with_letters_falling_at_start() {
+        let level = DesignedLevel::from_tsv_line("CATSTSXXXXXXXXXX\tAnimals\tCATS").unwrap();
+        let contested_tiles =
+            GridSet::from_iter([Tile::new_const::<2, 0>(), Tile::new_const::<0, 1>()]);
+
+        let word = WordAmbiguity {
+            word_index: 0,
+            tile_sets: vec![GridSet::EMPTY, GridSet::EMPTY],
+            contested_tiles,
+            falling_at_start: GridSet::from_iter([Tile::new_const::<2, 0>()]),
+            falling_when_last: GridSet::EMPTY,
+        };
+
+        let explanation = word.explanation(&level);
+        assert!(explanation.contains("will fall before any word is found"));
+        assert!(!explanation.contains("None of these letters fall"));
+    }
+
+    #[test]
+    pub fn test_unambiguous_level() {
+        let level = DesignedLevel::from_tsv_line("ABCDEFGHIJKLMNOP\tLetters\tABCD\tMNOP").unwrap();
+
+        let ambiguity = analyze_ambiguity(&level);
+
+        assert_eq!(ambiguity.words, vec![]);
+        assert_eq!(ambiguity.score, 0.0);
+    }
+}
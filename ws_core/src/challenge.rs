@@ -0,0 +1,226 @@
+use crate::share_code::{fletcher_16, BitReader, BitWriter};
+use crate::ShareCodeError;
+use serde::{Deserialize, Serialize};
+
+/// The first byte of every challenge code
+pub const CHALLENGE_CODE_VERSION: u8 = 1;
+/// Separates the level part of a challenge link from the challenge
+pub const CHALLENGE_PATH_SEPARATOR: &str = "/vs/";
+
+const SECONDS_BITS: u8 = 24;
+const MAX_SECONDS: u32 = (1 << SECONDS_BITS) - 1;
+
+/// The result of someone who played a level, shared so that others can try to beat it
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Challenge {
+    /// The name of the player who sent the challenge, if they gave one
+    pub name: Option<String>,
+    pub seconds: u32,
+    pub hints_used: u8,
+    /// For each word in the level, the position it was found in, or `None` if it was not found
+    pub found_order: Vec<Option<u8>>,
+}
+
+/// Split a path into the level part and the challenge data, if there is any
+pub fn split_challenge_path(path: &str) -> (&str, Option<&str>) {
+    match path.split_once(CHALLENGE_PATH_SEPARATOR) {
+        Some((level_path, data)) => (level_path, Some(data)),
+        None => (path, None),
+    }
+}
+
+/// The part of the path which the challenge checksum covers
+fn normalize_level_path(path: &str) -> &str {
+    path.trim_start_matches("https://wordsalad.online")
+        .trim_end_matches('/')
+}
+
+impl Challenge {
+    /// Write this challenge as a compact binary code.
+    ///
+    /// The code ends with a Fletcher-16 checksum which also covers the level path,
+    /// so a challenge cannot be moved to a different level
+    pub fn to_challenge_code(&self, level_path: &str) -> Result<Vec<u8>, ShareCodeError> {
+        let mut writer = BitWriter::default();
+        writer.write(CHALLENGE_CODE_VERSION as u32, 8);
+        writer.write(self.seconds.min(MAX_SECONDS), SECONDS_BITS);
+        writer.write(self.hints_used as u32, 8);
+
+        writer.write_count(self.found_order.len())?;
+        for position in self.found_order.iter() {
+            let value = match position {
+                Some(position) => position
+                    .checked_add(1)
+                    .ok_or(ShareCodeError::TooManyItems)?,
+                None => 0,
+            };
+            writer.write(value as u32, 8);
+        }
+        writer.write_text(self.name.as_deref().unwrap_or_default())?;
+
+        let mut bytes = writer.finish();
+        bytes.extend(Self::checksum(&bytes, level_path).to_be_bytes());
+        Ok(bytes)
+    }
+
+    /// Read a challenge from a code made by `to_challenge_code` for the same level path
+    pub fn try_from_challenge_code(bytes: &[u8], level_path: &str) -> Result<Self, ShareCodeError> {
+        if bytes.len() < 2 {
+            return Err(ShareCodeError::UnexpectedEnd);
+        }
+        let (data, checksum) = bytes.split_at(bytes.len() - 2);
+        if Self::checksum(data, level_path).to_be_bytes() != checksum {
+            return Err(ShareCodeError::InvalidChecksum);
+        }
+
+        let mut reader = BitReader::new(data);
+        let version = reader.read(8)? as u8;
+        if version != CHALLENGE_CODE_VERSION {
+            return Err(ShareCodeError::UnknownVersion(version));
+        }
+        let seconds = reader.read(SECONDS_BITS)?;
+        let hints_used = reader.read(8)? as u8;
+
+        let word_count = reader.read(8)? as usize;
+        let found_order = (0..word_count)
+            .map(|_| reader.read(8).map(|value| (value as u8).checked_sub(1)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let name = Some(reader.read_text()?).filter(|name| !name.trim().is_empty());
+
+        Ok(Self {
+            name,
+            seconds,
+            hints_used,
+            found_order,
+        })
+    }
+
+    /// The path of a challenge link for the level at `level_path`
+    pub fn to_path(&self, level_path: &str) -> Result<String, ShareCodeError> {
+        use base64::Engine;
+
+        let level_path = normalize_level_path(level_path);
+        let code = self.to_challenge_code(level_path)?;
+        let data = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(code);
+        Ok(format!("{level_path}{CHALLENGE_PATH_SEPARATOR}{data}"))
+    }
+
+    /// Read the challenge from a link made by `to_path`.
+    /// Returns `None` if the link has no challenge or the challenge has been corrupted
+    pub fn try_from_path(path: &str) -> Option<Self> {
+        use base64::Engine;
+
+        let (level_path, data) = split_challenge_path(path);
+        let bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(data?.trim().trim_end_matches(['=', '/']))
+            .ok()?;
+
+        match Self::try_from_challenge_code(&bytes, normalize_level_path(level_path)) {
+            Ok(challenge) => Some(challenge),
+            Err(err) => {
+                log::error!("{err}");
+                None
+            }
+        }
+    }
+
+    /// Whether this challenge could be for a level with this many words
+    pub fn fits_word_count(&self, word_count: usize) -> bool {
+        self.found_order.len() == word_count
+    }
+
+    /// The number of words found in the same position as in this challenge
+    pub fn same_order_count(&self, found_order: &[Option<u8>]) -> usize {
+        self.found_order
+            .iter()
+            .zip(found_order)
+            .filter(|(a, b)| a.is_some() && a == b)
+            .count()
+    }
+
+    fn checksum(data: &[u8], level_path: &str) -> u16 {
+        let mut bytes = data.to_vec();
+        bytes.extend(level_path.bytes());
+        fletcher_16(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn challenge() -> Challenge {
+        Challenge {
+            name: Some("Alex".to_string()),
+            seconds: 83,
+            hints_used: 2,
+            found_order: vec![Some(2), None, Some(0), Some(1)],
+        }
+    }
+
+    #[test]
+    pub fn test_round_trip() {
+        let path = challenge()
+            .to_path("https://wordsalad.online/daily/12")
+            .unwrap();
+        assert!(path.starts_with("/daily/12/vs/"), "{path}");
+
+        assert_eq!(Challenge::try_from_path(&path), Some(challenge()));
+        assert_eq!(
+            Challenge::try_from_path(format!("https://wordsalad.online{path}").as_str()),
+            Some(challenge())
+        );
+    }
+
+    #[test]
+    pub fn test_no_name() {
+        let challenge = Challenge {
+            name: None,
+            ..challenge()
+        };
+        let path = challenge.to_path("/daily/3").unwrap();
+
+        assert_eq!(Challenge::try_from_path(&path), Some(challenge));
+    }
+
+    #[test]
+    pub fn test_challenge_is_bound_to_level() {
+        let path = challenge().to_path("/daily/12").unwrap();
+        let moved = path.replace("/daily/12", "/daily/13");
+
+        assert_eq!(Challenge::try_from_path(&moved), None);
+        assert_eq!(Challenge::try_from_path("/daily/12"), None);
+    }
+
+    #[test]
+    pub fn test_tampered_code() {
+        let mut code = challenge().to_challenge_code("/daily/12").unwrap();
+        code[2] ^= 0b1;
+
+        assert_eq!(
+            Challenge::try_from_challenge_code(&code, "/daily/12"),
+            Err(ShareCodeError::InvalidChecksum)
+        );
+    }
+
+    #[test]
+    pub fn test_level_path_ignores_challenge() {
+        let line = "ASHPKILOEUIOGNDT\tSports\tPOLO\tSHOOTING\tKENDO\tSAILING\tLUGE\tSKIING";
+        let level = DesignedLevel::from_tsv_line(line).unwrap();
+        let level_path = format!("/game/{}", level.share_data());
+        let path = challenge().to_path(&level_path).unwrap();
+
+        assert_eq!(DesignedLevel::try_from_path(&path), Some(level));
+        assert_eq!(Challenge::try_from_path(&path), Some(challenge()));
+    }
+
+    #[test]
+    pub fn test_same_order_count() {
+        assert_eq!(
+            challenge().same_order_count(&[Some(2), None, Some(1), Some(0)]),
+            1
+        );
+    }
+}
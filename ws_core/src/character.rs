@@ -1,4 +1,4 @@
-use arrayvec::ArrayVec;
+use crate::{alphabet::Alphabet, CharsArray, WordParseError};
 use prime_bag::*;
 
 use num_derive::FromPrimitive;
@@ -51,7 +51,48 @@ pub enum Character {
     J = 23,
     X = 24,
     Z = 25,
-    Blank,
+    Blank = 26,
+    /// Å, kept by alphabets which treat it as a separate letter
+    ARing = 27,
+    /// Ä, kept by alphabets which treat it as a separate letter
+    AUmlaut = 28,
+    /// Ö, kept by alphabets which treat it as a separate letter
+    OUmlaut = 29,
+    /// Ñ, kept by alphabets which treat it as a separate letter
+    NTilde = 30,
+    /// Cyrillic letters, in order of frequency in Russian text
+    CyrillicO = 31,
+    CyrillicIe = 32,
+    CyrillicA = 33,
+    CyrillicI = 34,
+    CyrillicEn = 35,
+    CyrillicTe = 36,
+    CyrillicEs = 37,
+    CyrillicEr = 38,
+    CyrillicVe = 39,
+    CyrillicEl = 40,
+    CyrillicKa = 41,
+    CyrillicEm = 42,
+    CyrillicDe = 43,
+    CyrillicPe = 44,
+    CyrillicU = 45,
+    CyrillicYa = 46,
+    CyrillicYeru = 47,
+    CyrillicSoftSign = 48,
+    CyrillicGhe = 49,
+    CyrillicZe = 50,
+    CyrillicBe = 51,
+    CyrillicChe = 52,
+    CyrillicShortI = 53,
+    CyrillicHa = 54,
+    CyrillicZhe = 55,
+    CyrillicSha = 56,
+    CyrillicYu = 57,
+    CyrillicTse = 58,
+    CyrillicShcha = 59,
+    CyrillicE = 60,
+    CyrillicEf = 61,
+    CyrillicHardSign = 62,
 }
 
 impl PrimeBagElement for Character {
@@ -100,6 +141,42 @@ impl Character {
             Character::X => 'X',
             Character::Y => 'Y',
             Character::Z => 'Z',
+            Character::ARing => 'Å',
+            Character::AUmlaut => 'Ä',
+            Character::OUmlaut => 'Ö',
+            Character::NTilde => 'Ñ',
+            Character::CyrillicO => 'О',
+            Character::CyrillicIe => 'Е',
+            Character::CyrillicA => 'А',
+            Character::CyrillicI => 'И',
+            Character::CyrillicEn => 'Н',
+            Character::CyrillicTe => 'Т',
+            Character::CyrillicEs => 'С',
+            Character::CyrillicEr => 'Р',
+            Character::CyrillicVe => 'В',
+            Character::CyrillicEl => 'Л',
+            Character::CyrillicKa => 'К',
+            Character::CyrillicEm => 'М',
+            Character::CyrillicDe => 'Д',
+            Character::CyrillicPe => 'П',
+            Character::CyrillicU => 'У',
+            Character::CyrillicYa => 'Я',
+            Character::CyrillicYeru => 'Ы',
+            Character::CyrillicSoftSign => 'Ь',
+            Character::CyrillicGhe => 'Г',
+            Character::CyrillicZe => 'З',
+            Character::CyrillicBe => 'Б',
+            Character::CyrillicChe => 'Ч',
+            Character::CyrillicShortI => 'Й',
+            Character::CyrillicHa => 'Х',
+            Character::CyrillicZhe => 'Ж',
+            Character::CyrillicSha => 'Ш',
+            Character::CyrillicYu => 'Ю',
+            Character::CyrillicTse => 'Ц',
+            Character::CyrillicShcha => 'Щ',
+            Character::CyrillicE => 'Э',
+            Character::CyrillicEf => 'Ф',
+            Character::CyrillicHardSign => 'Ъ',
         }
         .to_string()
     }
@@ -133,25 +210,78 @@ impl Character {
             Character::X => 'X',
             Character::Y => 'Y',
             Character::Z => 'Z',
+            Character::ARing => 'Å',
+            Character::AUmlaut => 'Ä',
+            Character::OUmlaut => 'Ö',
+            Character::NTilde => 'Ñ',
+            Character::CyrillicO => 'О',
+            Character::CyrillicIe => 'Е',
+            Character::CyrillicA => 'А',
+            Character::CyrillicI => 'И',
+            Character::CyrillicEn => 'Н',
+            Character::CyrillicTe => 'Т',
+            Character::CyrillicEs => 'С',
+            Character::CyrillicEr => 'Р',
+            Character::CyrillicVe => 'В',
+            Character::CyrillicEl => 'Л',
+            Character::CyrillicKa => 'К',
+            Character::CyrillicEm => 'М',
+            Character::CyrillicDe => 'Д',
+            Character::CyrillicPe => 'П',
+            Character::CyrillicU => 'У',
+            Character::CyrillicYa => 'Я',
+            Character::CyrillicYeru => 'Ы',
+            Character::CyrillicSoftSign => 'Ь',
+            Character::CyrillicGhe => 'Г',
+            Character::CyrillicZe => 'З',
+            Character::CyrillicBe => 'Б',
+            Character::CyrillicChe => 'Ч',
+            Character::CyrillicShortI => 'Й',
+            Character::CyrillicHa => 'Х',
+            Character::CyrillicZhe => 'Ж',
+            Character::CyrillicSha => 'Ш',
+            Character::CyrillicYu => 'Ю',
+            Character::CyrillicTse => 'Ц',
+            Character::CyrillicShcha => 'Щ',
+            Character::CyrillicE => 'Э',
+            Character::CyrillicEf => 'Ф',
+            Character::CyrillicHardSign => 'Ъ',
+        }
+    }
+
+    /// The letter after this one in prime index order, skipping blank.
+    /// The letter after blank is the first letter
+    pub fn next_letter(&self) -> Option<Character> {
+        let next = match self {
+            Character::Blank => 0,
+            other => other.into_prime_index() + 1,
+        };
+        match Character::from_usize(next)? {
+            Character::Blank => Character::from_usize(next + 1),
+            letter => Some(letter),
         }
     }
 }
 
-pub fn normalize_characters_array(text: &str) -> Result<ArrayVec<Character, 16>, &'static str> {
-    let mut characters = ArrayVec::<Character, 16>::default();
-    let unicode_graphemes = unicode_segmentation::UnicodeSegmentation::graphemes(text, true);
+pub fn normalize_characters_array(text: &str) -> Result<CharsArray, WordParseError> {
+    normalize_characters_array_with_alphabet(text, &Alphabet::default())
+}
 
-    for grapheme in unicode_graphemes {
-        let mut normalized = unicode_normalization::UnicodeNormalization::nfd(grapheme);
+pub fn normalize_characters_array_with_alphabet(
+    text: &str,
+    alphabet: &Alphabet,
+) -> Result<CharsArray, WordParseError> {
+    let mut characters = CharsArray::default();
+    let unicode_graphemes = unicode_segmentation::UnicodeSegmentation::graphemes(text, true);
 
-        let Some(c) = normalized.next() else {
-            continue;
-        };
-        let character = Character::try_from(c)?;
-        if !character.is_blank() {
+    for (position, grapheme) in unicode_graphemes.enumerate() {
+        let grapheme_characters = alphabet
+            .grapheme_characters(grapheme)
+            .map_err(|_| WordParseError::InvalidCharacter { position })?;
+        for character in grapheme_characters {
             characters
                 .try_push(character)
-                .map_err(|_| "Word is too long")?;
+                .map_err(|_| WordParseError::TooLong)?;
         }
     }
 
@@ -194,13 +324,57 @@ impl TryFrom<char> for Character {
             'x' | 'X' => Ok(Character::X),
             'y' | 'Y' => Ok(Character::Y),
             'z' | 'Z' => Ok(Character::Z),
+            'å' | 'Å' => Ok(Character::ARing),
+            'ä' | 'Ä' => Ok(Character::AUmlaut),
+            'ö' | 'Ö' => Ok(Character::OUmlaut),
+            'ñ' | 'Ñ' => Ok(Character::NTilde),
+            'о' | 'О' => Ok(Character::CyrillicO),
+            'е' | 'Е' => Ok(Character::CyrillicIe),
+            'а' | 'А' => Ok(Character::CyrillicA),
+            'и' | 'И' => Ok(Character::CyrillicI),
+            'н' | 'Н' => Ok(Character::CyrillicEn),
+            'т' | 'Т' => Ok(Character::CyrillicTe),
+            'с' | 'С' => Ok(Character::CyrillicEs),
+            'р' | 'Р' => Ok(Character::CyrillicEr),
+            'в' | 'В' => Ok(Character::CyrillicVe),
+            'л' | 'Л' => Ok(Character::CyrillicEl),
+            'к' | 'К' => Ok(Character::CyrillicKa),
+            'м' | 'М' => Ok(Character::CyrillicEm),
+            'д' | 'Д' => Ok(Character::CyrillicDe),
+            'п' | 'П' => Ok(Character::CyrillicPe),
+            'у' | 'У' => Ok(Character::CyrillicU),
+            'я' | 'Я' => Ok(Character::CyrillicYa),
+            'ы' | 'Ы' => Ok(Character::CyrillicYeru),
+            'ь' | 'Ь' => Ok(Character::CyrillicSoftSign),
+            'г' | 'Г' => Ok(Character::CyrillicGhe),
+            'з' | 'З' => Ok(Character::CyrillicZe),
+            'б' | 'Б' => Ok(Character::CyrillicBe),
+            'ч' | 'Ч' => Ok(Character::CyrillicChe),
+            'й' | 'Й' => Ok(Character::CyrillicShortI),
+            'х' | 'Х' => Ok(Character::CyrillicHa),
+            'ж' | 'Ж' => Ok(Character::CyrillicZhe),
+            'ш' | 'Ш' => Ok(Character::CyrillicSha),
+            'ю' | 'Ю' => Ok(Character::CyrillicYu),
+            'ц' | 'Ц' => Ok(Character::CyrillicTse),
+            'щ' | 'Щ' => Ok(Character::CyrillicShcha),
+            'э' | 'Э' => Ok(Character::CyrillicE),
+            'ф' | 'Ф' => Ok(Character::CyrillicEf),
+            'ъ' | 'Ъ' => Ok(Character::CyrillicHardSign),
+            // Ё is written as Е in most Russian text
+            'ё' | 'Ё' => Ok(Character::CyrillicIe),
             _ => Err("Invalid character"),
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Default, Hash, Eq)]
-pub struct CharacterMap<T>([T; 27]);
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Eq)]
+pub struct CharacterMap<T>([T; Character::COUNT]);
+
+impl<T: Default> Default for CharacterMap<T> {
+    fn default() -> Self {
+        Self(std::array::from_fn(|_| T::default()))
+    }
+}
 
 impl<T> CharacterMap<T> {
     pub fn get(&self, c: Character) -> &T {
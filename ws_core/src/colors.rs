@@ -1,3 +1,5 @@
+use crate::ColorParseError;
+
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub struct BasicColor {
     pub red: f32,
@@ -34,36 +36,38 @@ impl BasicColor {
     /// <div style="background-color:rgb(100%, 100%, 100%); width: 10px; padding: 10px; border: 1px solid;"></div>
     pub(crate) const WHITE: BasicColor = BasicColor::rgb(1.0, 1.0, 1.0);
 
-    pub fn try_from_str(hex: &str) -> Option<Self> {
+    pub fn try_from_str(hex: &str) -> Result<Self, ColorParseError> {
+        const INVALID: ColorParseError = ColorParseError::InvalidHexDigit;
         let hex = hex.strip_prefix('#').unwrap_or(hex);
 
         let (r, g, b, a) = match *hex.as_bytes() {
             // RGB
             [r, g, b] => {
-                let [r, g, b, ..] = Self::decode_hex([r, r, g, g, b, b])?;
+                let [r, g, b, ..] = Self::decode_hex([r, r, g, g, b, b]).ok_or(INVALID)?;
                 (r, g, b, u8::MAX)
             }
             // RGBA
             [r, g, b, a] => {
-                let [r, g, b, a, ..] = Self::decode_hex([r, r, g, g, b, b, a, a])?;
+                let [r, g, b, a, ..] = Self::decode_hex([r, r, g, g, b, b, a, a]).ok_or(INVALID)?;
                 (r, g, b, a)
             }
             // RRGGBB
             [r1, r2, g1, g2, b1, b2] => {
-                let [r, g, b, ..] = Self::decode_hex([r1, r2, g1, g2, b1, b2])?;
+                let [r, g, b, ..] = Self::decode_hex([r1, r2, g1, g2, b1, b2]).ok_or(INVALID)?;
                 (r, g, b, u8::MAX)
             }
             // RRGGBBAA
             [r1, r2, g1, g2, b1, b2, a1, a2] => {
-                let [r, g, b, a, ..] = Self::decode_hex([r1, r2, g1, g2, b1, b2, a1, a2])?;
+                let [r, g, b, a, ..] =
+                    Self::decode_hex([r1, r2, g1, g2, b1, b2, a1, a2]).ok_or(INVALID)?;
                 (r, g, b, a)
             }
             _ => {
-                return None;
+                return Err(ColorParseError::InvalidLength(hex.len()));
             }
         };
 
-        Some(Self::rgba(
+        Ok(Self::rgba(
             r as f32 / 255.0,
             g as f32 / 255.0,
             b as f32 / 255.0,
@@ -71,6 +75,25 @@ impl BasicColor {
         ))
     }
 
+    /// The color as a hex string, which can be read by `try_from_str`
+    pub fn to_hex(&self) -> String {
+        fn to_u8(value: f32) -> u8 {
+            (value * 255.0).round().clamp(0.0, 255.0) as u8
+        }
+        let (r, g, b, a) = (
+            to_u8(self.red),
+            to_u8(self.green),
+            to_u8(self.blue),
+            to_u8(self.alpha),
+        );
+
+        if a == u8::MAX {
+            format!("#{r:02x}{g:02x}{b:02x}")
+        } else {
+            format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+        }
+    }
+
     const fn decode_hex<const N: usize>(mut bytes: [u8; N]) -> Option<[u8; N]> {
         /// Parse a single hex digit (a-f/A-F/0-9) as a `u8`
         const fn hex_value(b: u8) -> Result<u8, u8> {
@@ -147,6 +170,7 @@ pub mod palette {
     pub const WORD_BACKGROUND_MANUAL_HINT2: Color = GREEN_OTHER;
     pub const WORD_BACKGROUND_COMPLETE: Color = GREEN_LIGHT;
     pub const WORD_BACKGROUND_PROGRESS: Color = GREEN_DARK;
+    pub const WORD_BACKGROUND_NEAR_MISS: Color = GOLD;
 
     pub const GRID_TILE_FILL_NORMAL: Color = LIGHT_GRAY;
 
@@ -186,6 +210,13 @@ pub mod palette {
     pub const RECORDING_BUTTON_NORMAL: Color = MY_BLACK;
     pub const RECORDING_BUTTON_RECORDING: Color = Color::rgba(1.0, 0.14, 0.09, 1.0);
 
+    pub const VERSUS_PLAYERS: [Color; 4] = [
+        GREEN_LIGHT,
+        Color::rgb(0.16, 0.42, 0.78),
+        Color::rgb(0.86, 0.33, 0.16),
+        Color::rgb(0.55, 0.27, 0.71),
+    ];
+
     const MY_BLACK: Color = Color::rgba(0.12, 0., 0., 1.);
     const MY_WHITE: Color = Color::rgba(1.0, 1.0, 1.0, 1.);
 
@@ -211,6 +242,15 @@ mod tests {
 
         let actual = BasicColor::try_from_str(hex);
 
-        assert_eq!(actual, Some(BasicColor::rgb(0.08627451, 0.35686275, 0.2)));
+        assert_eq!(actual, Ok(BasicColor::rgb(0.08627451, 0.35686275, 0.2)));
+    }
+
+    #[test]
+    pub fn test_hex_round_trip() {
+        for hex in ["#165b33", "#009a49", "#ff390a80"] {
+            let color = BasicColor::try_from_str(hex).unwrap();
+
+            assert_eq!(color.to_hex(), hex);
+        }
     }
 }
@@ -2,10 +2,9 @@ use std::str::FromStr;
 
 use crate::{finder::helpers::LetterCounts, prelude::*, Grid};
 use itertools::Itertools;
-use log::{error, warn};
 use ustr::Ustr;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct DesignedLevel {
     pub name: Ustr,
     pub numbering: Option<Numbering>,
@@ -13,8 +12,56 @@ pub struct DesignedLevel {
     // Attribution
     pub extra_info: Option<Ustr>,
     pub grid: Grid,
+    /// Tiles which hold two letters
+    pub digraphs: Option<DigraphMap>,
     pub words: Vec<DisplayWord>,
     pub special_colors: Option<Vec<BasicColor>>,
+    /// Information which is only stored in structured level files
+    pub metadata: LevelMetadata,
+    /// A cache, so it is ignored when comparing levels
+    pub unneeded_tiles_table: UnneededTilesTable,
+    /// A cache, so it is ignored when comparing levels
+    pub difficulty_cache: DifficultyCache,
+}
+
+impl PartialEq for DesignedLevel {
+    fn eq(&self, other: &Self) -> bool {
+        let Self {
+            name,
+            numbering,
+            extra_info,
+            grid,
+            digraphs,
+            words,
+            special_colors,
+            metadata,
+            unneeded_tiles_table: _,
+            difficulty_cache: _,
+        } = self;
+
+        *name == other.name
+            && *numbering == other.numbering
+            && *extra_info == other.extra_info
+            && *grid == other.grid
+            && *digraphs == other.digraphs
+            && *words == other.words
+            && *special_colors == other.special_colors
+            && *metadata == other.metadata
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LevelMetadata {
+    pub author: Option<Ustr>,
+    pub difficulty: Option<u8>,
+    pub source: Option<Ustr>,
+    pub date: Option<chrono::NaiveDate>,
+    /// Notes on particular words, keyed by word text
+    pub word_notes: std::collections::BTreeMap<String, String>,
+    /// Word lengths are not shown until the word is started or a length hint is used
+    pub hide_word_lengths: bool,
+    /// The alphabet used to read the words
+    pub alphabet: Alphabet,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -28,7 +75,7 @@ impl std::fmt::Display for DesignedLevel {
         write!(
             f,
             "{grid}\t{name}\t{words}",
-            grid = self.grid.iter().join(""),
+            grid = self.grid_text(),
             name = self.name,
             words = self.words.iter().join("\t")
         )
@@ -38,31 +85,14 @@ impl std::fmt::Display for DesignedLevel {
 impl DesignedLevel {
     pub fn try_from_path(mut path: &str) -> Option<Self> {
         path = path.trim_start_matches("https://wordsalad.online");
+        path = split_challenge_path(path).0;
 
         if path.is_empty() || path.eq_ignore_ascii_case("/") {
             return None;
         }
 
         if path.to_ascii_lowercase().starts_with("/game/") {
-            //log::info!("Path starts with game");
-            let data = path[6..].to_string();
-            //log::info!("{data}");
-
-            use base64::Engine;
-
-            let data = base64::engine::general_purpose::URL_SAFE
-                .decode(data)
-                .ok()?;
-
-            let data = String::from_utf8(data).ok()?;
-
-            match DesignedLevel::from_tsv_line(data.trim()) {
-                Ok(data) => Some(data),
-                Err(err) => {
-                    error!("{err}");
-                    None
-                }
-            }
+            Self::try_from_share_data(&path[6..])
         } else {
             None
         }
@@ -85,35 +115,152 @@ impl DesignedLevel {
             name: Ustr::from("Unknown"),
             numbering: None,
             extra_info: None,
-            grid: Grid::from_inner([Character::Blank; 16]),
+            grid: Grid::from_inner([Character::Blank; GRID_SIZE]),
+            digraphs: None,
             words: vec![],
             special_colors: None,
+            metadata: Default::default(),
+            unneeded_tiles_table: Default::default(),
+            difficulty_cache: Default::default(),
         }
     }
 
     pub fn letter_counts(&self) -> Option<LetterCounts> {
-        LetterCounts::try_from_iter(self.grid.iter().cloned())
+        LetterCounts::try_from_iter(Tile::iter_by_row().flat_map(|x| self.tile_characters(x)))
+    }
+
+    /// The grid as text, with digraph tiles in brackets
+    pub fn grid_text(&self) -> String {
+        match &self.digraphs {
+            Some(digraphs) => grid_text_with_digraphs(&self.grid, digraphs),
+            None => self.grid.iter().join(""),
+        }
+    }
+
+    /// The tiles at the start of a solution which hold at least `letters` letters.
+    /// A digraph tile is revealed whole, so this may hold one more letter than asked for
+    pub fn revealed_prefix<'a>(&self, solution: &'a [Tile], letters: usize) -> &'a [Tile] {
+        let mut revealed = 0;
+        let tiles = solution
+            .iter()
+            .take_while(|tile| {
+                if revealed >= letters {
+                    return false;
+                }
+                revealed += self.tile_characters(**tile).len();
+                true
+            })
+            .count();
+        &solution[..tiles]
+    }
+
+    /// The characters spelled by a selection of tiles
+    pub fn selection_characters(&self, solution: &[Tile]) -> CharsArray {
+        match &self.digraphs {
+            Some(digraphs) => path_characters(&self.grid, digraphs, solution),
+            None => solution.iter().map(|t| self.grid[*t]).collect(),
+        }
+    }
+
+    /// Find a solution for a word, ignoring unneeded tiles
+    pub fn find_word_solution(
+        &self,
+        word: &DisplayWord,
+        unneeded_tiles: GridSet,
+    ) -> Option<Solution> {
+        match &self.digraphs {
+            Some(digraphs) => {
+                find_word_path_with_digraphs(&word.characters, &self.grid, digraphs, unneeded_tiles)
+            }
+            None => word.find_solution_with_tiles(&self.grid, unneeded_tiles),
+        }
+    }
+
+    /// Find every path for a word, taking digraphs into account
+    pub fn find_all_word_solutions(&self, word: &DisplayWord) -> Vec<Solution> {
+        match &self.digraphs {
+            Some(digraphs) => {
+                find_solutions_with_digraphs(&word.characters, &self.grid, digraphs, false)
+            }
+            None => word.find_solutions(&self.grid),
+        }
+    }
+
+    pub(crate) fn parse_grid(chars: &str) -> Result<(Grid, Option<DigraphMap>), GridParseError> {
+        if chars.contains('(') {
+            // Each bracketed digraph is four characters on one tile
+            let tiles = chars
+                .chars()
+                .count()
+                .saturating_sub(3 * chars.matches('(').count());
+            if tiles > GRID_SIZE {
+                return Err(GridParseError::TooManyTiles);
+            }
+            let (grid, digraphs) =
+                try_make_grid_with_digraphs(chars).ok_or(GridParseError::InvalidDigraph)?;
+            return Ok((grid, Some(digraphs)));
+        }
+
+        let mut arr = [Character::Blank; GRID_SIZE];
+        for (position, char) in chars.chars().enumerate() {
+            let c = Character::try_from(char)
+                .map_err(|_| GridParseError::InvalidCharacter { position })?;
+            *arr.get_mut(position).ok_or(GridParseError::TooManyTiles)? = c;
+        }
+
+        Ok((Grid::from_inner(arr), None))
+    }
+
+    /// Write this level as a tsv line which can be read by `from_tsv_line`
+    pub fn to_tsv_line(&self) -> String {
+        let mut name = self.name.to_string();
+        if let Some(extra_info) = self.extra_info {
+            name.push_str(format!("[{extra_info}]").as_str());
+        }
+        if let Some(colors) = &self.special_colors {
+            name.push_str(format!("{{{}}}", colors.iter().map(|x| x.to_hex()).join(",")).as_str());
+        }
+
+        format!(
+            "{grid}\t{name}\t{words}",
+            grid = self.grid_text(),
+            words = self.words.iter().join("\t")
+        )
     }
 
-    pub fn from_tsv_line(line: &str) -> Result<Self, String> {
-        let mut iter = line.split('\t');
+    pub fn from_tsv_line(line: &str) -> Result<Self, LevelParseError> {
+        let mut iter = line.split('\t').scan(0usize, |column, field| {
+            let field_column = *column;
+            *column += field.chars().count() + 1;
+            Some((field_column, field))
+        });
 
         let chars: &str = iter
             .next()
-            .ok_or_else(|| format!("Level '{line}' should have a grid"))?;
-        let name: &str = iter
-            .next()
-            .ok_or_else(|| format!("Level '{line}' should have a name"))?;
+            .map(|x| x.1)
+            .filter(|x| !x.trim().is_empty())
+            .ok_or(LevelParseError::MissingGrid)?;
+        let (name_column, name): (usize, &str) = iter.next().ok_or(LevelParseError::MissingName)?;
 
-        let grid = try_make_grid(chars)
-            .ok_or_else(|| format!("Level '{line}' should be able to make grid"))?;
+        let (grid, digraphs) =
+            Self::parse_grid(chars).map_err(|error| LevelParseError::Grid { error })?;
 
         let mut words: Vec<DisplayWord> = iter
-            .map(|x| {
-                DisplayWord::from_str(x.trim()).map_err(|e| format!("Word '{x}' is not valid {e}"))
+            .enumerate()
+            .map(|(word_index, (column, x))| {
+                DisplayWord::from_str(x.trim()).map_err(|error| LevelParseError::Word {
+                    column: Some(column),
+                    word_index,
+                    text: x.to_string(),
+                    error,
+                })
             })
             .try_collect()?;
 
+        if words.len() > MAX_LEVEL_WORDS {
+            return Err(LevelParseError::TooManyWords);
+        }
+
         words.sort();
 
         let mut name = name;
@@ -121,15 +268,22 @@ impl DesignedLevel {
         let special_colors = if name.ends_with('}') {
             if let Some(index) = name.find('{') {
                 let (prefix, colors) = name.split_at(index);
+                let mut column = name_column + prefix.chars().count() + 1;
                 name = prefix.trim_end();
                 let colors = &colors[1..(colors.len() - 1)];
                 let mut colors_vec = Vec::<BasicColor>::default();
 
-                for c in colors.split(',') {
-                    if let Some(color) = BasicColor::try_from_str(c) {
+                if !colors.is_empty() {
+                    for c in colors.split(',') {
+                        let color = BasicColor::try_from_str(c).map_err(|error| {
+                            LevelParseError::Color {
+                                column: Some(column),
+                                text: c.to_string(),
+                                error,
+                            }
+                        })?;
                         colors_vec.push(color);
-                    } else {
-                        warn!("Could not parse color '{c}'");
+                        column += c.chars().count() + 1;
                     }
                 }
                 if colors_vec.is_empty() {
@@ -164,12 +318,39 @@ impl DesignedLevel {
             numbering: None,
             extra_info,
             grid,
+            digraphs,
             words,
             special_colors,
+            metadata: Default::default(),
+            unneeded_tiles_table: Default::default(),
+            difficulty_cache: Default::default(),
         })
     }
 }
 
+impl FromStr for Numbering {
+    type Err = LevelParseError;
+
+    /// Parses `#12` as a word salad number and `12` as a sequence number
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || LevelParseError::Numbering {
+            text: s.to_string(),
+        };
+        let s = s.trim();
+
+        match s.strip_prefix('#') {
+            Some(number) => number
+                .parse()
+                .map(Numbering::WordSaladNumber)
+                .map_err(|_| error()),
+            None => s
+                .parse()
+                .map(Numbering::SequenceNumber)
+                .map_err(|_| error()),
+        }
+    }
+}
+
 impl LevelTrait for DesignedLevel {
     type Word = DisplayWord;
 
@@ -180,11 +361,110 @@ impl LevelTrait for DesignedLevel {
     fn words(&self) -> &[Self::Word] {
         self.words.as_slice()
     }
+
+    fn tile_characters(&self, tile: Tile) -> ArrayVec<Character, 2> {
+        match &self.digraphs {
+            Some(digraphs) => tile_characters(&self.grid, digraphs, tile),
+            None => ArrayVec::from_iter([self.grid[tile]]),
+        }
+    }
+
+    fn find_word_path(&self, word: &Self::Word, unneeded_tiles: GridSet) -> Option<Solution> {
+        self.find_word_solution(word, unneeded_tiles)
+    }
+
+    fn unneeded_tiles_table(&self) -> Option<&UnneededTilesTable> {
+        Some(&self.unneeded_tiles_table)
+    }
 }
 
 #[cfg(test)]
 pub mod tests {
     use crate::prelude::*;
+    use test_case::test_case;
+
+    /* spellchecker:disable */
+    #[test_case("\tName\tAbcd", LevelParseError::MissingGrid)]
+    #[test_case("ABCDEFGHIJKLMNOP", LevelParseError::MissingName)]
+    #[test_case(
+        "ABC1EFGHIJKLMNOP\tName\tAbcd",
+        LevelParseError::Grid { error: GridParseError::InvalidCharacter { position: 3 } }
+    )]
+    #[test_case(
+        "ABCDEFGHIJKLMNOPQ\tName\tAbcd",
+        LevelParseError::Grid { error: GridParseError::TooManyTiles }
+    )]
+    #[test_case(
+        "(QX)BCDEFGHIJKLMNOP\tName\tAbcd",
+        LevelParseError::Grid { error: GridParseError::InvalidDigraph }
+    )]
+    #[test_case(
+        "ABCDEFGHIJKLMNOP\tName\tAbcd\tA1cd",
+        LevelParseError::Word {
+            column: Some(27),
+            word_index: 1,
+            text: "A1cd".to_string(),
+            error: WordParseError::InvalidCharacter { position: 1 }
+        }
+    )]
+    #[test_case(
+        "ABCDEFGHIJKLMNOP\tName\tAbc",
+        LevelParseError::Word {
+            column: Some(22),
+            word_index: 0,
+            text: "Abc".to_string(),
+            error: WordParseError::TooShort
+        }
+    )]
+    #[test_case(
+        "ABCDEFGHIJKLMNOP\tName\tAbcdefghijklmnopq",
+        LevelParseError::Word {
+            column: Some(22),
+            word_index: 0,
+            text: "Abcdefghijklmnopq".to_string(),
+            error: WordParseError::TooLong
+        }
+    )]
+    #[test_case(
+        "ABCDEFGHIJKLMNOP\tName{#12345g}\tAbcd",
+        LevelParseError::Color {
+            column: Some(22),
+            text: "#12345g".to_string(),
+            error: ColorParseError::InvalidHexDigit
+        }
+    )]
+    #[test_case(
+        "ABCDEFGHIJKLMNOP\tName{#123456,#12345}\tAbcd",
+        LevelParseError::Color {
+            column: Some(30),
+            text: "#12345".to_string(),
+            error: ColorParseError::InvalidLength(5)
+        }
+    )]
+    pub fn test_level_parse_errors(line: &str, expected: LevelParseError) {
+        let actual = DesignedLevel::from_tsv_line(line).unwrap_err();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    pub fn test_too_many_words() {
+        let words = ["Abcd"; MAX_LEVEL_WORDS + 1].join("\t");
+        let line = format!("ABCDEFGHIJKLMNOP\tName\t{words}");
+
+        assert_eq!(
+            DesignedLevel::from_tsv_line(&line),
+            Err(LevelParseError::TooManyWords)
+        );
+    }
+
+    #[test_case("#12", Ok(Numbering::WordSaladNumber(12)))]
+    #[test_case("12", Ok(Numbering::SequenceNumber(12)))]
+    #[test_case("abc", Err(LevelParseError::Numbering { text: "abc".to_string() }))]
+    pub fn test_parse_numbering(text: &str, expected: Result<Numbering, LevelParseError>) {
+        assert_eq!(text.parse::<Numbering>(), expected);
+    }
+    /* spellchecker:enable */
 
     #[test]
     pub fn test_calculate_needed_tiles() {
@@ -236,4 +516,28 @@ pub mod tests {
             }
         }
     }
+
+    #[test]
+    pub fn test_revealed_prefix() {
+        let level =
+            DesignedLevel::from_tsv_line("(QU)EENTIDEXXXXXXXX\tDigraphs\tQUEEN\tTIDE").unwrap();
+        let solution = level
+            .find_word_solution(&level.words[0], GridSet::EMPTY)
+            .unwrap();
+
+        let revealed = [1, 2, 3, 5].map(|letters| level.revealed_prefix(&solution, letters).len());
+
+        assert_eq!(revealed, [1, 1, 2, 4]);
+    }
+
+    #[test]
+    pub fn test_digraph_tiles_fall() {
+        let level =
+            DesignedLevel::from_tsv_line("(QU)EENTIDEXXXXXXXX\tDigraphs\tQUEEN\tTIDE").unwrap();
+
+        let unneeded = level.calculate_unneeded_tiles(GridSet::EMPTY, |index| index == 0);
+        let expected = GridSet::from_iter(Tile::iter_by_row().filter(|tile| tile.y() != 1));
+
+        assert_eq!(unneeded, expected);
+    }
 }
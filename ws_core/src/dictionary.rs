@@ -0,0 +1,872 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    iter::FusedIterator,
+};
+
+use itertools::Itertools;
+use prime_bag::PrimeBagElement;
+use strum::{EnumCount, IntoEnumIterator};
+
+use crate::{Alphabet, Character, CharacterMap, CharsArray, Grid, GridSet, WordTrait};
+
+const MAGIC: &[u8; 4] = b"WSDA";
+const VERSION: u8 = 2;
+/// Version 1 used `u32` masks, from before there were more than 32 characters
+const VERSION_U32_MASKS: u8 = 1;
+
+/// A compressed trie of words which can be searched for in grids.
+/// It can be written to a compact binary format
+/// so that large word lists only need to be parsed once
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordAutomata {
+    slab: Vec<State>,
+}
+
+impl Default for WordAutomata {
+    fn default() -> Self {
+        Self {
+            slab: vec![State::default()],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct State {
+    pub inner: CharacterMap<Option<usize>>, //todo option<nonzerou32>
+}
+
+/// Iterates through every word in an automata
+pub struct AutomataIterator<'a> {
+    automata: &'a WordAutomata,
+    stack: Vec<(usize, Character)>,
+}
+
+impl<'a> FusedIterator for AutomataIterator<'a> {}
+
+impl<'a> Iterator for AutomataIterator<'a> {
+    type Item = CharsArray;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        fn increment_last(stack: &mut Vec<(usize, Character)>) {
+            loop {
+                match stack.last_mut() {
+                    Some(other) => match other.1.next_letter() {
+                        Some(next) => {
+                            other.1 = next;
+                            return;
+                        }
+                        None => {
+                            stack.pop();
+                        }
+                    },
+                    None => return,
+                }
+            }
+        }
+
+        loop {
+            let (top_state_index, character) = *self.stack.last()?;
+
+            // println!(
+            //     "{}",
+            //     self.stack.iter().map(|x|x.1.as_char()).join("")
+            // );
+
+            match self.automata.slab[top_state_index].inner.get(character) {
+                Some(next_state_index) => {
+                    if character.is_blank() {
+                        //this is a valid word
+                        let word = self
+                            .stack
+                            .iter()
+                            .take(self.stack.len() - 1)
+                            .map(|x| x.1)
+                            .collect();
+                        increment_last(&mut self.stack);
+
+                        return Some(word);
+                    } else if self.stack.len() > crate::GRID_SIZE {
+                        //Words can be no longer than the grid
+                        increment_last(&mut self.stack);
+                    } else {
+                        //Make the stack bigger, exploring the next state
+                        self.stack.push((*next_state_index, Character::Blank));
+                    }
+                }
+                None => {
+                    increment_last(&mut self.stack);
+                }
+            }
+        }
+    }
+}
+
+impl WordAutomata {
+    /// Make an automata from a word list with one word per line.
+    /// Lines which are not valid words are skipped
+    pub fn from_word_list(text: &str, min_word_length: usize) -> Self {
+        Self::from_word_list_with_alphabet(text, min_word_length, &Alphabet::default())
+    }
+
+    /// Make an automata from a word list, reading the words with this alphabet
+    pub fn from_word_list_with_alphabet(
+        text: &str,
+        min_word_length: usize,
+        alphabet: &Alphabet,
+    ) -> Self {
+        let mut wa = WordAutomata::default();
+        for line in text.lines() {
+            if let Ok(characters) = crate::normalize_characters_array_with_alphabet(line, alphabet)
+            {
+                if !characters.is_empty() && characters.len() >= min_word_length {
+                    wa.add_characters(&characters);
+                }
+            }
+        }
+        wa.compress();
+        wa
+    }
+
+    /// The number of states in the automata
+    pub fn state_count(&self) -> usize {
+        self.slab.len()
+    }
+
+    /// The approximate number of bytes of memory used by the automata
+    pub fn memory_size(&self) -> usize {
+        std::mem::size_of::<Self>() + (self.slab.capacity() * std::mem::size_of::<State>())
+    }
+
+    pub fn iter(&self) -> AutomataIterator {
+        AutomataIterator {
+            automata: self,
+            stack: vec![(0, Character::Blank)],
+        }
+    }
+
+    /// Merge identical states.
+    /// Words should not be added after compressing
+    pub fn compress(&mut self) {
+        let mut leaves: HashMap<CharacterMap<Option<usize>>, usize> = Default::default();
+        let mut replacements: HashMap<usize, usize> = Default::default();
+        let mut removed: HashSet<usize> = Default::default();
+        loop {
+            leaves.clear();
+            replacements.clear();
+
+            for (index, state) in self.slab.iter().enumerate() {
+                match leaves.entry(state.inner) {
+                    std::collections::hash_map::Entry::Occupied(o) => {
+                        replacements.insert(index, *o.get());
+                        removed.insert(index);
+                    }
+                    std::collections::hash_map::Entry::Vacant(v) => {
+                        v.insert(index);
+                    }
+                }
+            }
+
+            if replacements.is_empty() {
+                break;
+            }
+            let mut changed = false;
+            for state in self.slab.iter_mut() {
+                for c in Character::iter() {
+                    if let Some(old_index) = state.inner.get(c) {
+                        if let Some(new_index) = replacements.get(old_index) {
+                            state.inner.set(c, Some(*new_index));
+                            changed = true;
+                        }
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        if removed.is_empty() {
+            return;
+        }
+
+        replacements.clear();
+        let mut new_slab: Vec<State> = Default::default();
+        let mut next_index = 0;
+
+        for (old_index, state) in self.slab.drain(..).enumerate() {
+            if removed.contains(&old_index) {
+                continue;
+            }
+
+            new_slab.push(state);
+            if old_index != next_index {
+                replacements.insert(old_index, next_index);
+            }
+            next_index += 1;
+        }
+        self.slab = new_slab;
+
+        for state in self.slab.iter_mut() {
+            for c in Character::iter() {
+                if let Some(old_index) = state.inner.get(c) {
+                    if let Some(new_index) = replacements.get(old_index) {
+                        state.inner.set(c, Some(*new_index));
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn contains(&self, w: &impl WordTrait) -> bool {
+        self.contains_characters(w.characters())
+    }
+
+    /// Whether any word starts with these characters
+    pub fn contains_prefix(&self, characters: &[Character]) -> bool {
+        let mut state = self.slab.first().unwrap();
+
+        for c in characters.iter() {
+            match state.inner.get(*c) {
+                Some(a) => state = self.slab.get(*a).unwrap(),
+                None => return false,
+            }
+        }
+        true
+    }
+
+    pub fn contains_characters(&self, characters: &[Character]) -> bool {
+        let mut state = self.slab.first().unwrap();
+
+        for c in characters.iter() {
+            match state.inner.get(*c) {
+                Some(a) => state = self.slab.get(*a).unwrap(),
+                None => return false,
+            }
+        }
+        state.inner.get(crate::Character::Blank).is_some()
+    }
+
+    pub fn find_all_words(&self, grid: &Grid) -> Vec<CharsArray> {
+        fn find_words_inner(
+            wa: &WordAutomata,
+            results: &mut Vec<CharsArray>,
+            current_index: usize,
+            grid: &Grid,
+            new_tile: crate::Tile,
+            used_tiles: GridSet,
+            previous_chars: &CharsArray,
+        ) {
+            let character = grid[new_tile];
+
+            if let Some(next_index) = wa.slab[current_index].inner.get(character) {
+                let state = &wa.slab[*next_index];
+                let mut next_chars = previous_chars.clone();
+                next_chars.push(character);
+
+                let next_used_tiles = used_tiles.with_bit_set(&new_tile, true);
+
+                for tile in new_tile.iter_adjacent().filter(|x| !used_tiles.get_bit(x)) {
+                    find_words_inner(
+                        wa,
+                        results,
+                        *next_index,
+                        grid,
+                        tile,
+                        next_used_tiles,
+                        &next_chars,
+                    );
+                }
+
+                if state.inner.get(crate::Character::Blank).is_some() {
+                    results.push(next_chars);
+                }
+            }
+        }
+
+        let mut result: Vec<CharsArray> = vec![];
+
+        for tile in crate::Tile::iter_by_row() {
+            find_words_inner(
+                self,
+                &mut result,
+                0,
+                grid,
+                tile,
+                GridSet::EMPTY,
+                &CharsArray::new(),
+            )
+        }
+
+        result
+    }
+
+    /// Returns true if the word was added
+    pub fn add_word(&mut self, w: &impl WordTrait) -> bool {
+        self.add_characters(w.characters())
+    }
+
+    /// Returns true if the word was added
+    pub fn add_characters(&mut self, characters: &[Character]) -> bool {
+        let mut state_index: usize = 0;
+
+        for c in characters.iter() {
+            match self.slab[state_index].inner.get(*c) {
+                Some(a) => {
+                    state_index = *a;
+                }
+                None => {
+                    let new_state = State::default();
+                    let new_state_index = self.slab.len();
+                    self.slab.push(new_state);
+
+                    self.slab[state_index].inner.set(*c, Some(new_state_index));
+                    state_index = new_state_index;
+                }
+            }
+        }
+
+        match self
+            .slab
+            .get(state_index)
+            .unwrap()
+            .inner
+            .get(crate::Character::Blank)
+        {
+            Some(_) => false, //word was already present
+            None => {
+                self.slab[state_index]
+                    .inner
+                    .set(crate::Character::Blank, Some(0));
+                true //word was added
+            }
+        }
+    }
+}
+
+impl WordAutomata {
+    /// Write the automata in a compact binary format.
+    ///
+    /// The format is the magic bytes `WSDA`, a version byte and the number of states.
+    /// Each state is a `u64` bitmask of the characters it has transitions for,
+    /// followed by the `u32` index of the state for each transition.
+    /// Word ends (blank transitions) always lead back to the start so have no index.
+    /// All numbers are little endian. Version 1 data, with `u32` masks, can still be read
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes: Vec<u8> = Vec::with_capacity(9 + (self.slab.len() * 12));
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(VERSION);
+        bytes.extend_from_slice(&(self.slab.len() as u32).to_le_bytes());
+
+        for state in self.slab.iter() {
+            let mask = state
+                .inner
+                .enumerate()
+                .filter(|(_, next)| next.is_some())
+                .fold(0u64, |mask, (c, _)| mask | (1 << c.into_prime_index()));
+            bytes.extend_from_slice(&mask.to_le_bytes());
+
+            for (c, next) in state.inner.enumerate() {
+                if let Some(next) = next {
+                    if !c.is_blank() {
+                        bytes.extend_from_slice(&(*next as u32).to_le_bytes());
+                    }
+                }
+            }
+        }
+
+        bytes
+    }
+
+    /// Read an automata written by `to_bytes`
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, &'static str> {
+        fn read_u32(bytes: &mut &[u8]) -> Result<u32, &'static str> {
+            if bytes.len() < 4 {
+                return Err("Dictionary data ended unexpectedly");
+            }
+            let (number, rest) = bytes.split_at(4);
+            *bytes = rest;
+            let number: [u8; 4] = number.try_into().map_err(|_| "Could not read number")?;
+            Ok(u32::from_le_bytes(number))
+        }
+
+        let mut bytes = bytes
+            .strip_prefix(MAGIC.as_slice())
+            .ok_or("Dictionary data has the wrong format")?;
+
+        let Some((&version, rest)) = bytes.split_first() else {
+            return Err("Dictionary data ended unexpectedly");
+        };
+        if version != VERSION && version != VERSION_U32_MASKS {
+            return Err("Dictionary data has an unsupported version");
+        }
+        bytes = rest;
+
+        let state_count = read_u32(&mut bytes)? as usize;
+        if state_count == 0 {
+            return Err("Dictionary data has no states");
+        }
+        let mut slab: Vec<State> = Vec::with_capacity(state_count);
+
+        for _ in 0..state_count {
+            let mask = if version == VERSION_U32_MASKS {
+                read_u32(&mut bytes)? as u64
+            } else {
+                let low = read_u32(&mut bytes)? as u64;
+                let high = read_u32(&mut bytes)? as u64;
+                low | (high << 32)
+            };
+            if mask >> Character::COUNT != 0 {
+                return Err("Dictionary data has an invalid character");
+            }
+            let mut state = State::default();
+            for c in Character::iter().filter(|c| mask & (1 << c.into_prime_index()) != 0) {
+                let next = if c.is_blank() {
+                    0
+                } else {
+                    read_u32(&mut bytes)? as usize
+                };
+                if next >= state_count {
+                    return Err("Dictionary data has an invalid state index");
+                }
+                state.inner.set(c, Some(next));
+            }
+            slab.push(state);
+        }
+
+        if !bytes.is_empty() {
+            return Err("Dictionary data has unexpected trailing bytes");
+        }
+
+        Ok(Self { slab })
+    }
+}
+
+/// A set of words which can be looked up and searched for in grids
+pub trait Dictionary {
+    fn contains_characters(&self, characters: &[Character]) -> bool;
+
+    /// Every word in the dictionary
+    fn words(&self) -> Box<dyn Iterator<Item = CharsArray> + '_>;
+
+    fn contains(&self, word: &impl WordTrait) -> bool
+    where
+        Self: Sized,
+    {
+        self.contains_characters(word.characters())
+    }
+
+    /// Whether any word starts with these characters
+    fn contains_prefix(&self, characters: &[Character]) -> bool;
+
+    /// Find every word which can be traced in the grid.
+    /// Each word is returned once, in sorted order
+    fn find_all_words(&self, grid: &Grid) -> Vec<CharsArray> {
+        self.words()
+            .filter(|word| RawCharacters(word).find_solution(grid).is_some())
+            .sorted()
+            .dedup()
+            .collect()
+    }
+
+    /// Restrict this dictionary to some of its words
+    fn filtered(self) -> FilteredDictionary<Self>
+    where
+        Self: Sized,
+    {
+        FilteredDictionary::new(self)
+    }
+}
+
+/// Lets a plain array of characters be searched for
+struct RawCharacters<'a>(&'a CharsArray);
+
+impl WordTrait for RawCharacters<'_> {
+    fn characters(&self) -> &CharsArray {
+        self.0
+    }
+}
+
+impl Dictionary for WordAutomata {
+    fn contains_characters(&self, characters: &[Character]) -> bool {
+        WordAutomata::contains_characters(self, characters)
+    }
+
+    fn words(&self) -> Box<dyn Iterator<Item = CharsArray> + '_> {
+        Box::new(self.iter())
+    }
+
+    fn contains_prefix(&self, characters: &[Character]) -> bool {
+        WordAutomata::contains_prefix(self, characters)
+    }
+
+    fn find_all_words(&self, grid: &Grid) -> Vec<CharsArray> {
+        WordAutomata::find_all_words(self, grid)
+            .into_iter()
+            .sorted()
+            .dedup()
+            .collect()
+    }
+}
+
+/// A dictionary read from text with one word per line.
+/// The text is parsed once, when the dictionary is made
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextDictionary {
+    pub alphabet: Alphabet,
+    words: SetDictionary,
+}
+
+impl TextDictionary {
+    pub fn new(text: &str) -> Self {
+        Self::with_alphabet(text, Alphabet::default())
+    }
+
+    pub fn with_alphabet(text: &str, alphabet: Alphabet) -> Self {
+        let words = text
+            .lines()
+            .filter_map(|line| {
+                crate::normalize_characters_array_with_alphabet(line, &alphabet)
+                    .ok()
+                    .filter(|word| !word.is_empty())
+            })
+            .collect();
+
+        Self { alphabet, words }
+    }
+}
+
+impl Dictionary for TextDictionary {
+    fn contains_characters(&self, characters: &[Character]) -> bool {
+        self.words.contains_characters(characters)
+    }
+
+    fn words(&self) -> Box<dyn Iterator<Item = CharsArray> + '_> {
+        self.words.words()
+    }
+
+    fn contains_prefix(&self, characters: &[Character]) -> bool {
+        self.words.contains_prefix(characters)
+    }
+}
+
+/// A dictionary held in memory as a sorted set of words
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SetDictionary(pub BTreeSet<CharsArray>);
+
+impl FromIterator<CharsArray> for SetDictionary {
+    fn from_iter<T: IntoIterator<Item = CharsArray>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Dictionary for SetDictionary {
+    fn contains_characters(&self, characters: &[Character]) -> bool {
+        CharsArray::try_from(characters)
+            .map(|word| self.0.contains(&word))
+            .unwrap_or_default()
+    }
+
+    fn words(&self) -> Box<dyn Iterator<Item = CharsArray> + '_> {
+        Box::new(self.0.iter().cloned())
+    }
+
+    /// Words which start with the prefix come straight after it in the set
+    fn contains_prefix(&self, characters: &[Character]) -> bool {
+        let Ok(prefix) = CharsArray::try_from(characters) else {
+            return false;
+        };
+        self.0
+            .range(prefix..)
+            .next()
+            .is_some_and(|word| word.starts_with(characters))
+    }
+}
+
+/// Some of the words from another dictionary
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilteredDictionary<D: Dictionary> {
+    pub inner: D,
+    pub min_length: usize,
+    pub excluded: HashSet<CharsArray>,
+}
+
+impl<D: Dictionary> FilteredDictionary<D> {
+    pub fn new(inner: D) -> Self {
+        Self {
+            inner,
+            min_length: 0,
+            excluded: Default::default(),
+        }
+    }
+
+    pub fn with_min_length(mut self, min_length: usize) -> Self {
+        self.min_length = min_length;
+        self
+    }
+
+    pub fn excluding(mut self, words: impl IntoIterator<Item = CharsArray>) -> Self {
+        self.excluded.extend(words);
+        self
+    }
+
+    fn is_allowed(&self, characters: &[Character]) -> bool {
+        characters.len() >= self.min_length
+            && CharsArray::try_from(characters)
+                .map(|word| !self.excluded.contains(&word))
+                .unwrap_or(true)
+    }
+}
+
+impl<D: Dictionary> Dictionary for FilteredDictionary<D> {
+    fn contains_characters(&self, characters: &[Character]) -> bool {
+        self.is_allowed(characters) && self.inner.contains_characters(characters)
+    }
+
+    fn words(&self) -> Box<dyn Iterator<Item = CharsArray> + '_> {
+        Box::new(self.inner.words().filter(|word| self.is_allowed(word)))
+    }
+
+    /// Checks the inner dictionary, so this may be true for a prefix of only excluded words.
+    /// That is enough to prune a search, which checks whole words with `contains_characters`
+    fn contains_prefix(&self, characters: &[Character]) -> bool {
+        self.inner.contains_prefix(characters)
+    }
+
+    fn find_all_words(&self, grid: &Grid) -> Vec<CharsArray> {
+        let mut words = self.inner.find_all_words(grid);
+        words.retain(|word| self.is_allowed(word));
+        words
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use itertools::Itertools;
+
+    use crate::{complete_solve::RawWord, normalize_characters_array_with_alphabet, try_make_grid};
+
+    use super::*;
+    #[test]
+    pub fn test_word_automata() {
+        let mark = RawWord::from_str("Mark").unwrap();
+        let mar = RawWord::from_str("Mar").unwrap();
+
+        let mut wa = WordAutomata::default();
+
+        assert!(!wa.contains(&mar));
+        assert!(!wa.contains(&mark));
+
+        assert!(wa.add_word(&mar));
+
+        assert!(wa.contains(&mar));
+        assert!(!wa.contains(&mark));
+
+        assert!(wa.add_word(&mark));
+
+        assert!(wa.contains(&mar));
+        assert!(wa.contains(&mark));
+    }
+
+    #[test]
+    pub fn test_iter() {
+        let mut wa = WordAutomata::default();
+
+        for word in [
+            "Earth", "Mars", "Neptune", "Pluto", "Saturn", "Uranus", "Venus", "Some", "Random",
+            "Word",
+        ] {
+            let word = RawWord::from_str(word).unwrap();
+            wa.add_word(&word);
+        }
+
+        wa.compress();
+
+        let joined = wa
+            .iter()
+            .map(|word| word.iter().map(|c| c.as_char()).join(""))
+            .join(", ");
+
+        assert_eq!(
+            joined,
+            "EARTH, NEPTUNE, SATURN, SOME, RANDOM, URANUS, MARS, WORD, PLUTO, VENUS"
+        );
+    }
+
+    #[test]
+    pub fn test_on_grid() {
+        let mut wa = WordAutomata::default();
+
+        for word in [
+            "Earth", "Mars", "Neptune", "Pluto", "Saturn", "Uranus", "Venus", "Some", "Random",
+            "Word",
+        ] {
+            let word = RawWord::from_str(word).unwrap();
+            wa.add_word(&word);
+        }
+        println!("Uncompressed - {} states", wa.slab.len());
+        wa.compress();
+        println!("Compressed - {} states", wa.slab.len());
+
+        let grid = try_make_grid("VENMOUAULTRSHPEN").unwrap();
+
+        let grid_words = wa.find_all_words(&grid);
+
+        let found_words = grid_words
+            .iter()
+            .map(|x| x.iter().map(|c| c.as_char()).join(""))
+            .join(", ");
+
+        assert_eq!(
+            found_words,
+            "VENUS, EARTH, MARS, URANUS, SATURN, PLUTO, NEPTUNE"
+        )
+    }
+
+    /* spellchecker:disable */
+    const PLANETS: &str = "Earth\nMars\nNeptune\nPluto\nSaturn\nUranus\nVenus\nSome\nRandom\nWord";
+    /* spellchecker:enable */
+
+    #[test]
+    pub fn test_binary_round_trip() {
+        let wa = WordAutomata::from_word_list(PLANETS, 4);
+
+        let bytes = wa.to_bytes();
+        let from_bytes = WordAutomata::try_from_bytes(&bytes).unwrap();
+
+        assert_eq!(from_bytes, wa);
+        assert_eq!(from_bytes.iter().collect_vec(), wa.iter().collect_vec());
+    }
+
+    #[test]
+    pub fn test_lookups_after_round_trip() {
+        let wa = WordAutomata::from_word_list(PLANETS, 4);
+        let wa = WordAutomata::try_from_bytes(&wa.to_bytes()).unwrap();
+
+        for word in PLANETS.lines() {
+            assert!(wa.contains(&RawWord::from_str(word).unwrap()), "{word}");
+        }
+
+        assert!(!wa.contains(&RawWord::from_str("Mercury").unwrap()));
+        assert!(!wa.contains(&RawWord::from_str("Mar").unwrap()));
+    }
+
+    #[test]
+    pub fn test_kept_letters_round_trip() {
+        let alphabet = AlphabetPreset::Nordic.alphabet();
+        let nordic =
+            |word: &str| normalize_characters_array_with_alphabet(word, &alphabet).unwrap();
+        let mut wa = WordAutomata::default();
+        for word in ["Malmö", "Åmål", "Mars"] {
+            wa.add_characters(&nordic(word));
+        }
+        wa.compress();
+
+        let wa = WordAutomata::try_from_bytes(&wa.to_bytes()).unwrap();
+
+        assert!(wa.contains_characters(&nordic("Åmål")));
+        assert!(wa.contains_characters(&nordic("Malmö")));
+        assert!(!wa.contains(&RawWord::from_str("Amal").unwrap()));
+        assert_eq!(wa.iter().count(), 3);
+        assert!(Dictionary::words(&wa).any(|word| word == nordic("Åmål")));
+    }
+
+    #[test]
+    pub fn test_cyrillic_round_trip() {
+        let alphabet = AlphabetPreset::Russian.alphabet();
+        let wa =
+            WordAutomata::from_word_list_with_alphabet("Москва\nЙошкар-Ола\nЩука", 4, &alphabet);
+        let wa = WordAutomata::try_from_bytes(&wa.to_bytes()).unwrap();
+
+        let words = Dictionary::words(&wa)
+            .map(|word| word.iter().map(|c| c.as_char()).join(""))
+            .sorted()
+            .collect_vec();
+        assert_eq!(words, ["ЙОШКАРОЛА", "МОСКВА", "ЩУКА"]);
+    }
+
+    #[test]
+    pub fn test_read_version_one() {
+        let wa = WordAutomata::from_word_list(PLANETS, 4);
+
+        let mut bytes = MAGIC.to_vec();
+        bytes.push(VERSION_U32_MASKS);
+        bytes.extend_from_slice(&(wa.slab.len() as u32).to_le_bytes());
+        for state in wa.slab.iter() {
+            let mask = state
+                .inner
+                .enumerate()
+                .filter(|(_, next)| next.is_some())
+                .fold(0u32, |mask, (c, _)| mask | (1 << c.into_prime_index()));
+            bytes.extend_from_slice(&mask.to_le_bytes());
+            for (c, next) in state.inner.enumerate() {
+                if let (Some(next), false) = (next, c.is_blank()) {
+                    bytes.extend_from_slice(&(*next as u32).to_le_bytes());
+                }
+            }
+        }
+
+        assert_eq!(WordAutomata::try_from_bytes(&bytes), Ok(wa));
+    }
+
+    #[test]
+    pub fn test_invalid_bytes() {
+        let bytes = WordAutomata::from_word_list(PLANETS, 4).to_bytes();
+
+        assert!(WordAutomata::try_from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(WordAutomata::try_from_bytes(&bytes[1..]).is_err());
+        assert!(WordAutomata::try_from_bytes(&[]).is_err());
+    }
+
+    #[test]
+    pub fn test_memory_size() {
+        let mut wa = WordAutomata::default();
+        for word in PLANETS.lines() {
+            wa.add_word(&RawWord::from_str(word).unwrap());
+        }
+        let uncompressed_states = wa.state_count();
+        wa.compress();
+        wa.slab.shrink_to_fit();
+
+        assert!(wa.state_count() < uncompressed_states);
+        assert_eq!(
+            wa.memory_size(),
+            std::mem::size_of::<WordAutomata>() + wa.state_count() * std::mem::size_of::<State>()
+        );
+
+        let bytes = wa.to_bytes();
+        assert!(bytes.len() < wa.memory_size());
+    }
+
+    #[test]
+    pub fn test_dictionary_backends() {
+        let automata = WordAutomata::from_word_list(PLANETS, 4);
+        let text = TextDictionary::new(PLANETS);
+        let set: SetDictionary = text.words().collect();
+        let filtered = WordAutomata::from_word_list(PLANETS, 4)
+            .filtered()
+            .with_min_length(5)
+            .excluding([crate::normalize_characters_array("Venus").unwrap()]);
+
+        let grid = try_make_grid("VENMOUAULTRSHPEN").unwrap();
+        let mars = RawWord::from_str("Mars").unwrap();
+        let venus = RawWord::from_str("Venus").unwrap();
+
+        for dictionary in [&automata as &dyn Dictionary, &text, &set] {
+            assert!(dictionary.contains_characters(&mars.characters));
+            assert!(dictionary.contains_prefix(&mars.characters[..2]));
+            assert_eq!(dictionary.words().count(), 10);
+            assert!(!dictionary.contains_prefix(&venus.characters[1..]));
+            assert_eq!(
+                dictionary.find_all_words(&grid),
+                Dictionary::find_all_words(&automata, &grid)
+            );
+        }
+
+        assert!(!filtered.contains(&mars));
+        assert!(!filtered.contains(&venus));
+        assert!(filtered.contains(&RawWord::from_str("Earth").unwrap()));
+        assert_eq!(filtered.words().count(), 6);
+    }
+}
@@ -0,0 +1,298 @@
+use std::sync::{Arc, Mutex};
+
+use itertools::Itertools;
+
+use crate::{finder::falling_probability, level_trait::LevelContents, prelude::*};
+
+const DIRECTION_CHANGE_WEIGHT: f32 = 1.0;
+const LENGTH_WEIGHT: f32 = 0.3;
+const ALTERNATIVE_PATH_WEIGHT: f32 = 0.6;
+const MAX_ALTERNATIVE_PATHS: usize = 4;
+const SHARED_LETTERS_WEIGHT: f32 = 4.0;
+const WORD_COUNT_WEIGHT: f32 = 0.4;
+const FALLING_WEIGHT: f32 = 3.0;
+/// Subtracted from every level score so that the built in levels spread across ratings 1 to 5
+const BASELINE_SCORE: f32 = 6.5;
+
+/// How hard a single word is to find
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordDifficulty {
+    pub word_index: usize,
+    /// The number of characters in the word
+    pub length: usize,
+    /// The fewest changes of direction in any path for this word
+    pub direction_changes: usize,
+    /// The number of distinct sets of tiles the word can be made from
+    pub paths: usize,
+    /// The proportion of this word's tiles which other words must use, on its least shared path
+    pub shared_letters: f32,
+    pub score: f32,
+}
+
+/// How hard a level is, with a breakdown for each word
+#[derive(Debug, Clone, PartialEq)]
+pub struct LevelDifficulty {
+    /// The probability that a letter falls after finding the first word
+    pub falling_probability: f32,
+    pub words: Vec<WordDifficulty>,
+    pub score: f32,
+}
+
+impl LevelDifficulty {
+    /// The score rounded to a rating between 1 and 5, as used by level metadata
+    pub fn rating(&self) -> u8 {
+        (self.score.round() as u8).clamp(1, 5)
+    }
+
+    /// The index of the hardest word, if there are any words
+    pub fn hardest_word(&self) -> Option<usize> {
+        self.words
+            .iter()
+            .max_by(|a, b| a.score.total_cmp(&b.score))
+            .map(|x| x.word_index)
+    }
+}
+
+impl std::fmt::Display for LevelDifficulty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{rating} ({score:.2}) falling {falling:.2}",
+            rating = self.rating(),
+            score = self.score,
+            falling = self.falling_probability
+        )
+    }
+}
+
+/// A level's difficulty, calculated the first time it is needed.
+/// It is calculated again if the level's grid or words change.
+/// Clones start with an empty cache
+#[derive(Default)]
+pub struct DifficultyCache(Mutex<Option<(LevelContents, Arc<LevelDifficulty>)>>);
+
+impl Clone for DifficultyCache {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl DifficultyCache {
+    pub fn get_or_calculate(&self, level: &DesignedLevel) -> Arc<LevelDifficulty> {
+        let contents = LevelContents::of(level);
+
+        if let Some(difficulty) = self.0.lock().ok().and_then(|cached| {
+            cached
+                .as_ref()
+                .filter(|(cached_contents, _)| *cached_contents == contents)
+                .map(|(_, difficulty)| difficulty.clone())
+        }) {
+            return difficulty;
+        }
+
+        let difficulty = Arc::new(calculate_difficulty(level));
+
+        if let Ok(mut cached) = self.0.lock() {
+            *cached = Some((contents, difficulty.clone()));
+        }
+
+        difficulty
+    }
+
+    pub fn is_calculated(&self) -> bool {
+        self.0.lock().map(|x| x.is_some()).unwrap_or_default()
+    }
+}
+
+impl std::fmt::Debug for DifficultyCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DifficultyCache")
+            .field("calculated", &self.is_calculated())
+            .finish()
+    }
+}
+
+impl DesignedLevel {
+    /// How difficult this level is likely to be, see `calculate_difficulty`
+    pub fn difficulty(&self) -> Arc<LevelDifficulty> {
+        self.difficulty_cache.get_or_calculate(self)
+    }
+}
+
+/// Calculate how difficult a level is likely to be.
+/// Prefer `DesignedLevel::difficulty`, which only calculates it once
+pub fn calculate_difficulty(level: &DesignedLevel) -> LevelDifficulty {
+    let solutions = level
+        .words
+        .iter()
+        .map(|word| level.find_all_word_solutions(word))
+        .collect_vec();
+
+    let falling_probability = if level.words.is_empty() {
+        0.0
+    } else {
+        falling_probability::calculate_falling_probability_1(level)
+    };
+
+    let words = level
+        .words
+        .iter()
+        .enumerate()
+        .map(|(word_index, word)| {
+            let word_solutions = &solutions[word_index];
+
+            let other_tiles = solutions
+                .iter()
+                .enumerate()
+                .filter(|(other_index, _)| *other_index != word_index)
+                .filter_map(|(_, other)| required_tiles(other))
+                .fold(GridSet::EMPTY, |acc, tiles| acc.union(&tiles));
+
+            let length = word.characters.len();
+            let direction_changes = word_solutions
+                .iter()
+                .map(|solution| count_direction_changes(solution))
+                .min()
+                .unwrap_or_default();
+            let paths = word_solutions
+                .iter()
+                .map(|solution| GridSet::from_iter(solution.iter().cloned()))
+                .fold(vec![], |mut acc: Vec<GridSet>, tiles| {
+                    if !acc.contains(&tiles) {
+                        acc.push(tiles);
+                    }
+                    acc
+                })
+                .len();
+            let shared_letters = word_solutions
+                .iter()
+                .filter(|solution| !solution.is_empty())
+                .map(|solution| {
+                    solution.iter().filter(|t| other_tiles.get_bit(t)).count() as f32
+                        / solution.len() as f32
+                })
+                .min_by(|a, b| a.total_cmp(b))
+                .unwrap_or_default();
+
+            let score = (direction_changes as f32 * DIRECTION_CHANGE_WEIGHT)
+                + (length as f32 * LENGTH_WEIGHT)
+                - (paths.saturating_sub(1).min(MAX_ALTERNATIVE_PATHS) as f32
+                    * ALTERNATIVE_PATH_WEIGHT)
+                + (shared_letters * SHARED_LETTERS_WEIGHT);
+
+            WordDifficulty {
+                word_index,
+                length,
+                direction_changes,
+                paths,
+                shared_letters,
+                score: score.max(0.0),
+            }
+        })
+        .collect_vec();
+
+    let mean_word_score = if words.is_empty() {
+        0.0
+    } else {
+        words.iter().map(|x| x.score).sum::<f32>() / words.len() as f32
+    };
+
+    let score = mean_word_score + (words.len() as f32 * WORD_COUNT_WEIGHT)
+        - (falling_probability * FALLING_WEIGHT)
+        - BASELINE_SCORE;
+
+    LevelDifficulty {
+        falling_probability,
+        words,
+        score: score.max(0.0),
+    }
+}
+
+/// The tiles used by every path of a word, if it has any paths
+fn required_tiles(solutions: &[Solution]) -> Option<GridSet> {
+    solutions
+        .iter()
+        .map(|solution| GridSet::from_iter(solution.iter().cloned()))
+        .reduce(|acc, tiles| acc.intersect(&tiles))
+}
+
+/// The number of times a path changes direction
+pub fn count_direction_changes(solution: &[Tile]) -> usize {
+    solution
+        .iter()
+        .tuple_windows()
+        .map(|(a, b)| (b.x() as i8 - a.x() as i8, b.y() as i8 - a.y() as i8))
+        .tuple_windows()
+        .filter(|(d1, d2)| d1 != d2)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_count_direction_changes() {
+        let straight = [
+            Tile::new_const::<0, 0>(),
+            Tile::new_const::<1, 0>(),
+            Tile::new_const::<2, 0>(),
+            Tile::new_const::<3, 0>(),
+        ];
+        let zig_zag = [
+            Tile::new_const::<0, 0>(),
+            Tile::new_const::<1, 1>(),
+            Tile::new_const::<2, 0>(),
+            Tile::new_const::<3, 1>(),
+        ];
+
+        assert_eq!(count_direction_changes(&straight), 0);
+        assert_eq!(count_direction_changes(&zig_zag), 2);
+    }
+
+    #[test]
+    pub fn test_word_breakdown() {
+        let level = DesignedLevel::from_tsv_line(
+            // spellchecker:disable-next-line
+            "ASHPKILOEUIOGNDT\tSports\tPOLO\tSHOOTING\tKENDO\tSAILING\tLUGE\tSKIING",
+        )
+        .unwrap();
+
+        let difficulty = calculate_difficulty(&level);
+
+        assert_eq!(difficulty.words.len(), level.words.len());
+        assert!(difficulty.words.iter().all(|x| x.paths > 0));
+        assert!((1..=5).contains(&difficulty.rating()));
+    }
+
+    #[test]
+    pub fn test_clones_do_not_share_cached_difficulty() {
+        let level = DesignedLevel::from_tsv_line(
+            // spellchecker:disable-next-line
+            "ASHPKILOEUIOGNDT\tSports\tPOLO\tSHOOTING\tKENDO\tSAILING\tLUGE\tSKIING",
+        )
+        .unwrap();
+
+        assert_eq!(*level.difficulty(), calculate_difficulty(&level));
+        assert!(level.difficulty_cache.is_calculated());
+        assert!(!level.clone().difficulty_cache.is_calculated());
+    }
+
+    #[test]
+    pub fn test_difficulty_changes_with_words() {
+        let mut level = DesignedLevel::from_tsv_line(
+            // spellchecker:disable-next-line
+            "ASHPKILOEUIOGNDT\tSports\tPOLO\tSHOOTING\tKENDO\tSAILING\tLUGE\tSKIING",
+        )
+        .unwrap();
+        let before = level.difficulty();
+
+        level.words.reverse();
+        assert_eq!(*level.difficulty(), calculate_difficulty(&level));
+        assert_eq!(level.difficulty().words[0].length, before.words[5].length);
+
+        level.words.pop();
+        assert_eq!(*level.difficulty(), calculate_difficulty(&level));
+        assert_eq!(level.difficulty().words.len(), 5);
+    }
+}
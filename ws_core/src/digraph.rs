@@ -0,0 +1,235 @@
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use strum::{EnumIter, IntoEnumIterator};
+
+use crate::prelude::*;
+
+/// A pair of letters which share a single grid tile
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumIter)]
+pub enum Digraph {
+    Qu,
+    Th,
+    Er,
+    In,
+    He,
+    An,
+}
+
+/// The digraphs on each tile of a grid.
+/// The grid itself holds the first letter of each digraph
+pub type DigraphMap =
+    geometrid::tile_map::TileMap<Option<Digraph>, GRID_WIDTH, GRID_HEIGHT, GRID_SIZE>;
+
+impl Digraph {
+    pub const fn characters(&self) -> [Character; 2] {
+        match self {
+            Digraph::Qu => [Character::Q, Character::U],
+            Digraph::Th => [Character::T, Character::H],
+            Digraph::Er => [Character::E, Character::R],
+            Digraph::In => [Character::I, Character::N],
+            Digraph::He => [Character::H, Character::E],
+            Digraph::An => [Character::A, Character::N],
+        }
+    }
+
+    pub fn first(&self) -> Character {
+        self.characters()[0]
+    }
+
+    pub fn try_from_characters(first: Character, second: Character) -> Option<Self> {
+        Self::iter().find(|x| x.characters() == [first, second])
+    }
+
+    pub fn to_tile_string(&self) -> String {
+        let [a, b] = self.characters();
+        format!("{}{}", a.as_char(), b.as_char().to_ascii_lowercase())
+    }
+}
+
+impl std::fmt::Display for Digraph {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [a, b] = self.characters();
+        write!(f, "({a}{b})")
+    }
+}
+
+/// The characters on a tile, taking digraphs into account
+pub fn tile_characters(grid: &Grid, digraphs: &DigraphMap, tile: Tile) -> ArrayVec<Character, 2> {
+    match digraphs[tile] {
+        Some(digraph) => ArrayVec::from(digraph.characters()),
+        None => ArrayVec::from_iter([grid[tile]]),
+    }
+}
+
+/// The characters spelled by a path through a grid, taking digraphs into account
+pub fn path_characters(grid: &Grid, digraphs: &DigraphMap, path: &[Tile]) -> CharsArray {
+    let mut result = CharsArray::new();
+    for tile in path {
+        for c in tile_characters(grid, digraphs, *tile) {
+            if result.try_push(c).is_err() {
+                return result;
+            }
+        }
+    }
+    result
+}
+
+/// Make a grid where digraph tiles are written in brackets e.g. `AB(QU)D`
+pub fn try_make_grid_with_digraphs(text: &str) -> Option<(Grid, DigraphMap)> {
+    let mut grid = Grid::from_inner([Character::Blank; GRID_SIZE]);
+    let mut digraphs = DigraphMap::from_inner([None; GRID_SIZE]);
+    let mut chars = text.chars();
+    let mut tiles = Tile::iter_by_row();
+
+    while let Some(char) = chars.next() {
+        let tile = tiles.next()?;
+        if char == '(' {
+            let first = Character::try_from(chars.next()?).ok()?;
+            let second = Character::try_from(chars.next()?).ok()?;
+            if chars.next()? != ')' {
+                return None;
+            }
+            let digraph = Digraph::try_from_characters(first, second)?;
+            grid[tile] = digraph.first();
+            digraphs[tile] = Some(digraph);
+        } else {
+            grid[tile] = Character::try_from(char).ok()?;
+        }
+    }
+
+    Some((grid, digraphs))
+}
+
+/// Write a grid with digraph tiles in brackets
+pub fn grid_text_with_digraphs(grid: &Grid, digraphs: &DigraphMap) -> String {
+    grid.enumerate()
+        .map(|(tile, character)| match digraphs[tile] {
+            Some(digraph) => digraph.to_string(),
+            None => character.to_string(),
+        })
+        .join("")
+}
+
+/// Find paths for a word where some tiles may hold two letters
+pub fn find_solutions_with_digraphs(
+    characters: &[Character],
+    grid: &Grid,
+    digraphs: &DigraphMap,
+    first_only: bool,
+) -> Vec<Solution> {
+    #[allow(clippy::too_many_arguments)]
+    fn find_inner(
+        remaining: &[Character],
+        grid: &Grid,
+        digraphs: &DigraphMap,
+        tile: Tile,
+        used_tiles: GridSet,
+        path: &mut Solution,
+        results: &mut Vec<Solution>,
+        first_only: bool,
+    ) {
+        if first_only && !results.is_empty() {
+            return;
+        }
+        let tile_characters = tile_characters(grid, digraphs, tile);
+        if !remaining.starts_with(&tile_characters) {
+            return;
+        }
+        let remaining = &remaining[tile_characters.len()..];
+        path.push(tile);
+        if remaining.is_empty() {
+            results.push(path.clone());
+        } else {
+            let used_tiles = used_tiles.with_bit_set(&tile, true);
+            for next in tile.iter_adjacent().filter(|x| !used_tiles.get_bit(x)) {
+                find_inner(
+                    remaining, grid, digraphs, next, used_tiles, path, results, first_only,
+                );
+            }
+        }
+        path.pop();
+    }
+
+    let mut results = vec![];
+    if characters.is_empty() {
+        return results;
+    }
+
+    for tile in Tile::iter_by_row() {
+        let mut path = Solution::new();
+        find_inner(
+            characters,
+            grid,
+            digraphs,
+            tile,
+            GridSet::EMPTY,
+            &mut path,
+            &mut results,
+            first_only,
+        );
+        if first_only && !results.is_empty() {
+            break;
+        }
+    }
+
+    results
+}
+
+/// Find a path for a word where some tiles may hold two letters, without using unneeded tiles
+pub fn find_word_path_with_digraphs(
+    characters: &[Character],
+    grid: &Grid,
+    digraphs: &DigraphMap,
+    unneeded_tiles: GridSet,
+) -> Option<Solution> {
+    let mut grid = *grid;
+    let mut digraphs = *digraphs;
+    for tile in unneeded_tiles.iter_true_tiles() {
+        grid[tile] = Character::Blank;
+        digraphs[tile] = None;
+    }
+    find_solutions_with_digraphs(characters, &grid, &digraphs, true)
+        .into_iter()
+        .next()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    /* spellchecker:disable */
+    #[test]
+    pub fn test_grid_round_trip() {
+        let text = "(QU)EENXXXXXXXXXXXX";
+        let (grid, digraphs) = try_make_grid_with_digraphs(text).unwrap();
+
+        assert_eq!(digraphs[Tile::new_const::<0, 0>()], Some(Digraph::Qu));
+        assert_eq!(grid[Tile::new_const::<0, 0>()], Character::Q);
+        assert_eq!(grid_text_with_digraphs(&grid, &digraphs), text);
+    }
+
+    #[test]
+    pub fn test_find_digraph_path() {
+        let (grid, digraphs) = try_make_grid_with_digraphs("(QU)EENXXXXXXXXXXXX").unwrap();
+        let queen = Word::from_str("queen").unwrap();
+
+        assert!(queen.find_solution(&grid).is_none());
+
+        let solutions = find_solutions_with_digraphs(&queen.characters, &grid, &digraphs, false);
+
+        let expected: Solution = ArrayVec::from_iter([
+            Tile::new_const::<0, 0>(),
+            Tile::new_const::<1, 0>(),
+            Tile::new_const::<2, 0>(),
+            Tile::new_const::<3, 0>(),
+        ]);
+
+        assert_eq!(solutions, vec![expected.clone()]);
+        assert_eq!(
+            path_characters(&grid, &digraphs, &expected),
+            queen.characters
+        );
+    }
+}
@@ -69,26 +69,32 @@ impl Ord for DisplayWord {
 }
 
 impl FromStr for DisplayWord {
-    type Err = &'static str;
+    type Err = WordParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_with_alphabet(s, &Alphabet::default())
+    }
+}
+
+impl DisplayWord {
+    pub fn from_str_with_alphabet(s: &str, alphabet: &Alphabet) -> Result<Self, WordParseError> {
         let mut hidden_text: String = Default::default();
         let mut graphemes: Vec<CharGrapheme> = Default::default();
         let mut stack: usize = 0;
-        let mut characters: ArrayVec<Character, 16> = Default::default();
+        let mut characters: CharsArray = Default::default();
 
         let unicode_graphemes = unicode_segmentation::UnicodeSegmentation::graphemes(s, true);
 
-        for grapheme in unicode_graphemes {
-            let mut normalized = unicode_normalization::UnicodeNormalization::nfd(grapheme);
-
-            let Some(c) = normalized.next() else {
+        for (position, grapheme) in unicode_graphemes.enumerate() {
+            let Some(c) = grapheme.chars().next() else {
                 continue;
             };
 
-            let character = Character::try_from(c)?;
+            let grapheme_characters = alphabet
+                .grapheme_characters(grapheme)
+                .map_err(|_| WordParseError::InvalidCharacter { position })?;
 
-            if character.is_blank() {
+            if grapheme_characters.is_empty() {
                 if let Some(char_to_push) = {
                     if ['-', '‐', '–', '—'].contains(&c) {
                         Some('-')
@@ -107,14 +113,16 @@ impl FromStr for DisplayWord {
 
                 // otherwise ignore the character in the hidden text
             } else {
-                characters
-                    .try_push(character)
-                    .map_err(|_| "Word is too long")?;
-                stack += 1;
+                for character in grapheme_characters.iter() {
+                    characters
+                        .try_push(*character)
+                        .map_err(|_| WordParseError::TooLong)?;
+                    stack += 1;
+                }
             }
 
             graphemes.push(CharGrapheme {
-                is_game_char: !character.is_blank(),
+                is_game_char: !grapheme_characters.is_empty(),
                 grapheme: Ustr::from(grapheme),
             })
         }
@@ -123,7 +131,7 @@ impl FromStr for DisplayWord {
         }
 
         if characters.len() <= 3 {
-            return Err("Word has 3 or fewer characters");
+            return Err(WordParseError::TooShort);
         }
 
         Ok(Self {
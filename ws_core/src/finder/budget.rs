@@ -0,0 +1,264 @@
+use itertools::Itertools;
+
+use super::{
+    counter::Counter,
+    helpers::{FinderDigraphs, FinderSingleWord, LetterCounts},
+    node::{try_make_grid_with_blank_filling, GridResult},
+};
+use crate::{Character, Digraph};
+
+/// How many steps to take between checks for cancellation
+const CANCEL_CHECK_INTERVAL: usize = 256;
+/// The finder can only track this many letters which appear more than once
+const MAX_MULTI_CONSTRAINTS: usize = 8;
+
+/// A counter which gives up after a fixed number of steps or when cancelled
+pub struct BudgetedCounter<F: FnMut() -> bool> {
+    pub max: usize,
+    pub current: usize,
+    pub is_cancelled: F,
+    pub cancelled: bool,
+}
+
+impl<F: FnMut() -> bool> BudgetedCounter<F> {
+    pub fn new(max: usize, is_cancelled: F) -> Self {
+        Self {
+            max,
+            current: 0,
+            is_cancelled,
+            cancelled: false,
+        }
+    }
+}
+
+impl<F: FnMut() -> bool> Counter for BudgetedCounter<F> {
+    fn try_increment(&mut self) -> bool {
+        if self.cancelled || self.current >= self.max {
+            return false;
+        }
+        if self.current % CANCEL_CHECK_INTERVAL == 0 && (self.is_cancelled)() {
+            self.cancelled = true;
+            return false;
+        }
+        self.current += 1;
+        true
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum BudgetedGridResult {
+    Found(GridResult),
+    /// The words can never fit in a grid
+    Impossible,
+    /// Every arrangement was tried and none worked
+    NotFound,
+    /// The search used all of its steps
+    OutOfSteps,
+    /// The search was cancelled before it finished
+    Cancelled,
+}
+
+/// Try to make a grid containing all of the words, taking at most `max_steps` steps.
+/// The result only depends on the words and the step count unless `is_cancelled` returns true,
+/// so it is the same on every platform.
+pub fn try_make_grid_budgeted(
+    words: &[FinderSingleWord],
+    exclude_words: &[FinderSingleWord],
+    max_steps: usize,
+    is_cancelled: impl FnMut() -> bool,
+) -> BudgetedGridResult {
+    try_make_grid_with_digraphs_budgeted(words, exclude_words, &[], max_steps, is_cancelled)
+}
+
+/// Like `try_make_grid_budgeted`, but each of these digraphs which appears in the words
+/// may be placed on a single tile
+pub fn try_make_grid_with_digraphs_budgeted(
+    words: &[FinderSingleWord],
+    exclude_words: &[FinderSingleWord],
+    digraphs: &[Digraph],
+    max_steps: usize,
+    is_cancelled: impl FnMut() -> bool,
+) -> BudgetedGridResult {
+    let Some(digraphs) = FinderDigraphs::new(digraphs, words, exclude_words) else {
+        return BudgetedGridResult::Impossible;
+    };
+    let Some(tile_words) = words
+        .iter()
+        .map(|word| digraphs.replace_digraphs(word))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return BudgetedGridResult::Impossible;
+    };
+    let Some(tile_exclude_words) = exclude_words
+        .iter()
+        .map(|word| digraphs.replace_digraphs(word))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return BudgetedGridResult::Impossible;
+    };
+
+    let Some(letters) = letters_with_blanks(&tile_words) else {
+        return BudgetedGridResult::Impossible;
+    };
+
+    let mut counter = BudgetedCounter::new(max_steps, is_cancelled);
+    let mut solution: Option<GridResult> = None;
+    try_make_grid_with_blank_filling(
+        letters,
+        &tile_words,
+        &tile_exclude_words,
+        &digraphs,
+        Character::E,
+        &mut counter,
+        &mut solution,
+    );
+
+    match solution {
+        Some(grid) => BudgetedGridResult::Found(grid),
+        None if counter.cancelled => BudgetedGridResult::Cancelled,
+        None if counter.current >= counter.max => BudgetedGridResult::OutOfSteps,
+        None => BudgetedGridResult::NotFound,
+    }
+}
+
+/// The letters needed for the words, padded with blanks to fill the grid.
+/// Returns `None` if the finder could not handle these letters.
+pub fn letters_with_blanks(words: &[FinderSingleWord]) -> Option<LetterCounts> {
+    let mut letters = LetterCounts::default();
+    for word in words {
+        letters = letters.try_union(&word.counts)?;
+    }
+
+    let letter_count = letters.into_iter().count();
+    if letter_count > 16 {
+        return None;
+    }
+
+    for _ in letter_count..16 {
+        letters = letters.try_insert(Character::Blank)?;
+    }
+
+    // Filling each blank can add at most one more repeated letter
+    let blanks = 16 - letter_count;
+    let repeated = letters
+        .into_iter()
+        .filter(|c| !c.is_blank())
+        .counts()
+        .into_values()
+        .filter(|count| *count > 1)
+        .count();
+    if repeated + blanks > MAX_MULTI_CONSTRAINTS {
+        return None;
+    }
+
+    Some(letters)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use std::str::FromStr;
+
+    fn words(text: &str) -> Vec<FinderSingleWord> {
+        text.lines()
+            .map(|x| FinderSingleWord::from_str(x).unwrap())
+            .collect()
+    }
+
+    #[test]
+    pub fn test_budgeted_grid_is_found() {
+        let words = words("Bishop\nPawn\nKing\nKnight\nQueen");
+        let BudgetedGridResult::Found(result) =
+            try_make_grid_budgeted(&words, &[], 1_000_000, || false)
+        else {
+            panic!("Should find a grid");
+        };
+
+        for word in words.iter() {
+            assert!(word.find_solution(&result.grid).is_some());
+        }
+    }
+
+    #[test]
+    pub fn test_budgeted_grid_is_deterministic() {
+        let words = words("Bishop\nPawn\nKing\nKnight\nQueen");
+        let grids =
+            [0, 1].map(
+                |_| match try_make_grid_budgeted(&words, &[], 1_000_000, || false) {
+                    BudgetedGridResult::Found(result) => result.grid,
+                    other => panic!("Should find a grid, got {other:?}"),
+                },
+            );
+
+        assert_eq!(grids[0], grids[1]);
+    }
+
+    #[test]
+    pub fn test_budget_runs_out() {
+        let words = words("Bishop\nPawn\nKing\nKnight\nQueen");
+        assert!(matches!(
+            try_make_grid_budgeted(&words, &[], 1, || false),
+            BudgetedGridResult::OutOfSteps
+        ));
+    }
+
+    #[test]
+    pub fn test_cancellation() {
+        let words = words("Bishop\nPawn\nKing\nKnight\nQueen");
+        assert!(matches!(
+            try_make_grid_budgeted(&words, &[], 1_000_000, || true),
+            BudgetedGridResult::Cancelled
+        ));
+    }
+
+    #[test]
+    pub fn test_too_many_letters() {
+        let words = words("Abcdefghij\nKlmnopqrst");
+        assert!(matches!(
+            try_make_grid_budgeted(&words, &[], 1_000_000, || false),
+            BudgetedGridResult::Impossible
+        ));
+    }
+
+    #[test]
+    pub fn test_grid_with_kept_letters() {
+        let words = ["Malmö", "Åmål", "Örebro"]
+            .map(|x| {
+                DisplayWord::from_str_with_alphabet(x, &AlphabetPreset::Nordic.alphabet()).unwrap()
+            })
+            .map(|x| FinderSingleWord::from(&x));
+        let BudgetedGridResult::Found(result) =
+            try_make_grid_budgeted(&words, &[], 1_000_000, || false)
+        else {
+            panic!("Should find a grid");
+        };
+
+        for word in words.iter() {
+            assert!(word.find_solution(&result.grid).is_some());
+        }
+        assert!(result.grid.iter().any(|x| *x == Character::ARing));
+    }
+
+    #[test]
+    pub fn test_grid_with_digraphs() {
+        let words = words("Queen\nQuiet\nQuote\nSquid");
+        let BudgetedGridResult::Found(result) =
+            try_make_grid_with_digraphs_budgeted(&words, &[], &[Digraph::Qu], 1_000_000, || false)
+        else {
+            panic!("Should find a grid");
+        };
+
+        let digraphs = result.digraphs.expect("Grid should have digraphs");
+        assert_eq!(digraphs.iter().flatten().collect_vec(), [&Digraph::Qu]);
+        assert!(!result.grid.iter().any(|x| *x == Character::U));
+        assert_eq!(result.words, words);
+        for word in words.iter() {
+            assert!(
+                result.find_word_path(word, GridSet::EMPTY).is_some(),
+                "{}",
+                word.text
+            );
+        }
+    }
+}
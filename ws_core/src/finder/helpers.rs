@@ -4,6 +4,7 @@ use crate::{prelude::*, word_trait::WordTrait};
 use const_sized_bit_set::BitSet;
 use itertools::Itertools;
 use prime_bag::PrimeBag128;
+use strum::IntoEnumIterator;
 use ustr::ustr;
 
 pub type LetterCounts = PrimeBag128<Character>;
@@ -158,7 +159,7 @@ impl FromStr for FinderSingleWord {
     type Err = &'static str;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let word = Word::from_str(s)?;
+        let word = Word::from_str(s).map_err(|e| e.as_str())?;
 
         let counts: PrimeBag128<Character> =
             PrimeBag128::try_from_iter(word.characters.iter().cloned())
@@ -186,6 +187,160 @@ impl From<&DisplayWord> for FinderSingleWord {
     }
 }
 
+/// Digraphs which the finder may place on a single tile.
+/// In finder words each digraph is replaced by a stand-in character which no word otherwise uses,
+/// so digraph tiles are counted and placed like any other letter
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FinderDigraphs {
+    stand_ins: Vec<(Digraph, Character)>,
+}
+
+impl FinderDigraphs {
+    /// Choose stand-ins for those digraphs which appear in the words.
+    /// Returns `None` if there are not enough unused characters
+    pub fn new(
+        digraphs: &[Digraph],
+        words: &[FinderSingleWord],
+        exclude_words: &[FinderSingleWord],
+    ) -> Option<Self> {
+        let needed = digraphs
+            .iter()
+            .filter(|digraph| {
+                words
+                    .iter()
+                    .any(|word| split_tiles(digraphs, &word.array).any(|x| x.1 == Some(**digraph)))
+            })
+            .copied()
+            .collect_vec();
+
+        let used: CharacterMap<bool> =
+            words
+                .iter()
+                .chain(exclude_words)
+                .fold(CharacterMap::default(), |mut used, word| {
+                    for (character, _) in
+                        split_tiles(&needed, &word.array).filter(|x| x.1.is_none())
+                    {
+                        used.set(character, true);
+                    }
+                    used
+                });
+
+        let mut unused = Character::iter().filter(|c| !c.is_blank() && !used.get(*c));
+        let stand_ins = needed
+            .into_iter()
+            .map(|digraph| unused.next().map(|c| (digraph, c)))
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Self { stand_ins })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stand_ins.is_empty()
+    }
+
+    fn digraphs(&self) -> Vec<Digraph> {
+        self.stand_ins.iter().map(|x| x.0).collect()
+    }
+
+    /// The word with each digraph replaced by its stand-in
+    pub fn replace_digraphs(&self, word: &FinderSingleWord) -> Option<FinderSingleWord> {
+        if self.is_empty() {
+            return Some(word.clone());
+        }
+        let digraphs = self.digraphs();
+        let array: CharsArray = split_tiles(&digraphs, &word.array)
+            .map(|(character, digraph)| match digraph {
+                Some(digraph) => self.stand_in(digraph),
+                None => character,
+            })
+            .collect();
+        let counts = PrimeBag128::try_from_iter(array.iter().cloned())?;
+
+        Some(FinderSingleWord {
+            text: word.text,
+            array,
+            counts,
+        })
+    }
+
+    /// The word with each stand-in replaced by its digraph
+    pub fn restore_word(&self, word: &FinderSingleWord) -> FinderSingleWord {
+        if self.is_empty() {
+            return word.clone();
+        }
+        let array: CharsArray = word
+            .array
+            .iter()
+            .flat_map(|c| match self.digraph(*c) {
+                Some(digraph) => ArrayVec::from(digraph.characters()),
+                None => ArrayVec::from_iter([*c]),
+            })
+            .take(GRID_SIZE)
+            .collect();
+        let counts = PrimeBag128::try_from_iter(array.iter().cloned())
+            .expect("A restored word should have the letters of the original");
+
+        FinderSingleWord {
+            text: word.text,
+            array,
+            counts,
+        }
+    }
+
+    /// The grid with the first letter of each digraph in place of its stand-in,
+    /// and the digraph on each of those tiles
+    pub fn restore_grid(&self, grid: &Grid) -> (Grid, Option<DigraphMap>) {
+        if self.is_empty() {
+            return (*grid, None);
+        }
+        let mut grid = *grid;
+        let mut digraphs = DigraphMap::from_inner([None; GRID_SIZE]);
+        for tile in Tile::iter_by_row() {
+            if let Some(digraph) = self.digraph(grid[tile]) {
+                grid[tile] = digraph.first();
+                digraphs[tile] = Some(digraph);
+            }
+        }
+        (grid, Some(digraphs))
+    }
+
+    fn stand_in(&self, digraph: Digraph) -> Character {
+        self.stand_ins
+            .iter()
+            .find(|x| x.0 == digraph)
+            .map(|x| x.1)
+            .expect("Digraph should have a stand-in")
+    }
+
+    fn digraph(&self, character: Character) -> Option<Digraph> {
+        self.stand_ins
+            .iter()
+            .find(|x| x.1 == character)
+            .map(|x| x.0)
+    }
+}
+
+/// Split characters into tiles, taking digraphs greedily from the start.
+/// Each tile is its first character and its digraph, if it has one
+fn split_tiles<'a>(
+    digraphs: &'a [Digraph],
+    characters: &'a [Character],
+) -> impl Iterator<Item = (Character, Option<Digraph>)> + 'a {
+    let mut index = 0;
+    std::iter::from_fn(move || {
+        let first = *characters.get(index)?;
+        let digraph = characters.get(index + 1).and_then(|second| {
+            digraphs
+                .iter()
+                .find(|d| d.characters() == [first, *second])
+                .copied()
+        });
+        index += if digraph.is_some() { 2 } else { 1 };
+        Some((first, digraph))
+    })
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub(crate) struct AdjacencyStrength {
     appearances: u8,
@@ -229,6 +384,7 @@ mod tests {
     use test_case::test_case;
 
     use super::FinderSingleWord;
+    use crate::prelude::*;
 
     #[test_case("abcd", "bcde", false)]
     #[test_case("abcd", "abcde", true)]
@@ -242,4 +398,19 @@ mod tests {
 
         assert_eq!(actual, expected)
     }
+
+    #[test]
+    fn test_letter_counts_with_kept_letters() {
+        let word =
+            DisplayWord::from_str_with_alphabet("Räksmörgås", &AlphabetPreset::Nordic.alphabet())
+                .unwrap();
+        let word = FinderSingleWord::from(&word);
+
+        assert_eq!(word.counts.into_iter().count(), 10);
+        for c in [Character::ARing, Character::AUmlaut, Character::OUmlaut] {
+            assert!(word.counts.contains(c));
+        }
+        assert!(!word.counts.contains(Character::A));
+        assert!(!word.counts.contains(Character::O));
+    }
 }
@@ -1,5 +1,6 @@
 use crate::{CharacterMap, Tile};
 
+pub mod budget;
 pub mod cluster;
 pub mod cluster_ordering;
 pub mod counter;
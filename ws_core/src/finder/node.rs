@@ -7,7 +7,7 @@ use self::helpers::FinderGroup;
 
 use super::{
     counter::{Counter, SolutionCollector},
-    helpers::{FinderSingleWord, LetterCounts},
+    helpers::{FinderDigraphs, FinderSingleWord, LetterCounts},
     partial_grid::{NodeMap, PartialGrid},
 };
 use crate::finder::*;
@@ -17,6 +17,8 @@ use crate::finder::*;
 #[derive(Debug, Clone)]
 pub struct GridResult {
     pub grid: Grid,
+    /// Tiles which hold two letters
+    pub digraphs: Option<DigraphMap>,
     pub letters: LetterCounts,
     pub words: Vec<FinderSingleWord>,
 }
@@ -31,6 +33,22 @@ impl LevelTrait for GridResult {
     fn words(&self) -> &[Self::Word] {
         self.words.as_slice()
     }
+
+    fn tile_characters(&self, tile: Tile) -> ArrayVec<Character, 2> {
+        match &self.digraphs {
+            Some(digraphs) => tile_characters(&self.grid, digraphs, tile),
+            None => ArrayVec::from_iter([self.grid[tile]]),
+        }
+    }
+
+    fn find_word_path(&self, word: &Self::Word, unneeded_tiles: GridSet) -> Option<Solution> {
+        match self.digraphs {
+            Some(digraphs) => {
+                find_word_path_with_digraphs(&word.array, &self.grid, &digraphs, unneeded_tiles)
+            }
+            None => word.find_solution_with_tiles(&self.grid, unneeded_tiles),
+        }
+    }
 }
 
 impl GridResult {
@@ -56,13 +74,19 @@ impl GridResult {
 
 impl From<&DesignedLevel> for GridResult {
     fn from(value: &DesignedLevel) -> Self {
-        let DesignedLevel { grid, words, .. } = value;
+        let DesignedLevel {
+            grid,
+            digraphs,
+            words,
+            ..
+        } = value;
 
-        let letters = LetterCounts::try_from_iter(grid.iter().cloned()).unwrap();
+        let letters = value.letter_counts().unwrap();
 
         let words = words.iter().map(|x| x.into()).collect_vec();
         GridResult {
             grid: *grid,
+            digraphs: *digraphs,
             letters,
             words,
         }
@@ -78,7 +102,14 @@ impl FromStr for GridResult {
         let chars: &str = iter.next().ok_or("Level should have a grid")?;
         let _name: &str = iter.next().ok_or("Level should have name")?;
 
-        let grid = crate::prelude::try_make_grid(chars).ok_or("Should be able to make grid")?;
+        let (grid, digraphs) = if chars.contains('(') {
+            let (grid, digraphs) =
+                try_make_grid_with_digraphs(chars).ok_or("Should be able to make grid")?;
+            (grid, Some(digraphs))
+        } else {
+            let grid = crate::prelude::try_make_grid(chars).ok_or("Should be able to make grid")?;
+            (grid, None)
+        };
 
         let mut words: Vec<FinderSingleWord> = iter
             .map(|x| FinderSingleWord::from_str(x.trim()))
@@ -89,16 +120,18 @@ impl FromStr for GridResult {
         // .sorted_by_cached_key(|x| x.text.to_ascii_lowercase())
         // .collect();
 
-        let mut letters = LetterCounts::default();
-        for c in grid.iter() {
-            letters = letters.try_insert(*c).ok_or("Prime bag is too big")?;
-        }
-
-        Ok(Self {
+        let mut result = Self {
             grid,
-            letters,
+            digraphs,
+            letters: LetterCounts::default(),
             words,
-        })
+        };
+        result.letters = LetterCounts::try_from_iter(
+            Tile::iter_by_row().flat_map(|tile| result.tile_characters(tile)),
+        )
+        .ok_or("Prime bag is too big")?;
+
+        Ok(result)
     }
 }
 
@@ -110,7 +143,10 @@ impl std::fmt::Display for GridResult {
             .sorted()
             .map(|x| format!("{:8}", x.text))
             .join("\t");
-        let solution = self.grid.iter().join("");
+        let solution = match &self.digraphs {
+            Some(digraphs) => grid_text_with_digraphs(&self.grid, digraphs),
+            None => self.grid.iter().join(""),
+        };
         let size = self.words.len();
 
         write!(f, "{solution}\t{size}\t{words_text}")
@@ -121,11 +157,12 @@ pub fn try_make_grid_with_blank_filling<Collector: SolutionCollector<GridResult>
     letters: LetterCounts,
     words: &[FinderSingleWord],
     exclude_words: &[FinderSingleWord],
+    digraphs: &FinderDigraphs,
     first_blank_replacement: Character,
     counter: &mut impl Counter,
     collector: &mut Collector,
 ) {
-    try_make_grid(letters, words, exclude_words, counter, collector);
+    try_make_grid(letters, words, exclude_words, digraphs, counter, collector);
 
     if collector.is_full() {
         return;
@@ -157,6 +194,7 @@ pub fn try_make_grid_with_blank_filling<Collector: SolutionCollector<GridResult>
                 new_letters,
                 words,
                 exclude_words,
+                digraphs,
                 replacement,
                 counter,
                 collector,
@@ -251,10 +289,14 @@ enum WordLetterResult {
 
 pub(crate) type NodeBuilders = geometrid::tile_map::TileMap<NodeBuilder, 16, 1, 16>;
 
+/// Try to make grids containing the words.
+/// The words should have their digraphs replaced by the stand-ins in `digraphs`;
+/// the resulting grids have digraph tiles and the original words
 pub fn try_make_grid<Collector: SolutionCollector<GridResult>>(
     letters: LetterCounts,
     words: &[FinderSingleWord],
     exclude_words: &[FinderSingleWord],
+    digraphs: &FinderDigraphs,
     counter: &mut impl Counter,
     collector: &mut Collector,
 ) {
@@ -441,12 +483,22 @@ pub fn try_make_grid<Collector: SolutionCollector<GridResult>>(
     //println!("Grid solved in {}micros", now.elapsed().as_micros());
 
     collector.collect_mapped(mapped_collector, |solution| {
-        let solution_grid = solution.to_grid(&nodes);
-        GridResult {
-            grid: solution_grid,
+        let (grid, grid_digraphs) = solution.to_grid_with_digraphs(&nodes, digraphs);
+        let mut result = GridResult {
+            grid,
+            digraphs: grid_digraphs,
             letters,
-            words: Vec::from(words),
+            words: words
+                .iter()
+                .map(|word| digraphs.restore_word(word))
+                .collect(),
+        };
+        if let Some(letters) = LetterCounts::try_from_iter(
+            Tile::iter_by_row().flat_map(|tile| result.tile_characters(tile)),
+        ) {
+            result.letters = letters;
         }
+        result
     })
 }
 
@@ -725,6 +777,7 @@ mod tests {
             letters,
             &words,
             &exclude_words,
+            &FinderDigraphs::default(),
             Character::E,
             &mut counter,
             &mut solution,
@@ -785,6 +838,7 @@ mod tests {
             letters,
             &words,
             &exclude_words,
+            &FinderDigraphs::default(),
             Character::E,
             &mut counter,
             &mut solutions,
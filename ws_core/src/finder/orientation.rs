@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 
 use crate::{
-    character,
+    dictionary::{Dictionary, WordAutomata},
     finder::{helpers::FinderSingleWord, node::GridResult},
     prelude::*,
 };
@@ -9,38 +9,33 @@ use itertools::Itertools;
 use lazy_static::lazy_static;
 
 lazy_static! {
-    static ref TABOO_WORDS: HashSet<CharsArray> = {
-        let text = include_str!("taboo.txt");
-
-        let words: HashSet<CharsArray> = text
-            .lines()
-            .filter_map(|l| character::normalize_characters_array(l).ok())
-            .collect();
-        words
-    };
-    static ref TABOO_PREFIXES: HashSet<CharsArray> = {
-        let mut set: HashSet<CharsArray> = Default::default();
-
-        for word in TABOO_WORDS.iter() {
-            for len in 1..=word.len() {
-                let mut clone = word.clone();
-                clone.truncate(len);
-                set.insert(clone);
-            }
-        }
-        set
-    };
+    static ref TABOO_WORDS: WordAutomata =
+        WordAutomata::from_word_list(include_str!("taboo.txt"), 0);
 }
 
-pub fn find_taboo_word(grid: &Grid) -> Option<CharsArray> {
-    fn find_taboo_inner(
-        grid: &Grid,
-        prefix: &mut CharsArray,
+pub fn find_taboo_word(grid: &Grid, digraphs: Option<&DigraphMap>) -> Option<CharsArray> {
+    find_dictionary_word(grid, digraphs, &*TABOO_WORDS)
+}
+
+/// Find a word from the dictionary reading across, down or diagonally down and right.
+/// Words may wrap from the end of one row to the start of the next.
+/// Digraph tiles are read as both of their letters
+pub fn find_dictionary_word(
+    grid: &Grid,
+    digraphs: Option<&DigraphMap>,
+    dictionary: &impl Dictionary,
+) -> Option<CharsArray> {
+    type Prefix = ArrayVec<Character, { GRID_SIZE * 2 }>;
+
+    fn find_inner(
+        read_tile: &impl Fn(Tile) -> ArrayVec<Character, 2>,
+        dictionary: &impl Dictionary,
+        prefix: &mut Prefix,
         last_tile: Tile,
         mut allow_wrap: bool,
     ) -> Option<CharsArray> {
-        if TABOO_WORDS.contains(prefix) {
-            return Some(prefix.clone());
+        if dictionary.contains_characters(prefix) {
+            return CharsArray::try_from(prefix.as_slice()).ok();
         }
 
         let next_tiles = [
@@ -54,23 +49,33 @@ pub fn find_taboo_word(grid: &Grid) -> Option<CharsArray> {
         ];
 
         for next_tile in next_tiles.into_iter().flatten() {
-            let c = grid[next_tile];
-            prefix.push(c);
-            if let Some(answer) = find_taboo_inner(grid, prefix, next_tile, allow_wrap) {
-                return Some(answer);
+            let len = prefix.len();
+            prefix.extend(read_tile(next_tile));
+            if dictionary.contains_prefix(prefix) {
+                if let Some(answer) =
+                    find_inner(read_tile, dictionary, prefix, next_tile, allow_wrap)
+                {
+                    return Some(answer);
+                }
             }
-            prefix.pop();
+            prefix.truncate(len);
             allow_wrap = false; //basically only allow wrap
         }
 
         None
     }
 
+    let read_tile = |tile: Tile| match digraphs {
+        Some(digraphs) => tile_characters(grid, digraphs, tile),
+        None => ArrayVec::from_iter([grid[tile]]),
+    };
+
     for tile in Tile::iter_by_row() {
-        let mut prefix = CharsArray::new();
-        prefix.push(grid[tile]);
-        if TABOO_PREFIXES.contains(&prefix) {
-            if let Some(answer) = find_taboo_inner(grid, &mut prefix, tile, tile.x() <= 1) {
+        let mut prefix = Prefix::from_iter(read_tile(tile));
+        if dictionary.contains_prefix(&prefix) {
+            if let Some(answer) =
+                find_inner(&read_tile, dictionary, &mut prefix, tile, tile.x() <= 1)
+            {
                 return Some(answer);
             }
         }
@@ -91,19 +96,27 @@ pub fn try_optimize_orientation(grid_result: &mut GridResult) -> Result<bool, St
 
     let transforms = flips.into_iter().cartesian_product(rotations);
 
-    if let Some(new_grid) = transforms
+    if let Some((new_grid, new_digraphs)) = transforms
         .map(|(axes, quarter_turns)| {
             let mut new_grid = grid_result.grid;
             new_grid.rotate(quarter_turns);
             new_grid.flip(axes);
-            new_grid
+            let new_digraphs = grid_result.digraphs.map(|mut digraphs| {
+                digraphs.rotate(quarter_turns);
+                digraphs.flip(axes);
+                digraphs
+            });
+            (new_grid, new_digraphs)
+        })
+        .filter(|(grid, digraphs)| find_taboo_word(grid, digraphs.as_ref()).is_none())
+        .max_by_key(|(new_grid, new_digraphs)| {
+            calculate_max_score(new_grid, new_digraphs.as_ref(), &grid_result.words)
         })
-        .filter(|grid| find_taboo_word(grid).is_none())
-        .max_by_key(|new_grid| calculate_max_score(new_grid, &grid_result.words))
     {
-        if grid_result.grid != new_grid {
+        if grid_result.grid != new_grid || grid_result.digraphs != new_digraphs {
             //log::info!("Changed \n{}\n to \n{new_grid}", grid_result.grid);
             grid_result.grid = new_grid;
+            grid_result.digraphs = new_digraphs;
             Ok(true)
         } else {
             Ok(false)
@@ -115,7 +128,12 @@ pub fn try_optimize_orientation(grid_result: &mut GridResult) -> Result<bool, St
             let mut new_grid = grid_result.grid;
             new_grid.rotate(quarter_turns);
             new_grid.flip(axes);
-            if let Some(word) = find_taboo_word(&new_grid) {
+            let new_digraphs = grid_result.digraphs.map(|mut digraphs| {
+                digraphs.rotate(quarter_turns);
+                digraphs.flip(axes);
+                digraphs
+            });
+            if let Some(word) = find_taboo_word(&new_grid, new_digraphs.as_ref()) {
                 taboo_words.insert(word.iter().join(""));
             }
         }
@@ -132,7 +150,10 @@ pub fn calculate_best_word(grid_result: &GridResult) -> (FinderSingleWord, i32)
     grid_result
         .words
         .iter()
-        .map(|word| (word, calculate_score(word, &grid_result.grid)))
+        .map(|word| {
+            let score = calculate_score(word, &grid_result.grid, grid_result.digraphs.as_ref());
+            (word, score)
+        })
         .max_by_key(|x| x.1)
         .map(|x| (x.0.clone(), x.1))
         .unwrap()
@@ -151,18 +172,26 @@ pub fn find_single_row_word(grid_result: &GridResult) -> Option<FinderSingleWord
     None
 }
 
-fn calculate_max_score(grid: &Grid, words: &[FinderSingleWord]) -> i32 {
+fn calculate_max_score(
+    grid: &Grid,
+    digraphs: Option<&DigraphMap>,
+    words: &[FinderSingleWord],
+) -> i32 {
     //println!("{}", grid);
     //println!();
     words
         .iter()
-        .map(|word| calculate_score(word, grid))
+        .map(|word| calculate_score(word, grid, digraphs))
         .max()
         .unwrap_or_default()
 }
 
-fn calculate_score(word: &FinderSingleWord, grid: &Grid) -> i32 {
-    word.find_solutions(grid)
+fn calculate_score(word: &FinderSingleWord, grid: &Grid, digraphs: Option<&DigraphMap>) -> i32 {
+    let solutions = match digraphs {
+        Some(digraphs) => find_solutions_with_digraphs(&word.array, grid, digraphs, false),
+        None => word.find_solutions(grid),
+    };
+    solutions
         .into_iter()
         .map(|x| score_solution(&x))
         .max()
@@ -277,7 +306,7 @@ pub mod tests {
             Some(crate::character::normalize_characters_array(expected).unwrap())
         };
 
-        let actual = find_taboo_word(&grid);
+        let actual = find_taboo_word(&grid, None);
 
         assert_eq!(actual, expected);
     }
@@ -1,10 +1,10 @@
 use crate::finder::node::*;
 use crate::{finder::*, WordTrait};
-use crate::{Character, Grid, GridSet};
+use crate::{Character, DigraphMap, Grid, GridSet};
 use arrayvec::ArrayVec;
 
 use super::counter::{Counter, SolutionCollector};
-use super::helpers::FinderSingleWord;
+use super::helpers::{FinderDigraphs, FinderSingleWord};
 
 pub type NodeMap = geometrid::tile_map::TileMap<Node, 16, 1, 16>;
 
@@ -36,6 +36,15 @@ impl PartialGrid {
         grid
     }
 
+    /// The grid with digraph tiles placed where the finder put their stand-ins
+    pub fn to_grid_with_digraphs(
+        &self,
+        nodes: &NodeMap,
+        digraphs: &FinderDigraphs,
+    ) -> (Grid, Option<DigraphMap>) {
+        digraphs.restore_grid(&self.to_grid(nodes))
+    }
+
     pub fn check_matches(
         &self,
         nodes: &NodeMap,
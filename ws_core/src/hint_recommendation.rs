@@ -0,0 +1,111 @@
+use itertools::Itertools;
+
+use crate::prelude::*;
+
+/// How many difficulty points each falling tile is worth
+const TILES_FREED_WEIGHT: f32 = 1.0;
+
+/// A word which has not been found yet, with how useful a hint for it would be
+#[derive(Debug, Clone, PartialEq)]
+pub struct HintRecommendation {
+    pub word_index: usize,
+    /// The word's difficulty score, see `WordDifficulty`
+    pub difficulty: f32,
+    /// The number of tiles which would fall if this word were found
+    pub tiles_freed: usize,
+    /// Lower scores make better hints
+    pub score: f32,
+}
+
+/// Rank the words which have not been found yet, best hint first.
+/// Easy words and words which would make letters fall are preferred
+pub fn recommend_hints(
+    level: &DesignedLevel,
+    unneeded_tiles: GridSet,
+    found_words: FoundWordsMask,
+) -> Vec<HintRecommendation> {
+    let difficulty = level.difficulty();
+    let unneeded_count = unneeded_tiles.count() as usize;
+
+    difficulty
+        .words
+        .iter()
+        .filter(|word| !is_word_found(found_words, word.word_index))
+        .map(|word| {
+            let after = level.unneeded_tiles_after_finding(
+                unneeded_tiles,
+                found_words | found_word_bit(word.word_index),
+            );
+            let tiles_freed = (after.count() as usize).saturating_sub(unneeded_count);
+
+            HintRecommendation {
+                word_index: word.word_index,
+                difficulty: word.score,
+                tiles_freed,
+                score: word.score - (tiles_freed as f32 * TILES_FREED_WEIGHT),
+            }
+        })
+        .sorted_by(|a, b| {
+            a.score
+                .total_cmp(&b.score)
+                .then(a.word_index.cmp(&b.word_index))
+        })
+        .collect_vec()
+}
+
+/// The index of the best word to hint, if any words have not been found
+pub fn recommend_hint(
+    level: &DesignedLevel,
+    unneeded_tiles: GridSet,
+    found_words: FoundWordsMask,
+) -> Option<usize> {
+    recommend_hints(level, unneeded_tiles, found_words)
+        .first()
+        .map(|x| x.word_index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /* spellchecker:disable */
+    const SPORTS: &str = "ASHPKILOEUIOGNDT\tSports\tPOLO\tSHOOTING\tKENDO\tSAILING\tLUGE\tSKIING";
+
+    #[test]
+    pub fn test_found_words_are_not_recommended() {
+        let level = DesignedLevel::from_tsv_line(SPORTS).unwrap();
+        let word_count = level.words.len();
+
+        let mut found_words: FoundWordsMask = 0;
+        let mut unneeded_tiles = GridSet::EMPTY;
+
+        for remaining in (1..=word_count).rev() {
+            let recommendations = recommend_hints(&level, unneeded_tiles, found_words);
+            assert_eq!(recommendations.len(), remaining);
+            assert!(recommendations
+                .iter()
+                .tuple_windows()
+                .all(|(a, b)| a.score <= b.score));
+
+            let best = recommendations[0].word_index;
+            assert!(!is_word_found(found_words, best));
+
+            found_words |= 1 << best;
+            unneeded_tiles = level.unneeded_tiles_after_finding(unneeded_tiles, found_words);
+        }
+
+        assert_eq!(recommend_hint(&level, unneeded_tiles, found_words), None);
+    }
+
+    #[test]
+    pub fn test_tiles_freed() {
+        let level = DesignedLevel::from_tsv_line(SPORTS).unwrap();
+
+        for recommendation in recommend_hints(&level, GridSet::EMPTY, 0) {
+            let expected = level
+                .calculate_unneeded_tiles(GridSet::EMPTY, |i| i == recommendation.word_index)
+                .count() as usize;
+            assert_eq!(recommendation.tiles_freed, expected);
+        }
+    }
+}
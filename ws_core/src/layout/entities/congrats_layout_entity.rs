@@ -10,6 +10,7 @@ pub enum CongratsStatistic {
     Left = 0,
     Middle = 1,
     Right = 2,
+    Bonus = 3,
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -176,9 +177,13 @@ impl LayoutStructure for CongratsLayoutEntity {
 
     fn iter_all(context: &Self::Context<'_>) -> impl Iterator<Item = Self> {
         let button_count = Self::get_button_count(context);
-        let take = 3 + button_count;
+        let take = CongratsStatistic::COUNT + button_count;
 
-        let stat_count = if context.1.is_tutorial() { 0 } else { 3 };
+        let stat_count = if context.1.is_tutorial() {
+            0
+        } else {
+            CongratsStatistic::COUNT
+        };
 
         CongratsStatistic::iter()
             .map(Self::Statistic)
@@ -0,0 +1,336 @@
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::prelude::*;
+
+/// A level in a structured (yaml) level file.
+/// Unlike the tsv format this has room for metadata
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LevelFileEntry {
+    pub grid: String,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extra_info: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub difficulty: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub colors: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub hide_word_lengths: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alphabet: Option<Alphabet>,
+    pub words: Vec<LevelFileWord>,
+}
+
+/// A word in a structured level file - either just the text or the text with a note
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum LevelFileWord {
+    Text(String),
+    WithNote { text: String, note: String },
+}
+
+impl LevelFileWord {
+    pub fn text(&self) -> &str {
+        match self {
+            LevelFileWord::Text(text) => text,
+            LevelFileWord::WithNote { text, .. } => text,
+        }
+    }
+}
+
+impl From<&DesignedLevel> for LevelFileEntry {
+    fn from(level: &DesignedLevel) -> Self {
+        let LevelMetadata {
+            author,
+            difficulty,
+            source,
+            date,
+            word_notes,
+            hide_word_lengths,
+            alphabet,
+        } = &level.metadata;
+
+        let words = level
+            .words
+            .iter()
+            .map(|word| match word_notes.get(word.text.as_str()) {
+                Some(note) => LevelFileWord::WithNote {
+                    text: word.text.to_string(),
+                    note: note.clone(),
+                },
+                None => LevelFileWord::Text(word.text.to_string()),
+            })
+            .collect();
+
+        Self {
+            grid: level.grid_text(),
+            name: level.name.to_string(),
+            extra_info: level.extra_info.map(|x| x.to_string()),
+            author: author.map(|x| x.to_string()),
+            difficulty: *difficulty,
+            source: source.map(|x| x.to_string()),
+            date: *date,
+            colors: level
+                .special_colors
+                .as_ref()
+                .map(|colors| colors.iter().map(|x| x.to_hex()).collect()),
+            hide_word_lengths: *hide_word_lengths,
+            alphabet: Some(alphabet.clone()).filter(|x| *x != Alphabet::default()),
+            words,
+        }
+    }
+}
+
+impl TryFrom<&LevelFileEntry> for DesignedLevel {
+    type Error = LevelParseError;
+
+    fn try_from(entry: &LevelFileEntry) -> Result<Self, Self::Error> {
+        let name = &entry.name;
+        let alphabet = entry.alphabet.clone().unwrap_or_default();
+        let (grid, digraphs) = DesignedLevel::parse_grid(&entry.grid)
+            .map_err(|error| LevelParseError::Grid { error })?;
+
+        let mut words: Vec<DisplayWord> = entry
+            .words
+            .iter()
+            .enumerate()
+            .map(|(word_index, x)| {
+                DisplayWord::from_str_with_alphabet(x.text().trim(), &alphabet).map_err(|error| {
+                    LevelParseError::Word {
+                        column: None,
+                        word_index,
+                        text: x.text().to_string(),
+                        error,
+                    }
+                })
+            })
+            .try_collect()?;
+        if words.len() > MAX_LEVEL_WORDS {
+            return Err(LevelParseError::TooManyWords);
+        }
+        words.sort();
+
+        let special_colors = match &entry.colors {
+            Some(colors) => Some(
+                colors
+                    .iter()
+                    .map(|c| {
+                        BasicColor::try_from_str(c).map_err(|error| LevelParseError::Color {
+                            column: None,
+                            text: c.to_string(),
+                            error,
+                        })
+                    })
+                    .try_collect()?,
+            ),
+            None => None,
+        };
+
+        let word_notes = entry
+            .words
+            .iter()
+            .filter_map(|x| match x {
+                LevelFileWord::Text(_) => None,
+                LevelFileWord::WithNote { text, note } => {
+                    Some((text.trim().to_string(), note.clone()))
+                }
+            })
+            .collect();
+
+        Ok(DesignedLevel {
+            name: Ustr::from(name),
+            numbering: None,
+            extra_info: entry.extra_info.as_deref().map(Ustr::from),
+            grid,
+            digraphs,
+            words,
+            special_colors,
+            metadata: LevelMetadata {
+                author: entry.author.as_deref().map(Ustr::from),
+                difficulty: entry.difficulty,
+                source: entry.source.as_deref().map(Ustr::from),
+                date: entry.date,
+                word_notes,
+                hide_word_lengths: entry.hide_word_lengths,
+                alphabet,
+            },
+            unneeded_tiles_table: Default::default(),
+            difficulty_cache: Default::default(),
+        })
+    }
+}
+
+impl DesignedLevel {
+    /// Read levels from a structured (yaml) level file
+    pub fn from_yaml(text: &str) -> Result<Vec<Self>, LevelParseError> {
+        let entries: Vec<LevelFileEntry> =
+            serde_yaml::from_str(text).map_err(|e| LevelParseError::File {
+                message: e.to_string(),
+            })?;
+
+        entries
+            .iter()
+            .map(|entry| {
+                DesignedLevel::try_from(entry).map_err(|error| LevelParseError::InLevel {
+                    name: entry.name.clone(),
+                    error: Box::new(error),
+                })
+            })
+            .collect()
+    }
+
+    /// Write levels to a structured (yaml) level file
+    pub fn to_yaml(levels: &[Self]) -> Result<String, serde_yaml::Error> {
+        let entries = levels.iter().map(LevelFileEntry::from).collect_vec();
+
+        serde_yaml::to_string(&entries)
+    }
+
+    /// Read levels from either a tsv or a structured (yaml) level file.
+    /// Tsv files are recognised by their first line containing a tab
+    pub fn from_level_file(text: &str) -> Result<Vec<Self>, LevelParseError> {
+        let is_tsv = text
+            .lines()
+            .find(|x| !x.trim().is_empty())
+            .map(|x| x.contains('\t'))
+            .unwrap_or(true);
+
+        if is_tsv {
+            text.lines()
+                .filter(|x| !x.trim().is_empty())
+                .map(DesignedLevel::from_tsv_line)
+                .collect()
+        } else {
+            Self::from_yaml(text)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /* spellchecker:disable */
+    const TSV: &str = "KGRROEAYDNLAUBIW\tCounties of Ireland[for St. Patrick's Day]{#009a49,#009e60,#4cbb17,#50c878}\tDerry\tDonegal\tDublin\tGalway\tKerry";
+
+    const YAML: &str = r#"
+- grid: KGRROEAYDNLAUBIW
+  name: Counties of Ireland
+  extra_info: for St. Patrick's Day
+  author: mark
+  difficulty: 2
+  source: Wikipedia
+  date: 2024-03-17
+  colors:
+  - '#009a49'
+  - '#009e60'
+  - '#4cbb17'
+  - '#50c878'
+  hide_word_lengths: true
+  words:
+  - Derry
+  - text: Donegal
+    note: The most northerly county
+  - Dublin
+  - Galway
+  - Kerry
+"#;
+
+    #[test]
+    pub fn test_tsv_round_trip() {
+        let level = DesignedLevel::from_tsv_line(TSV).unwrap();
+
+        assert_eq!(level.to_tsv_line(), TSV);
+
+        let yaml = DesignedLevel::to_yaml(&[level.clone()]).unwrap();
+        let from_yaml = DesignedLevel::from_yaml(&yaml).unwrap();
+
+        assert_eq!(from_yaml, vec![level]);
+    }
+
+    #[test]
+    pub fn test_read_yaml() {
+        let levels = DesignedLevel::from_level_file(YAML).unwrap();
+
+        assert_eq!(levels.len(), 1);
+        let level = &levels[0];
+
+        assert_eq!(level.to_tsv_line(), TSV);
+        assert_eq!(level.metadata.author, Some(Ustr::from("mark")));
+        assert_eq!(level.metadata.difficulty, Some(2));
+        assert!(level.metadata.hide_word_lengths);
+        assert_eq!(
+            level.metadata.word_notes.get("Donegal").map(|x| x.as_str()),
+            Some("The most northerly county")
+        );
+
+        let yaml = DesignedLevel::to_yaml(&levels).unwrap();
+
+        assert_eq!(DesignedLevel::from_level_file(&yaml).unwrap(), levels);
+    }
+
+    #[test]
+    pub fn test_read_tsv_file() {
+        let levels = DesignedLevel::from_level_file(TSV).unwrap();
+
+        assert_eq!(levels, vec![DesignedLevel::from_tsv_line(TSV).unwrap()]);
+    }
+
+    #[test]
+    pub fn test_invalid_word() {
+        let yaml = r#"
+- grid: ABCDEFGHIJKLMNOP
+  name: Letters
+  words:
+  - Abcd
+  - Zeb
+"#;
+
+        assert_eq!(
+            DesignedLevel::from_yaml(yaml),
+            Err(LevelParseError::InLevel {
+                name: "Letters".to_string(),
+                error: Box::new(LevelParseError::Word {
+                    column: None,
+                    word_index: 1,
+                    text: "Zeb".to_string(),
+                    error: WordParseError::TooShort,
+                }),
+            })
+        );
+    }
+
+    #[test]
+    pub fn test_read_yaml_with_alphabet() {
+        let yaml = r#"
+- grid: МОСКЙЛАВРНТИЕДУП
+  name: Города
+  alphabet: Russian
+  words:
+  - Москва
+  - Мой
+"#;
+        let levels = DesignedLevel::from_yaml(yaml).unwrap();
+        let level = &levels[0];
+
+        assert_eq!(
+            level.metadata.alphabet.preset(),
+            Some(AlphabetPreset::Russian)
+        );
+        assert!(level
+            .words
+            .iter()
+            .all(|w| w.find_solution(&level.grid).is_some()));
+        assert!(DesignedLevel::to_yaml(&levels)
+            .unwrap()
+            .contains("alphabet: Russian"));
+    }
+}
@@ -0,0 +1,160 @@
+use crate::MAX_LEVEL_WORDS;
+
+/// An error from parsing a level
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LevelParseError {
+    /// The level has no grid
+    MissingGrid,
+    /// The level has no name
+    MissingName,
+    /// The grid could not be parsed
+    Grid { error: GridParseError },
+    /// A word could not be parsed
+    Word {
+        /// The character column in the line where the word starts, for tsv lines
+        column: Option<usize>,
+        /// The index of the word within the level
+        word_index: usize,
+        text: String,
+        error: WordParseError,
+    },
+    /// The level has more words than can be tracked
+    TooManyWords,
+    /// A special color could not be parsed
+    Color {
+        /// The character column in the line where the color starts, for tsv lines
+        column: Option<usize>,
+        text: String,
+        error: ColorParseError,
+    },
+    /// A level number could not be parsed
+    Numbering { text: String },
+    /// A level file could not be read as yaml
+    File { message: String },
+    /// A level in a level file could not be parsed
+    InLevel {
+        name: String,
+        error: Box<LevelParseError>,
+    },
+}
+
+impl std::fmt::Display for LevelParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LevelParseError::MissingGrid => write!(f, "Level should have a grid"),
+            LevelParseError::MissingName => write!(f, "Level should have a name"),
+            LevelParseError::Grid { error } => write!(f, "Could not make grid: {error}"),
+            LevelParseError::Word {
+                column,
+                word_index,
+                text,
+                error,
+            } => {
+                write!(f, "Word {word_index} '{text}'")?;
+                if let Some(column) = column {
+                    write!(f, " (column {column})")?;
+                }
+                write!(f, " is not valid: {error}")
+            }
+            LevelParseError::TooManyWords => {
+                write!(f, "Level should have at most {MAX_LEVEL_WORDS} words")
+            }
+            LevelParseError::Color {
+                column,
+                text,
+                error,
+            } => {
+                write!(f, "Color '{text}'")?;
+                if let Some(column) = column {
+                    write!(f, " (column {column})")?;
+                }
+                write!(f, " is not valid: {error}")
+            }
+            LevelParseError::Numbering { text } => write!(f, "Could not parse numbering '{text}'"),
+            LevelParseError::File { message } => write!(f, "Could not read level file: {message}"),
+            LevelParseError::InLevel { name, error } => write!(f, "Level '{name}': {error}"),
+        }
+    }
+}
+
+impl std::error::Error for LevelParseError {}
+
+/// An error from parsing a grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GridParseError {
+    /// The character at this position is not allowed in a grid
+    InvalidCharacter { position: usize },
+    /// There are more characters than tiles
+    TooManyTiles,
+    /// A bracketed digraph is not valid
+    InvalidDigraph,
+}
+
+impl std::fmt::Display for GridParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridParseError::InvalidCharacter { position } => {
+                write!(f, "Invalid character at position {position}")
+            }
+            GridParseError::TooManyTiles => write!(f, "Grid has too many tiles"),
+            GridParseError::InvalidDigraph => write!(f, "Grid has an invalid digraph"),
+        }
+    }
+}
+
+impl std::error::Error for GridParseError {}
+
+/// An error from parsing a word
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WordParseError {
+    /// The grapheme at this position is not a valid character
+    InvalidCharacter {
+        position: usize,
+    },
+    TooLong,
+    TooShort,
+}
+
+impl WordParseError {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            WordParseError::InvalidCharacter { .. } => "Invalid character",
+            WordParseError::TooLong => "Word is too long",
+            WordParseError::TooShort => "Word has 3 or fewer characters",
+        }
+    }
+}
+
+impl std::fmt::Display for WordParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WordParseError::InvalidCharacter { position } => {
+                write!(f, "{} at position {position}", self.as_str())
+            }
+            _ => write!(f, "{}", self.as_str()),
+        }
+    }
+}
+
+impl std::error::Error for WordParseError {}
+
+/// An error from parsing a hex color
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorParseError {
+    /// Colors must have 3, 4, 6 or 8 hex digits
+    InvalidLength(usize),
+    InvalidHexDigit,
+}
+
+impl std::fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColorParseError::InvalidLength(len) => {
+                write!(f, "Color has {len} digits (expected 3, 4, 6 or 8)")
+            }
+            ColorParseError::InvalidHexDigit => write!(f, "Color has an invalid hex digit"),
+        }
+    }
+}
+
+impl std::error::Error for ColorParseError {}
@@ -1,5 +1,31 @@
 use super::word_trait::WordTrait;
-use crate::{finder::helpers::LetterCounts, Character, Grid, GridSet};
+use crate::{
+    finder::helpers::LetterCounts, is_word_found, ArrayVec, Character, CharsArray, FoundWordsMask,
+    Grid, GridSet, Solution, Tile, UnneededTilesTable,
+};
+
+/// The parts of a level which its caches depend on.
+/// A cached result is only used while these are unchanged
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct LevelContents {
+    tiles: Vec<ArrayVec<Character, 2>>,
+    words: Vec<CharsArray>,
+}
+
+impl LevelContents {
+    pub(crate) fn of<L: LevelTrait>(level: &L) -> Self {
+        Self {
+            tiles: Tile::iter_by_row()
+                .map(|tile| level.tile_characters(tile))
+                .collect(),
+            words: level
+                .words()
+                .iter()
+                .map(|x| x.characters().clone())
+                .collect(),
+        }
+    }
+}
 
 pub trait LevelTrait {
     type Word: WordTrait;
@@ -7,6 +33,52 @@ pub trait LevelTrait {
 
     fn words(&self) -> &[Self::Word];
 
+    /// The characters on a tile. Levels with digraphs have two characters on some tiles
+    fn tile_characters(&self, tile: Tile) -> ArrayVec<Character, 2> {
+        ArrayVec::from_iter([self.grid()[tile]])
+    }
+
+    /// Find a path for a word which does not use any of the unneeded tiles
+    fn find_word_path(&self, word: &Self::Word, unneeded_tiles: GridSet) -> Option<Solution> {
+        word.find_solution_with_tiles(&self.grid(), unneeded_tiles)
+    }
+
+    /// Memoised unneeded tiles, if this level keeps them
+    fn unneeded_tiles_table(&self) -> Option<&UnneededTilesTable> {
+        None
+    }
+
+    /// Calculate unneeded tiles after finding a word, using the memoised table if there is one.
+    /// This gives the same result as `calculate_unneeded_tiles`
+    fn unneeded_tiles_after_finding(
+        &self,
+        unneeded_tiles: GridSet,
+        found_words: FoundWordsMask,
+    ) -> GridSet
+    where
+        Self: Sized,
+    {
+        match self.unneeded_tiles_table() {
+            Some(table) => table.get_or_calculate(self, unneeded_tiles, found_words),
+            None => self.calculate_unneeded_tiles(unneeded_tiles, |index| {
+                is_word_found(found_words, index)
+            }),
+        }
+    }
+
+    /// The unneeded tiles once a set of words have been found in index order
+    fn unneeded_tiles_for_found_words(&self, found_words: FoundWordsMask) -> GridSet
+    where
+        Self: Sized,
+    {
+        if found_words == 0 {
+            return GridSet::EMPTY;
+        }
+        let last_word = FoundWordsMask::BITS - 1 - found_words.leading_zeros();
+        let previous = self.unneeded_tiles_for_found_words(found_words & !(1 << last_word));
+        self.unneeded_tiles_after_finding(previous, found_words)
+    }
+
     fn calculate_unneeded_tiles<F: Fn(usize) -> bool>(
         &self,
         mut unneeded_tiles: GridSet,
@@ -39,9 +111,8 @@ pub trait LevelTrait {
         let remaining_characters = grid
             .enumerate()
             .filter(|(tile, _)| !unneeded_tiles.get_bit(tile))
-            .map(|x| x.1)
-            .filter(|x| !x.is_blank())
-            .cloned();
+            .flat_map(|(tile, _)| self.tile_characters(tile))
+            .filter(|x| !x.is_blank());
         let Some(remaining_characters) = LetterCounts::try_from_iter(remaining_characters) else {
             //warn!("Could not get letter counts of remaining tiles");
             return unneeded_tiles;
@@ -58,20 +129,22 @@ pub trait LevelTrait {
             .iter_groups()
             .map(|x| (x.0, x.1.get()))
         {
-            let character_tiles = grid.enumerate().filter(|x| x.1 == &character).map(|x| x.0);
-            if needed_characters.contains(character) {
-                //we have additional copies of this character - try removing them
-                'tiles_to_check: for tile in character_tiles {
-                    if unneeded_tiles.get_bit(&tile) {
-                        //we've already excluded this tile
-                        continue 'tiles_to_check;
-                    }
+            let is_needed = needed_characters.contains(character);
+            let character_tiles = grid
+                .enumerate()
+                .map(|x| x.0)
+                .filter(|tile| self.tile_characters(*tile).contains(&character));
 
-                    let mut remaining_grid = self.grid();
-                    for t in unneeded_tiles.iter_true_tiles() {
-                        remaining_grid[t] = Character::Blank;
-                    }
-                    remaining_grid[tile] = Character::Blank;
+            'tiles_to_check: for tile in character_tiles {
+                if unneeded_tiles.get_bit(&tile) {
+                    //we've already excluded this tile
+                    continue 'tiles_to_check;
+                }
+
+                //a digraph tile may still hold a needed character, so it must be checked
+                if is_needed || self.tile_characters(tile).len() > 1 {
+                    //we have additional copies of this character - try removing them
+                    let remaining_tiles = unneeded_tiles.with_bit_set(&tile, true);
 
                     for word in self
                         .words()
@@ -80,22 +153,20 @@ pub trait LevelTrait {
                         .filter(|x| !is_word_found(x.0))
                         .map(|x| x.1)
                     {
-                        if word.find_solution(&remaining_grid).is_none() {
+                        if self.find_word_path(word, remaining_tiles).is_none() {
                             continue 'tiles_to_check;
                         }
                     }
-                    //this tile is not needed for any solutions
-                    unneeded_tiles.set_bit(&tile, true);
+                }
+
+                //this tile is not needed for any solutions
+                unneeded_tiles.set_bit(&tile, true);
+                if is_needed {
                     remaining_copies -= 1;
                     if remaining_copies == 0 {
                         continue 'character_groups;
                     }
                 }
-            } else {
-                //remove this character completely
-                for tile in character_tiles {
-                    unneeded_tiles.set_bit(&tile, true);
-                }
             }
         }
 
@@ -6,5 +6,6 @@ pub enum LevelType {
     Fixed,
     DailyChallenge,
     Custom,
+    Endless,
     NonLevel,
 }
@@ -1,29 +1,54 @@
+pub mod alphabet;
+pub mod ambiguity;
 pub mod background_type;
+pub mod challenge;
 pub mod character;
 pub mod colors;
 pub mod complete_solve;
 pub mod designed_level;
+pub mod dictionary;
+pub mod difficulty;
+pub mod digraph;
 pub mod display_word;
 pub mod finder;
 pub mod font_icons;
+pub mod hint_recommendation;
 pub mod insets;
 pub mod layout;
+pub mod level_file;
+pub mod level_parse_error;
 pub mod level_trait;
 pub mod level_type;
+pub mod lint;
+pub mod path_search;
+pub mod share_code;
+pub mod unneeded_tiles_table;
 pub mod word;
 pub mod word_trait;
 pub use crate::prelude::*;
 
 pub mod prelude {
 
+    pub use crate::alphabet::*;
+    pub use crate::ambiguity::*;
     pub use crate::background_type::*;
+    pub use crate::challenge::*;
     pub use crate::character::*;
     pub use crate::colors::*;
     pub use crate::designed_level::*;
+    pub use crate::difficulty::*;
+    pub use crate::digraph::*;
     pub use crate::display_word::*;
     pub use crate::font_icons::*;
+    pub use crate::hint_recommendation::*;
     pub use crate::insets::*;
+    pub use crate::level_file::*;
+    pub use crate::level_parse_error::*;
     pub use crate::level_trait::*;
+    pub use crate::lint::*;
+    pub use crate::path_search::*;
+    pub use crate::share_code::*;
+    pub use crate::unneeded_tiles_table::*;
     pub use crate::word::*;
     pub use crate::word_trait::*;
 
@@ -35,20 +60,45 @@ pub mod prelude {
 
     pub use crate::layout::prelude::*;
 
-    pub type Tile = geometrid::tile::Tile<4, 4>;
+    /// The width of the default grid
+    pub const GRID_WIDTH: u8 = 4;
+    /// The height of the default grid
+    pub const GRID_HEIGHT: u8 = 4;
+    /// The number of tiles in the default grid
+    pub const GRID_SIZE: usize = (GRID_WIDTH as usize) * (GRID_HEIGHT as usize);
 
-    pub type CharsArray = ArrayVec<Character, 16>;
-    pub type Grid = geometrid::tile_map::TileMap<Character, 4, 4, 16>;
-    pub type GridSet = geometrid::tile_set::TileSet16<4, 4, 16>;
-    pub type Solution = ArrayVec<Tile, 16>;
+    pub type Tile = geometrid::tile::Tile<GRID_WIDTH, GRID_HEIGHT>;
+
+    pub type CharsArray = ArrayVec<Character, GRID_SIZE>;
+    pub type Grid = SizedGrid<GRID_WIDTH, GRID_HEIGHT, GRID_SIZE>;
+    pub type GridSet = geometrid::tile_set::TileSet16<GRID_WIDTH, GRID_HEIGHT, GRID_SIZE>;
+    pub type Solution = SizedSolution<GRID_WIDTH, GRID_HEIGHT, GRID_SIZE>;
+
+    /// A grid of any dimensions. `SIZE` must equal `W * H`.
+    ///
+    /// Sized grids are only used for path search, see `find_sized_solutions`.
+    /// Levels and everything built on them use the default grid.
+    pub type SizedGrid<const W: u8, const H: u8, const SIZE: usize> =
+        geometrid::tile_map::TileMap<Character, W, H, SIZE>;
+    /// A path through a grid of any dimensions
+    pub type SizedSolution<const W: u8, const H: u8, const SIZE: usize> =
+        ArrayVec<geometrid::tile::Tile<W, H>, SIZE>;
 
     pub fn try_make_grid(text: &str) -> Option<Grid> {
-        let mut arr = [Character::Blank; 16];
+        try_make_sized_grid(text)
+    }
+
+    /// Make a grid of any dimensions from its characters, row by row.
+    /// Missing characters at the end are filled with blanks.
+    pub fn try_make_sized_grid<const W: u8, const H: u8, const SIZE: usize>(
+        text: &str,
+    ) -> Option<SizedGrid<W, H, SIZE>> {
+        let mut arr = [Character::Blank; SIZE];
         for (index, char) in text.chars().enumerate() {
             let c = Character::try_from(char).ok()?;
             *arr.get_mut(index)? = c;
         }
 
-        Some(Grid::from_inner(arr))
+        Some(SizedGrid::from_inner(arr))
     }
 }
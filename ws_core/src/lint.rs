@@ -0,0 +1,391 @@
+use itertools::Itertools;
+
+use crate::{
+    finder::{helpers::FinderSingleWord, node::GridResult, orientation},
+    prelude::*,
+};
+
+/// How serious a lint diagnostic is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LintSeverity {
+    /// Worth knowing but the level is fine
+    Info,
+    /// The level is playable but should probably be changed
+    Warning,
+    /// The level should not be released
+    Error,
+}
+
+/// The kind of problem found by the linter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LintCode {
+    NoWords,
+    UnsolvableWord,
+    MultipleSolutions,
+    TabooWord,
+    UnusedTiles,
+    BlankTiles,
+    DuplicateWord,
+    WordOrdering,
+    BadOrientation,
+}
+
+impl LintCode {
+    pub const fn severity(&self) -> LintSeverity {
+        match self {
+            LintCode::NoWords
+            | LintCode::UnsolvableWord
+            | LintCode::TabooWord
+            | LintCode::DuplicateWord => LintSeverity::Error,
+            LintCode::UnusedTiles | LintCode::BlankTiles | LintCode::WordOrdering => {
+                LintSeverity::Warning
+            }
+            LintCode::MultipleSolutions | LintCode::BadOrientation => LintSeverity::Info,
+        }
+    }
+
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            LintCode::NoWords => "no-words",
+            LintCode::UnsolvableWord => "unsolvable-word",
+            LintCode::MultipleSolutions => "multiple-solutions",
+            LintCode::TabooWord => "taboo-word",
+            LintCode::UnusedTiles => "unused-tiles",
+            LintCode::BlankTiles => "blank-tiles",
+            LintCode::DuplicateWord => "duplicate-word",
+            LintCode::WordOrdering => "word-ordering",
+            LintCode::BadOrientation => "bad-orientation",
+        }
+    }
+}
+
+impl std::fmt::Display for LintCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A problem found in a level
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintDiagnostic {
+    pub code: LintCode,
+    pub severity: LintSeverity,
+    /// The index of the word this relates to, if any
+    pub word_index: Option<usize>,
+    /// The tiles this relates to, if any
+    pub tiles: GridSet,
+    pub message: String,
+}
+
+impl LintDiagnostic {
+    fn new(code: LintCode, message: String) -> Self {
+        Self {
+            code,
+            severity: code.severity(),
+            word_index: None,
+            tiles: GridSet::EMPTY,
+            message,
+        }
+    }
+
+    fn with_word_index(mut self, word_index: usize) -> Self {
+        self.word_index = Some(word_index);
+        self
+    }
+
+    fn with_tiles(mut self, tiles: GridSet) -> Self {
+        self.tiles = tiles;
+        self
+    }
+}
+
+impl std::fmt::Display for LintDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} [{}]: {}", self.severity, self.code, self.message)
+    }
+}
+
+/// Check a level for problems.
+/// Diagnostics are returned in the order the checks are run, not by severity
+pub fn lint_level(level: &DesignedLevel) -> Vec<LintDiagnostic> {
+    let mut diagnostics: Vec<LintDiagnostic> = vec![];
+
+    if level.words.is_empty() {
+        diagnostics.push(LintDiagnostic::new(
+            LintCode::NoWords,
+            format!("Level '{}' has no words", level.name),
+        ));
+    }
+
+    let mut used_tiles = GridSet::EMPTY;
+
+    for (word_index, word) in level.words.iter().enumerate() {
+        let solutions = level.find_all_word_solutions(word);
+
+        let mut tile_sets: Vec<GridSet> = vec![];
+        for solution in solutions.iter() {
+            let tiles = GridSet::from_iter(solution.iter().cloned());
+            if !tile_sets.contains(&tiles) {
+                tile_sets.push(tiles);
+            }
+        }
+
+        match tile_sets.as_slice() {
+            [] => diagnostics.push(
+                LintDiagnostic::new(
+                    LintCode::UnsolvableWord,
+                    format!("'{}' cannot be found in the grid", word.text),
+                )
+                .with_word_index(word_index),
+            ),
+            [tiles] => used_tiles = used_tiles.union(tiles),
+            _ => {
+                let all_tiles = tile_sets.iter().fold(GridSet::EMPTY, |a, b| a.union(b));
+                used_tiles = used_tiles.union(&all_tiles);
+                diagnostics.push(
+                    LintDiagnostic::new(
+                        LintCode::MultipleSolutions,
+                        format!(
+                            "'{}' can be made from {} different sets of tiles",
+                            word.text,
+                            tile_sets.len()
+                        ),
+                    )
+                    .with_word_index(word_index)
+                    .with_tiles(all_tiles),
+                );
+            }
+        }
+    }
+
+    let unused_tiles = GridSet::from_iter(
+        level
+            .grid
+            .enumerate()
+            .filter(|(tile, character)| !character.is_blank() && !used_tiles.get_bit(tile))
+            .map(|x| x.0),
+    );
+    if unused_tiles != GridSet::EMPTY {
+        diagnostics.push(
+            LintDiagnostic::new(
+                LintCode::UnusedTiles,
+                format!(
+                    "No word uses {}",
+                    unused_tiles
+                        .iter_true_tiles()
+                        .map(|tile| level.grid[tile].as_char())
+                        .join(", ")
+                ),
+            )
+            .with_tiles(unused_tiles),
+        );
+    }
+
+    let blank_tiles = GridSet::from_iter(
+        level
+            .grid
+            .enumerate()
+            .filter(|(_, character)| character.is_blank())
+            .map(|x| x.0),
+    );
+    if blank_tiles != GridSet::EMPTY {
+        diagnostics.push(
+            LintDiagnostic::new(
+                LintCode::BlankTiles,
+                format!("The grid has {} blank tiles", blank_tiles.count()),
+            )
+            .with_tiles(blank_tiles),
+        );
+    }
+
+    for (word_index, word) in level.words.iter().enumerate() {
+        if level.words[..word_index]
+            .iter()
+            .any(|other| other.characters == word.characters)
+        {
+            diagnostics.push(
+                LintDiagnostic::new(
+                    LintCode::DuplicateWord,
+                    format!("'{}' appears more than once", word.text),
+                )
+                .with_word_index(word_index),
+            );
+        }
+    }
+
+    for (word_index, (a, b)) in level.words.iter().tuple_windows().enumerate() {
+        if a > b {
+            diagnostics.push(
+                LintDiagnostic::new(
+                    LintCode::WordOrdering,
+                    format!("'{}' should come before '{}'", b.text, a.text),
+                )
+                .with_word_index(word_index + 1),
+            );
+        }
+    }
+
+    if let Some(taboo_word) = orientation::find_taboo_word(&level.grid, level.digraphs.as_ref()) {
+        diagnostics.push(LintDiagnostic::new(
+            LintCode::TabooWord,
+            format!("Grid contains taboo word '{}'", taboo_word.iter().join("")),
+        ));
+    } else if let Some(diagnostic) = check_orientation(level) {
+        diagnostics.push(diagnostic);
+    }
+
+    diagnostics
+}
+
+fn check_orientation(level: &DesignedLevel) -> Option<LintDiagnostic> {
+    let words = level.words.iter().map(FinderSingleWord::from).collect();
+
+    let mut grid_result = GridResult {
+        grid: level.grid,
+        digraphs: level.digraphs,
+        letters: level.letter_counts()?,
+        words,
+    };
+
+    match orientation::try_optimize_orientation(&mut grid_result) {
+        Ok(true) => Some(LintDiagnostic::new(
+            LintCode::BadOrientation,
+            format!(
+                "The grid would read better as {}",
+                match &grid_result.digraphs {
+                    Some(digraphs) => grid_text_with_digraphs(&grid_result.grid, digraphs),
+                    None => grid_result.grid.iter().join(""),
+                }
+            ),
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn codes(level: &DesignedLevel) -> Vec<LintCode> {
+        lint_level(level).into_iter().map(|x| x.code).collect()
+    }
+
+    /* spellchecker:disable */
+    const SPORTS: &str = "ASHPKILOEUIOGNDT\tSports\tKENDO\tLUGE\tPOLO\tSAILING\tSHOOTING\tSKIING";
+
+    #[test]
+    pub fn test_no_errors() {
+        let level = DesignedLevel::from_tsv_line(SPORTS).unwrap();
+
+        let errors = lint_level(&level)
+            .into_iter()
+            .filter(|x| x.severity == LintSeverity::Error)
+            .collect_vec();
+
+        assert_eq!(errors, vec![]);
+    }
+
+    #[test]
+    pub fn test_unsolvable_word() {
+        let level = DesignedLevel::from_tsv_line("ASHPKILOEUIOGNDT\tSports\tKENDO\tZEBRA").unwrap();
+
+        let diagnostic = lint_level(&level)
+            .into_iter()
+            .find(|x| x.code == LintCode::UnsolvableWord)
+            .unwrap();
+
+        assert_eq!(diagnostic.word_index, Some(1));
+        assert_eq!(diagnostic.severity, LintSeverity::Error);
+    }
+
+    #[test]
+    pub fn test_unused_tiles() {
+        let level = DesignedLevel::from_tsv_line("ASHPKILOEUIOGNDT\tSports\tPOLO").unwrap();
+
+        let diagnostic = lint_level(&level)
+            .into_iter()
+            .find(|x| x.code == LintCode::UnusedTiles)
+            .unwrap();
+
+        assert!(!diagnostic.tiles.get_bit(&Tile::new_const::<3, 0>()));
+        assert!(diagnostic.tiles.get_bit(&Tile::new_const::<0, 0>()));
+    }
+
+    #[test]
+    pub fn test_blank_tiles() {
+        let level = DesignedLevel::from_tsv_line("ABCDEFGHIJKLMNO\tLetters\tAbcd").unwrap();
+
+        let diagnostic = lint_level(&level)
+            .into_iter()
+            .find(|x| x.code == LintCode::BlankTiles)
+            .unwrap();
+
+        assert_eq!(diagnostic.tiles.count(), 1);
+        assert!(diagnostic.tiles.get_bit(&Tile::new_const::<3, 3>()));
+    }
+
+    #[test]
+    pub fn test_duplicate_word() {
+        let level = DesignedLevel::from_tsv_line("ASHPKILOEUIOGNDT\tSports\tPOLO\tPolo").unwrap();
+
+        assert!(codes(&level).contains(&LintCode::DuplicateWord));
+    }
+
+    #[test]
+    pub fn test_word_ordering() {
+        let mut level = DesignedLevel::from_tsv_line(SPORTS).unwrap();
+        level.words.reverse();
+
+        assert!(codes(&level).contains(&LintCode::WordOrdering));
+    }
+
+    #[test]
+    pub fn test_taboo_word() {
+        let level = DesignedLevel::from_tsv_line("ANALBCDEFGHIJKMO\tTaboo\tANAL").unwrap();
+
+        assert!(codes(&level).contains(&LintCode::TabooWord));
+    }
+
+    #[test]
+    pub fn test_taboo_word_in_digraph() {
+        let level = DesignedLevel::from_tsv_line("(AN)ALBCDEFGHIJKMO\tTaboo\tCdef").unwrap();
+
+        assert!(codes(&level).contains(&LintCode::TabooWord));
+    }
+
+    #[test]
+    pub fn test_no_words() {
+        let level = DesignedLevel::from_tsv_line("ASHPKILOEUIOGNDT\tSports").unwrap();
+
+        assert!(codes(&level).contains(&LintCode::NoWords));
+    }
+
+    #[test]
+    pub fn test_multiple_solutions() {
+        let level = DesignedLevel::from_tsv_line("ABCDABXXXXXXXXXX\tLetters\tAbcd").unwrap();
+
+        let diagnostic = lint_level(&level)
+            .into_iter()
+            .find(|x| x.code == LintCode::MultipleSolutions)
+            .unwrap();
+
+        assert_eq!(diagnostic.word_index, Some(0));
+        assert_eq!(diagnostic.tiles.count(), 6);
+        assert_eq!(diagnostic.severity, LintSeverity::Info);
+    }
+
+    #[test]
+    pub fn test_bad_orientation() {
+        let level = DesignedLevel::from_tsv_line("XXXXXXXXXXXXDCBA\tLetters\tAbcd").unwrap();
+
+        let diagnostic = lint_level(&level)
+            .into_iter()
+            .find(|x| x.code == LintCode::BadOrientation)
+            .unwrap();
+
+        assert_eq!(
+            diagnostic.message,
+            "The grid would read better as ABCDXXXXXXXXXXXX"
+        );
+    }
+}
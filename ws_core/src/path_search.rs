@@ -0,0 +1,191 @@
+use arrayvec::ArrayVec;
+use geometrid::vector::Vector;
+use itertools::Itertools;
+use lazy_static::lazy_static;
+
+use crate::prelude::*;
+
+type AdjacencyMap = geometrid::tile_map::TileMap<GridSet, GRID_WIDTH, GRID_HEIGHT, GRID_SIZE>;
+
+lazy_static! {
+    static ref ADJACENT_TILES: AdjacencyMap =
+        AdjacencyMap::from_fn(|tile| GridSet::from_iter(tile.iter_adjacent()));
+}
+
+/// The tiles holding each character in a grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GridBitboard(CharacterMap<GridSet>);
+
+impl GridBitboard {
+    pub fn new(grid: &Grid) -> Self {
+        let mut map: CharacterMap<GridSet> = Default::default();
+        for (tile, character) in grid.enumerate() {
+            map.get_mut(*character).set_bit(&tile, true);
+        }
+        Self(map)
+    }
+
+    /// The tiles holding this character
+    pub fn tiles(&self, character: Character) -> GridSet {
+        *self.0.get(character)
+    }
+
+    /// Lazily find every path for these characters
+    pub fn iter_solutions<'a>(&self, characters: &'a [Character]) -> SolutionIter<'a> {
+        SolutionIter::new(characters, *self)
+    }
+}
+
+/// A lazy depth first search for the paths of a word through a grid.
+/// Paths are returned in the same order as `WordTrait::find_solutions_sized`
+#[derive(Debug, Clone)]
+pub struct SolutionIter<'a> {
+    characters: &'a [Character],
+    bitboard: GridBitboard,
+    /// Tiles which may start a path and have not been tried yet
+    first_tiles: GridSet,
+    path: Solution,
+    /// The index into `Vector::UNITS` to try next at each step of the path
+    indices: ArrayVec<u8, GRID_SIZE>,
+    used_tiles: GridSet,
+    /// Words with no repeated letters can never revisit a tile
+    check_used_tiles: bool,
+}
+
+impl<'a> SolutionIter<'a> {
+    pub fn new(characters: &'a [Character], bitboard: GridBitboard) -> Self {
+        let possible = characters.len() <= GRID_SIZE
+            && characters.iter().all(|c| !bitboard.tiles(*c).is_empty());
+
+        let first_tiles = match characters.first() {
+            Some(first) if possible => bitboard.tiles(*first),
+            _ => GridSet::EMPTY,
+        };
+
+        Self {
+            characters,
+            bitboard,
+            first_tiles,
+            path: Default::default(),
+            indices: Default::default(),
+            used_tiles: GridSet::EMPTY,
+            check_used_tiles: !characters.iter().all_unique(),
+        }
+    }
+
+    fn push(&mut self, tile: Tile) {
+        self.path.push(tile);
+        self.indices.push(0);
+        self.used_tiles.set_bit(&tile, true);
+    }
+
+    fn pop(&mut self) {
+        if let Some(tile) = self.path.pop() {
+            self.used_tiles.set_bit(&tile, false);
+        }
+        self.indices.pop();
+    }
+}
+
+impl Iterator for SolutionIter<'_> {
+    type Item = Solution;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some(current_tile) = self.path.last().copied() else {
+                let first_tile = self.first_tiles.iter_true_tiles().next()?;
+                self.first_tiles.set_bit(&first_tile, false);
+
+                if self.characters.len() == 1 {
+                    return Some(ArrayVec::from_iter([first_tile]));
+                }
+                self.push(first_tile);
+                continue;
+            };
+
+            let char_to_find = self.characters[self.path.len()];
+            let mut candidates =
+                ADJACENT_TILES[current_tile].intersect(&self.bitboard.tiles(char_to_find));
+            if self.check_used_tiles {
+                candidates = candidates.intersect(&self.used_tiles.negate());
+            }
+
+            let mut next_tile = None;
+            if !candidates.is_empty() {
+                if let Some(index) = self.indices.last_mut() {
+                    while let Some(vector) = Vector::UNITS.get(*index as usize) {
+                        *index += 1;
+                        if let Some(adjacent_tile) = current_tile + vector {
+                            if candidates.get_bit(&adjacent_tile) {
+                                next_tile = Some(adjacent_tile);
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+
+            match next_tile {
+                Some(tile) if self.path.len() + 1 == self.characters.len() => {
+                    let mut solution = self.path.clone();
+                    solution.push(tile);
+                    return Some(solution);
+                }
+                Some(tile) => self.push(tile),
+                None => self.pop(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct TestWord(CharsArray);
+
+    impl WordTrait for TestWord {
+        fn characters(&self) -> &CharsArray {
+            &self.0
+        }
+    }
+
+    /// Random grids and words over a small alphabet so that letters repeat often
+    #[test]
+    pub fn test_matches_reference_search() {
+        const LETTERS: [Character; 4] = [Character::A, Character::B, Character::C, Character::E];
+        let mut rng = StdRng::seed_from_u64(123);
+
+        for _ in 0..500 {
+            let grid = Grid::from_inner(std::array::from_fn(|_| {
+                LETTERS[rng.gen_range(0..LETTERS.len())]
+            }));
+
+            for _ in 0..10 {
+                let length = rng.gen_range(1..=8);
+                let word = TestWord(
+                    (0..length)
+                        .map(|_| LETTERS[rng.gen_range(0..LETTERS.len())])
+                        .collect(),
+                );
+
+                let expected = word.find_solutions_sized(&grid);
+                let actual: Vec<Solution> = word.iter_solutions(&grid).collect();
+
+                assert_eq!(actual, expected, "{word:?} in \n{grid}");
+                assert_eq!(word.find_solution(&grid), word.find_solution_sized(&grid));
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_missing_letter() {
+        let grid = Grid::from_inner([Character::A; GRID_SIZE]);
+        let word = TestWord(CharsArray::from_iter([Character::A, Character::B]));
+
+        assert_eq!(word.iter_solutions(&grid).next(), None);
+    }
+}
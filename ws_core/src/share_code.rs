@@ -0,0 +1,637 @@
+use itertools::Itertools;
+use log::{error, warn};
+use num_traits::FromPrimitive;
+use strum::IntoEnumIterator;
+use ustr::Ustr;
+
+use crate::prelude::*;
+
+/// The first byte of every share code.
+/// Older share links hold tsv text, which always starts with a printable character
+pub const SHARE_CODE_VERSION: u8 = 2;
+/// Version 1 share codes have 5 bit characters, from before there were more than 32 characters
+const SHARE_CODE_VERSION_5_BIT_CHARACTERS: u8 = 1;
+
+const FLAG_DIGRAPHS: u8 = 1 << 0;
+const FLAG_EXTRA_INFO: u8 = 1 << 1;
+const FLAG_COLORS: u8 = 1 << 2;
+const FLAG_ALPHABET: u8 = 1 << 3;
+
+const CHARACTER_BITS: u8 = 6;
+/// Written instead of a preset index for alphabets which are not presets
+const CUSTOM_ALPHABET: u8 = u8::MAX;
+const DIGRAPH_BITS: u8 = 3;
+const TILE_BITS: u8 = 4;
+const WORD_KIND_BITS: u8 = 2;
+
+/// How the display text of a word is stored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+enum WordKind {
+    /// The text is the characters of the path in upper case
+    Upper = 0,
+    /// The text is the characters of the path with only the first letter in upper case
+    Title = 1,
+    /// The text is stored in full
+    Text = 2,
+}
+
+/// An error from reading a share code
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShareCodeError {
+    /// The share code is for a newer version of the game
+    UnknownVersion(u8),
+    /// The checksum does not match, so the share code has been corrupted
+    InvalidChecksum,
+    /// The share code ended early
+    UnexpectedEnd,
+    InvalidCharacter,
+    InvalidDigraph,
+    /// The share code uses an alphabet this version does not know
+    InvalidAlphabet,
+    /// A word is not stored as tiles or as text
+    InvalidWord,
+    /// Some text was not valid UTF-8
+    InvalidText,
+    /// Some text is too long to be written
+    TextTooLong,
+    /// There are too many words or colors to be written
+    TooManyItems,
+    /// A word was read but is not valid
+    Word {
+        word_index: usize,
+        error: WordParseError,
+    },
+}
+
+impl std::fmt::Display for ShareCodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShareCodeError::UnknownVersion(version) => {
+                write!(f, "Share code version {version} is not supported")
+            }
+            ShareCodeError::InvalidChecksum => write!(f, "Share code checksum does not match"),
+            ShareCodeError::UnexpectedEnd => write!(f, "Share code is too short"),
+            ShareCodeError::InvalidCharacter => write!(f, "Share code has an invalid character"),
+            ShareCodeError::InvalidDigraph => write!(f, "Share code has an invalid digraph"),
+            ShareCodeError::InvalidAlphabet => write!(f, "Share code has an invalid alphabet"),
+            ShareCodeError::InvalidWord => write!(f, "Share code has an invalid word"),
+            ShareCodeError::InvalidText => write!(f, "Share code text is not valid"),
+            ShareCodeError::TextTooLong => write!(f, "Text is too long for a share code"),
+            ShareCodeError::TooManyItems => write!(f, "Too many items for a share code"),
+            ShareCodeError::Word { word_index, error } => {
+                write!(f, "Word {word_index} is not valid: {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ShareCodeError {}
+
+impl DesignedLevel {
+    /// Write this level as a compact binary share code.
+    ///
+    /// The grid is written as packed 6 bit characters and each word as the tiles of its path,
+    /// so the words cannot be read without decoding.
+    /// The code ends with a Fletcher-16 checksum
+    pub fn to_share_code(&self) -> Result<Vec<u8>, ShareCodeError> {
+        let mut writer = BitWriter::default();
+        writer.write(SHARE_CODE_VERSION as u32, 8);
+
+        let mut flags = 0;
+        if self.digraphs.is_some() {
+            flags |= FLAG_DIGRAPHS;
+        }
+        if self.extra_info.is_some() {
+            flags |= FLAG_EXTRA_INFO;
+        }
+        if self.special_colors.is_some() {
+            flags |= FLAG_COLORS;
+        }
+        if self.metadata.alphabet != Alphabet::default() {
+            flags |= FLAG_ALPHABET;
+        }
+        writer.write(flags as u32, 8);
+
+        if flags & FLAG_ALPHABET != 0 {
+            writer.write_alphabet(&self.metadata.alphabet)?;
+        }
+
+        for character in self.grid.iter() {
+            writer.write(*character as u32, CHARACTER_BITS);
+        }
+
+        if let Some(digraphs) = &self.digraphs {
+            for digraph in digraphs.iter() {
+                let value = match digraph {
+                    Some(digraph) => Digraph::iter().position(|x| x == *digraph).unwrap_or(0) + 1,
+                    None => 0,
+                };
+                writer.write(value as u32, DIGRAPH_BITS);
+            }
+        }
+
+        writer.write_text(&self.name)?;
+        if let Some(extra_info) = self.extra_info {
+            writer.write_text(&extra_info)?;
+        }
+
+        writer.write_count(self.words.len())?;
+        for word in self.words.iter() {
+            let path = self
+                .find_word_solution(word, GridSet::EMPTY)
+                .filter(|path| !path.is_empty());
+
+            let kind = match &path {
+                Some(_) if word.text.as_str() == upper_text(&word.characters) => WordKind::Upper,
+                Some(_) if word.text.as_str() == title_text(&word.characters) => WordKind::Title,
+                _ => WordKind::Text,
+            };
+
+            writer.write(kind as u32, WORD_KIND_BITS);
+            match (kind, path) {
+                (WordKind::Upper | WordKind::Title, Some(path)) => {
+                    writer.write(path.len() as u32 - 1, TILE_BITS);
+                    for tile in path {
+                        writer.write(tile.inner() as u32, TILE_BITS);
+                    }
+                }
+                _ => writer.write_text(&word.text)?,
+            }
+        }
+
+        if let Some(colors) = &self.special_colors {
+            writer.write_count(colors.len())?;
+            for color in colors {
+                for value in [color.red, color.green, color.blue, color.alpha] {
+                    writer.write((value * 255.0).round().clamp(0.0, 255.0) as u32, 8);
+                }
+            }
+        }
+
+        let mut bytes = writer.finish();
+        bytes.extend(fletcher_16(&bytes).to_be_bytes());
+        Ok(bytes)
+    }
+
+    /// The data part of a share link for this level.
+    /// This is a base64 share code, or base64 tsv if the level cannot be written as a share code
+    pub fn share_data(&self) -> String {
+        use base64::Engine;
+
+        match self.to_share_code() {
+            Ok(code) => base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(code),
+            Err(err) => {
+                warn!("Could not make share code: {err}");
+                base64::engine::general_purpose::URL_SAFE.encode(self.to_tsv_line())
+            }
+        }
+    }
+
+    /// Read the data part of a share link.
+    /// Both share codes and the older base64 tsv links can be read
+    pub fn try_from_share_data(data: &str) -> Option<Self> {
+        use base64::Engine;
+
+        let data = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(data.trim().trim_end_matches('='))
+            .ok()?;
+
+        if matches!(data.first(), Some(1..=SHARE_CODE_VERSION)) {
+            return match Self::try_from_share_code(&data) {
+                Ok(level) => Some(level),
+                Err(err) => {
+                    error!("{err}");
+                    None
+                }
+            };
+        }
+
+        let data = String::from_utf8(data).ok()?;
+
+        match DesignedLevel::from_tsv_line(data.trim()) {
+            Ok(level) => Some(level),
+            Err(err) => {
+                error!("{err}");
+                None
+            }
+        }
+    }
+
+    /// Read a level from a share code made by `to_share_code`
+    pub fn try_from_share_code(bytes: &[u8]) -> Result<Self, ShareCodeError> {
+        if bytes.len() < 2 {
+            return Err(ShareCodeError::UnexpectedEnd);
+        }
+        let (data, checksum) = bytes.split_at(bytes.len() - 2);
+        if fletcher_16(data).to_be_bytes() != checksum {
+            return Err(ShareCodeError::InvalidChecksum);
+        }
+
+        let mut reader = BitReader::new(data);
+        let version = reader.read(8)? as u8;
+        let character_bits = match version {
+            SHARE_CODE_VERSION => CHARACTER_BITS,
+            SHARE_CODE_VERSION_5_BIT_CHARACTERS => 5,
+            _ => return Err(ShareCodeError::UnknownVersion(version)),
+        };
+        let flags = reader.read(8)? as u8;
+
+        let alphabet = if flags & FLAG_ALPHABET != 0 {
+            reader.read_alphabet(character_bits)?
+        } else {
+            Alphabet::default()
+        };
+
+        let mut characters = [Character::Blank; GRID_SIZE];
+        for character in characters.iter_mut() {
+            *character = reader.read_character(character_bits)?;
+        }
+        let grid = Grid::from_inner(characters);
+
+        let digraphs = if flags & FLAG_DIGRAPHS != 0 {
+            let mut digraphs = [None; GRID_SIZE];
+            for digraph in digraphs.iter_mut() {
+                *digraph = match reader.read(DIGRAPH_BITS)? {
+                    0 => None,
+                    value => Some(
+                        Digraph::iter()
+                            .nth(value as usize - 1)
+                            .ok_or(ShareCodeError::InvalidDigraph)?,
+                    ),
+                };
+            }
+            Some(DigraphMap::from_inner(digraphs))
+        } else {
+            None
+        };
+
+        let name = Ustr::from(reader.read_text()?.as_str());
+        let extra_info = if flags & FLAG_EXTRA_INFO != 0 {
+            Some(Ustr::from(reader.read_text()?.as_str()))
+        } else {
+            None
+        };
+
+        let mut level = DesignedLevel {
+            name,
+            extra_info,
+            grid,
+            digraphs,
+            ..DesignedLevel::unknown()
+        };
+        level.metadata.alphabet = alphabet.clone();
+
+        let word_count = reader.read(8)? as usize;
+        if word_count > MAX_LEVEL_WORDS {
+            return Err(ShareCodeError::TooManyItems);
+        }
+        for word_index in 0..word_count {
+            let text = match reader.read(WORD_KIND_BITS)? {
+                kind @ 0..=1 => {
+                    let length = reader.read(TILE_BITS)? as usize + 1;
+                    let mut path = Solution::default();
+                    for _ in 0..length {
+                        let tile = Tile::try_from_inner(reader.read(TILE_BITS)? as u8)
+                            .ok_or(ShareCodeError::InvalidWord)?;
+                        path.push(tile);
+                    }
+                    let characters = level.selection_characters(&path);
+                    if kind == WordKind::Upper as u32 {
+                        upper_text(&characters)
+                    } else {
+                        title_text(&characters)
+                    }
+                }
+                2 => reader.read_text()?,
+                _ => return Err(ShareCodeError::InvalidWord),
+            };
+
+            let word = DisplayWord::from_str_with_alphabet(&text, &alphabet)
+                .map_err(|error| ShareCodeError::Word { word_index, error })?;
+            level.words.push(word);
+        }
+        level.words.sort();
+
+        if flags & FLAG_COLORS != 0 {
+            let count = reader.read(8)? as usize;
+            let colors: Vec<BasicColor> = (0..count)
+                .map(|_| {
+                    let mut values = [0.0; 4];
+                    for value in values.iter_mut() {
+                        *value = reader.read(8)? as f32 / 255.0;
+                    }
+                    let [r, g, b, a] = values;
+                    Ok(BasicColor::rgba(r, g, b, a))
+                })
+                .try_collect()?;
+            level.special_colors = Some(colors).filter(|x| !x.is_empty());
+        }
+
+        Ok(level)
+    }
+}
+
+fn upper_text(characters: &[Character]) -> String {
+    characters.iter().map(|c| c.as_char()).collect()
+}
+
+fn title_text(characters: &[Character]) -> String {
+    let mut text = String::new();
+    if let Some((first, rest)) = characters.split_first() {
+        text.push(first.as_char());
+        text.extend(rest.iter().flat_map(|c| c.as_char().to_lowercase()));
+    }
+    text
+}
+
+/// The Fletcher-16 checksum of some bytes
+pub(crate) fn fletcher_16(bytes: &[u8]) -> u16 {
+    let (sum1, sum2) = bytes.iter().fold((0u16, 0u16), |(sum1, sum2), byte| {
+        let sum1 = (sum1 + *byte as u16) % 255;
+        let sum2 = (sum2 + sum1) % 255;
+        (sum1, sum2)
+    });
+    (sum2 << 8) | sum1
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct BitWriter {
+    bytes: Vec<u8>,
+    /// The number of bits used in the last byte
+    used_bits: u8,
+}
+
+impl BitWriter {
+    /// Write the lowest `bits` bits of `value`, most significant first
+    pub(crate) fn write(&mut self, value: u32, bits: u8) {
+        for bit in (0..bits).rev() {
+            if self.used_bits % 8 == 0 {
+                self.bytes.push(0);
+                self.used_bits = 0;
+            }
+            if (value >> bit) & 1 == 1 {
+                if let Some(last) = self.bytes.last_mut() {
+                    *last |= 1 << (7 - self.used_bits);
+                }
+            }
+            self.used_bits += 1;
+        }
+    }
+
+    pub(crate) fn write_count(&mut self, count: usize) -> Result<(), ShareCodeError> {
+        let count = u8::try_from(count).map_err(|_| ShareCodeError::TooManyItems)?;
+        self.write(count as u32, 8);
+        Ok(())
+    }
+
+    pub(crate) fn write_text(&mut self, text: &str) -> Result<(), ShareCodeError> {
+        let length = u8::try_from(text.len()).map_err(|_| ShareCodeError::TextTooLong)?;
+        self.write(length as u32, 8);
+        for byte in text.bytes() {
+            self.write(byte as u32, 8);
+        }
+        Ok(())
+    }
+
+    /// Write the index of a preset alphabet, or the letters of a custom one
+    pub(crate) fn write_alphabet(&mut self, alphabet: &Alphabet) -> Result<(), ShareCodeError> {
+        if let Some(preset) = alphabet.preset() {
+            let index = AlphabetPreset::iter().position(|x| x == preset);
+            self.write(index.unwrap_or_default() as u32, 8);
+            return Ok(());
+        }
+
+        self.write(CUSTOM_ALPHABET as u32, 8);
+        self.write_count(alphabet.kept_letters().count())?;
+        for character in alphabet.kept_letters() {
+            self.write(character as u32, CHARACTER_BITS);
+        }
+        self.write_count(alphabet.transliterations().count())?;
+        for (letter, characters) in alphabet.transliterations() {
+            self.write_text(&letter.to_string())?;
+            self.write(characters.len() as u32, 2);
+            for character in characters {
+                self.write(*character as u32, CHARACTER_BITS);
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn finish(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    pub(crate) fn read(&mut self, bits: u8) -> Result<u32, ShareCodeError> {
+        let mut value = 0;
+        for _ in 0..bits {
+            let byte = self
+                .bytes
+                .get(self.position / 8)
+                .ok_or(ShareCodeError::UnexpectedEnd)?;
+            let bit = (byte >> (7 - (self.position % 8))) & 1;
+            value = (value << 1) | bit as u32;
+            self.position += 1;
+        }
+        Ok(value)
+    }
+
+    pub(crate) fn read_character(&mut self, bits: u8) -> Result<Character, ShareCodeError> {
+        Character::from_u32(self.read(bits)?).ok_or(ShareCodeError::InvalidCharacter)
+    }
+
+    pub(crate) fn read_alphabet(&mut self, character_bits: u8) -> Result<Alphabet, ShareCodeError> {
+        let index = self.read(8)? as u8;
+        if index != CUSTOM_ALPHABET {
+            return AlphabetPreset::iter()
+                .nth(index as usize)
+                .map(|x| x.alphabet())
+                .ok_or(ShareCodeError::InvalidAlphabet);
+        }
+
+        let kept_letters: Vec<Character> = (0..self.read(8)?)
+            .map(|_| self.read_character(character_bits))
+            .try_collect()?;
+        let mut alphabet = Alphabet::default().with_kept_letters(kept_letters);
+
+        for _ in 0..self.read(8)? {
+            let letter = self.read_text()?;
+            let letter = letter
+                .chars()
+                .exactly_one()
+                .map_err(|_| ShareCodeError::InvalidAlphabet)?;
+            let length = self.read(2)?;
+            let characters: GraphemeCharacters = (0..length)
+                .map(|_| self.read_character(character_bits))
+                .try_collect()?;
+            alphabet = alphabet.with_transliteration(letter, characters);
+        }
+        Ok(alphabet)
+    }
+
+    pub(crate) fn read_text(&mut self) -> Result<String, ShareCodeError> {
+        let length = self.read(8)? as usize;
+        let bytes: Vec<u8> = (0..length)
+            .map(|_| self.read(8).map(|x| x as u8))
+            .try_collect()?;
+        String::from_utf8(bytes).map_err(|_| ShareCodeError::InvalidText)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    /* spellchecker:disable */
+    #[test_case("ASHPKILOEUIOGNDT\tSports\tPOLO\tSHOOTING\tKENDO\tSAILING\tLUGE\tSKIING")]
+    #[test_case("KGRROEAYDNLAUBIW\tCounties of Ireland\tDerry\tDonegal\tDublin\tGalway\tKerry")]
+    #[test_case("(QU)EEN(TH)INXXXXXXXXX\tDigraphs\tQUEEN\tTHIN")]
+    #[test_case("ASHPKILOEUIOGNDT\tSports[By someone]{#ff0000,#00ff0080}\tKendo\tSAIL ING")]
+    #[test_case("ASHPKILOEUIOGNDT\tSports\tZEBRA")]
+    pub fn test_round_trip(line: &str) {
+        let level = DesignedLevel::from_tsv_line(line).unwrap();
+        let code = level.to_share_code().unwrap();
+        let decoded = DesignedLevel::try_from_share_code(&code).unwrap();
+
+        assert_eq!(decoded.to_tsv_line(), level.to_tsv_line());
+        assert_eq!(decoded, level);
+    }
+
+    #[test]
+    pub fn test_round_trip_with_alphabet() {
+        let yaml = r#"
+- grid: ÅLESDNUMXXLAXXÖM
+  name: Nordic
+  alphabet: Nordic
+  words:
+  - Ålesund
+  - MALMÖ
+"#;
+        let level = DesignedLevel::from_yaml(yaml).unwrap().remove(0);
+        assert!(level.words.iter().all(|w| w
+            .characters
+            .iter()
+            .any(|c| level.metadata.alphabet.keeps(*c))));
+
+        let code = level.to_share_code().unwrap();
+        let decoded = DesignedLevel::try_from_share_code(&code).unwrap();
+
+        assert_eq!(decoded, level);
+        assert!(!code.windows(4).any(|x| x == b"MALM"));
+    }
+
+    #[test]
+    pub fn test_share_data() {
+        let line = "ASHPKILOEUIOGNDT\tSports\tPOLO\tSHOOTING\tKENDO\tSAILING\tLUGE\tSKIING";
+        let level = DesignedLevel::from_tsv_line(line).unwrap();
+
+        let data = level.share_data();
+        assert_eq!(
+            DesignedLevel::try_from_share_data(&data),
+            Some(level.clone())
+        );
+
+        let path = format!("https://wordsalad.online/game/{data}");
+        assert_eq!(DesignedLevel::try_from_path(&path), Some(level));
+    }
+
+    #[test]
+    pub fn test_old_links() {
+        let data =
+            "Sk5FSU1BTFpSWUlaVFRESwlCZW5uZXQgU2lzdGVycwlKYW5lCUtpdHR5CUxpenppZQlMeWRpYQlNYXJ5";
+        let level = DesignedLevel::try_from_path(format!("/game/{data}").as_str()).unwrap();
+
+        assert_eq!(level.name.as_str(), "Bennet Sisters");
+        assert_eq!(level.words.len(), 5);
+        assert_eq!(
+            DesignedLevel::try_from_share_data(&level.share_data()),
+            Some(level)
+        );
+    }
+
+    #[test]
+    pub fn test_version_one_code() {
+        // Written with 5 bit characters
+        let data = "AQARjyqNRQLGWJEhBlNwb3J0cwEM3ayQnQ";
+        let level = DesignedLevel::try_from_share_data(data).unwrap();
+
+        assert_eq!(
+            level,
+            DesignedLevel::from_tsv_line("ASHPKILOEUIOGNDT\tSports\tPOLO").unwrap()
+        );
+    }
+
+    #[test]
+    pub fn test_round_trip_with_custom_alphabet() {
+        let yaml = r#"
+- grid: ЙОШКXЖРАXXXXXXXX
+  name: Custom
+  alphabet:
+    kept_letters: Й
+    transliterations:
+      ё: ЙО
+  words:
+  - Йошкар
+  - Ёж
+"#;
+        let level = DesignedLevel::from_yaml(yaml).unwrap().remove(0);
+        assert_eq!(level.metadata.alphabet.preset(), None);
+
+        let code = level.to_share_code().unwrap();
+        let decoded = DesignedLevel::try_from_share_code(&code).unwrap();
+
+        assert_eq!(decoded, level);
+        assert_eq!(decoded.metadata.alphabet, level.metadata.alphabet);
+    }
+
+    #[test]
+    pub fn test_code_is_smaller_than_text() {
+        let line = "ASHPKILOEUIOGNDT\tSports\tPOLO\tSHOOTING\tKENDO\tSAILING\tLUGE\tSKIING";
+        let level = DesignedLevel::from_tsv_line(line).unwrap();
+        let code = level.to_share_code().unwrap();
+
+        assert!(code.len() < line.len(), "{} bytes", code.len());
+        assert!(!code.windows(4).any(|x| x == b"POLO"));
+    }
+
+    #[test]
+    pub fn test_corrupted_code() {
+        let level = DesignedLevel::from_tsv_line("ASHPKILOEUIOGNDT\tSports\tPOLO").unwrap();
+        let mut code = level.to_share_code().unwrap();
+        code[3] ^= 0b100;
+
+        assert_eq!(
+            DesignedLevel::try_from_share_code(&code),
+            Err(ShareCodeError::InvalidChecksum)
+        );
+        assert_eq!(
+            DesignedLevel::try_from_share_code(&[]),
+            Err(ShareCodeError::UnexpectedEnd)
+        );
+    }
+
+    #[test]
+    pub fn test_unknown_version() {
+        let data = [SHARE_CODE_VERSION + 1, 0];
+        let mut code = data.to_vec();
+        code.extend(fletcher_16(&data).to_be_bytes());
+
+        assert_eq!(
+            DesignedLevel::try_from_share_code(&code),
+            Err(ShareCodeError::UnknownVersion(SHARE_CODE_VERSION + 1))
+        );
+    }
+}
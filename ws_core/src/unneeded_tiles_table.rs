@@ -0,0 +1,190 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use crate::{level_trait::LevelContents, GridSet, LevelTrait};
+
+/// A bitmask of the indices of found words
+pub type FoundWordsMask = u64;
+
+/// The most words a level can have, so that every word has a bit in a `FoundWordsMask`
+pub const MAX_LEVEL_WORDS: usize = FoundWordsMask::BITS as usize;
+
+pub const fn is_word_found(found_words: FoundWordsMask, word_index: usize) -> bool {
+    found_words & found_word_bit(word_index) != 0
+}
+
+/// The bit for a word in a `FoundWordsMask`, or zero if the index is too large to have one
+pub const fn found_word_bit(word_index: usize) -> FoundWordsMask {
+    if word_index < MAX_LEVEL_WORDS {
+        1 << word_index
+    } else {
+        0
+    }
+}
+
+/// The unneeded tiles for each set of found words, calculated lazily.
+/// Each entry also records the previously unneeded tiles it was calculated from,
+/// because `calculate_unneeded_tiles` depends on them, and is replaced if they differ.
+/// The table is cleared if the level's grid or words change. Clones start with an empty table
+#[derive(Default)]
+pub struct UnneededTilesTable(Mutex<Option<(LevelContents, TableEntries)>>);
+
+/// The previously unneeded tiles and the result, for each set of found words
+type TableEntries = HashMap<FoundWordsMask, (GridSet, GridSet)>;
+
+impl Clone for UnneededTilesTable {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl UnneededTilesTable {
+    pub fn get_or_calculate<L: LevelTrait>(
+        &self,
+        level: &L,
+        unneeded_tiles: GridSet,
+        found_words: FoundWordsMask,
+    ) -> GridSet {
+        let contents = LevelContents::of(level);
+
+        if let Some(result) = self.0.lock().ok().and_then(|table| {
+            let (table_contents, entries) = table.as_ref()?;
+            if *table_contents != contents {
+                return None;
+            }
+            let (previous, result) = entries.get(&found_words)?;
+            (*previous == unneeded_tiles).then_some(*result)
+        }) {
+            return result;
+        }
+
+        let result = level
+            .calculate_unneeded_tiles(unneeded_tiles, |index| is_word_found(found_words, index));
+
+        if let Ok(mut table) = self.0.lock() {
+            match table.as_mut() {
+                Some((table_contents, entries)) if *table_contents == contents => {
+                    entries.insert(found_words, (unneeded_tiles, result));
+                }
+                _ => {
+                    *table = Some((
+                        contents,
+                        TableEntries::from([(found_words, (unneeded_tiles, result))]),
+                    ));
+                }
+            }
+        }
+
+        result
+    }
+
+    /// The number of sets of found words in the table
+    pub fn len(&self) -> usize {
+        self.0
+            .lock()
+            .ok()
+            .and_then(|table| table.as_ref().map(|x| x.1.len()))
+            .unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl std::fmt::Debug for UnneededTilesTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UnneededTilesTable")
+            .field("entries", &self.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use crate::prelude::*;
+
+    /* spellchecker:disable */
+    const LEVELS: [&str; 2] = [
+        "ASHPKILOEUIOGNDT\tSports\tPOLO\tSHOOTING\tKENDO\tSAILING\tLUGE\tSKIING",
+        "KGRROEAYDNLAUBIW\tCounties of Ireland\tDerry\tDonegal\tDublin\tGalway\tKerry",
+    ];
+
+    #[test]
+    pub fn test_matches_incremental_calculation() {
+        for line in LEVELS {
+            let level = DesignedLevel::from_tsv_line(line).unwrap();
+            let word_count = level.words.len();
+
+            for order in (0..word_count).permutations(word_count) {
+                let mut expected = GridSet::EMPTY;
+                let mut found_words: FoundWordsMask = 0;
+
+                for word_index in order {
+                    found_words |= 1 << word_index;
+                    let previous = expected;
+                    expected = level.calculate_unneeded_tiles(previous, |index| {
+                        is_word_found(found_words, index)
+                    });
+
+                    let actual = level.unneeded_tiles_after_finding(previous, found_words);
+                    assert_eq!(actual, expected);
+                }
+            }
+
+            assert!(!level.unneeded_tiles_table.is_empty());
+            assert!(level.unneeded_tiles_table.len() < 1 << word_count);
+        }
+    }
+
+    #[test]
+    pub fn test_clones_do_not_share_entries() {
+        let level = DesignedLevel::from_tsv_line(LEVELS[0]).unwrap();
+        let clone = level.clone();
+
+        level.unneeded_tiles_after_finding(GridSet::EMPTY, 1);
+
+        assert!(!level.unneeded_tiles_table.is_empty());
+        assert!(clone.unneeded_tiles_table.is_empty());
+        assert_eq!(clone, level);
+    }
+
+    #[test]
+    pub fn test_changing_words_clears_entries() {
+        let mut level = DesignedLevel::from_tsv_line(LEVELS[0]).unwrap();
+        level.unneeded_tiles_for_found_words(0b111);
+
+        level.words.reverse();
+        level.words.pop();
+
+        assert_every_subset(&level);
+    }
+
+    #[test]
+    pub fn test_every_subset() {
+        for line in LEVELS {
+            let level = DesignedLevel::from_tsv_line(line).unwrap();
+
+            assert_every_subset(&level);
+        }
+    }
+
+    /// Check the table against finding each set of words in index order
+    fn assert_every_subset(level: &DesignedLevel) {
+        for found_words in 0..(1 << level.words.len()) {
+            let mut expected = GridSet::EMPTY;
+            let mut found_so_far: FoundWordsMask = 0;
+            for word_index in 0..level.words.len() {
+                if !is_word_found(found_words, word_index) {
+                    continue;
+                }
+                found_so_far |= 1 << word_index;
+                expected = level
+                    .calculate_unneeded_tiles(expected, |index| is_word_found(found_so_far, index));
+            }
+
+            assert_eq!(level.unneeded_tiles_for_found_words(found_words), expected);
+        }
+    }
+}
@@ -17,13 +17,13 @@ impl WordTrait for Word {
 }
 
 impl FromStr for Word {
-    type Err = &'static str;
+    type Err = WordParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let characters = normalize_characters_array(s)?;
 
         if characters.len() <= 3 {
-            return Err("Word has 3 or fewer characters");
+            return Err(WordParseError::TooShort);
         }
 
         Ok(Self {
@@ -38,6 +38,7 @@ mod tests {
     use std::str::FromStr;
 
     use arrayvec::ArrayVec;
+    use itertools::{Either, Itertools};
 
     use crate::prelude::*;
 
@@ -94,4 +95,72 @@ mod tests {
         assert_eq!(expected_0, paths[0]);
         assert_eq!(expected_1, paths[1]);
     }
+
+    #[test]
+    pub fn test_find_path_small_grid() {
+        // spellchecker:disable-next-line
+        let grid =
+            try_make_sized_grid::<3, 3, 9>("CATXXSXXX").expect("Should be able to make grid");
+        let cats = Word::from_str("cats").expect("Should be able to make word");
+
+        let path = cats
+            .find_solution_sized(&grid)
+            .expect("Should be able to find a path for 'cats'");
+
+        let expected: SizedSolution<3, 3, 9> = arrayvec::ArrayVec::from_iter([
+            geometrid::tile::Tile::<3, 3>::new_const::<0, 0>(),
+            geometrid::tile::Tile::<3, 3>::new_const::<1, 0>(),
+            geometrid::tile::Tile::<3, 3>::new_const::<2, 0>(),
+            geometrid::tile::Tile::<3, 3>::new_const::<2, 1>(),
+        ]);
+
+        assert_eq!(expected, path)
+    }
+
+    #[test]
+    pub fn test_find_paths_large_grid() {
+        // spellchecker:disable-next-line
+        let grid = try_make_sized_grid::<5, 5, 25>("ABCDEFGHIJKLMNOPQRSTUVWXY")
+            .expect("Should be able to make grid");
+        // spellchecker:disable-next-line
+        let diagonal = Word::from_str("agmsy").expect("Should be able to make word");
+
+        let paths = diagonal.find_solutions_sized(&grid);
+
+        let expected: SizedSolution<5, 5, 25> = arrayvec::ArrayVec::from_iter([
+            geometrid::tile::Tile::<5, 5>::new_const::<0, 0>(),
+            geometrid::tile::Tile::<5, 5>::new_const::<1, 1>(),
+            geometrid::tile::Tile::<5, 5>::new_const::<2, 2>(),
+            geometrid::tile::Tile::<5, 5>::new_const::<3, 3>(),
+            geometrid::tile::Tile::<5, 5>::new_const::<4, 4>(),
+        ]);
+
+        assert_eq!(vec![expected], paths);
+    }
+
+    #[test]
+    pub fn test_word_longer_than_default_grid() {
+        // spellchecker:disable-next-line
+        let grid = try_make_sized_grid::<5, 5, 25>("ABCDEFGHIJKLMNOPQRSTUVWXY")
+            .expect("Should be able to make grid");
+        let tiles = geometrid::tile::Tile::<5, 5>::iter_by_row().collect_vec();
+
+        // Snake along the first four rows, which is longer than a default grid has tiles
+        let path: SizedSolution<5, 5, 25> = (0..4)
+            .flat_map(|row| {
+                let columns = (0..5).map(move |column| (row * 5) + column);
+                if row % 2 == 0 {
+                    Either::Left(columns)
+                } else {
+                    Either::Right(columns.rev())
+                }
+            })
+            .map(|index| tiles[index])
+            .collect();
+        let characters = path.iter().map(|tile| grid[*tile]).collect_vec();
+
+        assert_eq!(characters.len(), 20);
+        assert_eq!(find_sized_solutions(&characters, &grid), vec![path.clone()]);
+        assert_eq!(find_sized_solution(&characters, &grid), Some(path));
+    }
 }
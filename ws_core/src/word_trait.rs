@@ -2,7 +2,35 @@ use arrayvec::ArrayVec;
 use geometrid::vector::Vector;
 
 use crate::finder::helpers::LetterCounts;
-use crate::{Character, CharsArray, Grid, GridSet, Solution, Tile};
+use crate::path_search::{GridBitboard, SolutionIter};
+use crate::{Character, CharsArray, Grid, GridSet, SizedGrid, SizedSolution, Solution};
+
+/// The tiles used by a path in a grid with up to 64 tiles
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct UsedTiles<const SIZE: usize>(u64);
+
+impl<const SIZE: usize> UsedTiles<SIZE> {
+    /// Fails to compile for grids with more tiles than fit in the set
+    const FITS: () = assert!(SIZE <= u64::BITS as usize);
+
+    fn new() -> Self {
+        let () = Self::FITS;
+        Self(0)
+    }
+
+    fn get(&self, index: u8) -> bool {
+        self.0 & (1u64 << index) != 0
+    }
+
+    fn set(&mut self, index: u8, value: bool) {
+        if value {
+            self.0 |= 1u64 << index;
+        } else {
+            self.0 &= !(1u64 << index);
+        }
+    }
+}
+
 pub trait WordTrait {
     fn characters(&self) -> &CharsArray;
 
@@ -10,161 +38,197 @@ pub trait WordTrait {
         LetterCounts::try_from_iter(self.characters().iter().cloned())
     }
 
-    fn find_solutions(&self, grid: &Grid) -> Vec<Solution> {
-        let characters = self.characters();
-        //TODO return iter
-        //TODO more efficient path if word has no duplicate letters
-
-        let Some(first_char) = characters.first() else {
-            return Default::default();
-        };
-        let mut solutions: Vec<Solution> = vec![];
-
-        for first_tile in Tile::iter_by_row().filter(|tile| grid[*tile] == *first_char) {
-            let mut path: ArrayVec<Tile, 16> = Default::default();
-            let mut used_tiles: GridSet = Default::default();
-            let mut indices: ArrayVec<u8, 16> = Default::default();
-
-            let mut current_index: u8 = 0;
-            let mut current_tile: Tile = first_tile;
-            let mut char_to_find: Character = match characters.get(1) {
-                Some(c) => *c,
-                None => {
-                    path.push(current_tile);
-                    solutions.push(path.clone());
-                    continue;
-                }
-            };
-
-            loop {
-                if let Some(vector) = Vector::UNITS.get(current_index as usize) {
-                    current_index += 1;
-                    if let Some(adjacent_tile) = current_tile + vector {
-                        if grid[adjacent_tile] == char_to_find
-                            && !used_tiles.get_bit(&adjacent_tile)
-                        {
-                            //we need to go deeper
-                            path.push(current_tile);
-
-                            match characters.get(path.len() + 1) {
-                                Some(c) => {
-                                    used_tiles.set_bit(&current_tile, true);
-                                    indices.push(current_index);
-                                    current_index = 0;
-                                    current_tile = adjacent_tile;
-                                    char_to_find = *c;
-                                }
-                                None => {
-                                    //we have found all the characters we need to find
-                                    let mut final_path = path.clone();
-                                    final_path.push(adjacent_tile);
-
-                                    solutions.push(final_path);
-                                    path.pop();
-                                }
-                            };
-                        }
-                    }
-                } else {
-                    //we have run out of options to try - go up a level
-                    let Some(ct) = path.pop() else {
-                        break;
-                    };
-
-                    used_tiles.set_bit(&ct, false);
-                    current_tile = ct;
-                    let Some(ci) = indices.pop() else {
-                        break;
-                    };
-                    current_index = ci;
-
-                    char_to_find = match characters.get(path.len() + 1) {
-                        Some(c) => *c,
-                        None => break,
-                    };
-                }
-            }
-        }
+    /// Lazily find all paths for this word
+    fn iter_solutions(&self, grid: &Grid) -> SolutionIter<'_> {
+        GridBitboard::new(grid).iter_solutions(self.characters())
+    }
 
-        solutions
+    fn find_solutions(&self, grid: &Grid) -> Vec<Solution> {
+        self.iter_solutions(grid).collect()
     }
 
     fn find_solution(&self, grid: &Grid) -> Option<Solution> {
-        let characters = self.characters();
-        // if characters.iter().all_unique(){
+        self.iter_solutions(grid).next()
+    }
 
-        // }
+    /// Find all paths for this word through a grid of any dimensions, up to 64 tiles.
+    /// For the standard grid size `iter_solutions` is faster
+    fn find_solutions_sized<const W: u8, const H: u8, const SIZE: usize>(
+        &self,
+        grid: &SizedGrid<W, H, SIZE>,
+    ) -> Vec<SizedSolution<W, H, SIZE>> {
+        find_sized_solutions(self.characters(), grid)
+    }
 
-        //TODO more efficient path if word has no duplicate letters
+    /// Find the first path for this word through a grid of any dimensions, up to 64 tiles
+    fn find_solution_sized<const W: u8, const H: u8, const SIZE: usize>(
+        &self,
+        grid: &SizedGrid<W, H, SIZE>,
+    ) -> Option<SizedSolution<W, H, SIZE>> {
+        find_sized_solution(self.characters(), grid)
+    }
 
-        let first_char = characters.first()?;
+    fn find_solution_with_tiles(&self, grid: &Grid, unneeded_tiles: GridSet) -> Option<Solution> {
+        let mut grid = *grid;
+        for tile in unneeded_tiles.iter_true_tiles() {
+            grid[tile] = Character::Blank;
+        }
+        Self::find_solution(self, &grid)
+    }
+}
 
-        for first_tile in Tile::iter_by_row().filter(|tile| grid[*tile] == *first_char) {
-            let mut path: ArrayVec<Tile, 16> = Default::default();
-            let mut used_tiles: GridSet = Default::default();
-            let mut indices: ArrayVec<u8, 16> = Default::default();
+/// Find all paths for some characters through a grid of any dimensions, up to 64 tiles.
+/// Unlike `WordTrait` words, the characters may be as long as the grid has tiles
+pub fn find_sized_solutions<const W: u8, const H: u8, const SIZE: usize>(
+    characters: &[Character],
+    grid: &SizedGrid<W, H, SIZE>,
+) -> Vec<SizedSolution<W, H, SIZE>> {
+    if characters.len() > SIZE {
+        return Default::default();
+    }
+    let Some(first_char) = characters.first() else {
+        return Default::default();
+    };
+    let mut solutions: Vec<SizedSolution<W, H, SIZE>> = vec![];
+
+    for first_tile in
+        geometrid::tile::Tile::<W, H>::iter_by_row().filter(|tile| grid[*tile] == *first_char)
+    {
+        let mut path: SizedSolution<W, H, SIZE> = Default::default();
+        let mut used_tiles: UsedTiles<SIZE> = UsedTiles::new();
+        let mut indices: ArrayVec<u8, SIZE> = Default::default();
+
+        let mut current_index: u8 = 0;
+        let mut current_tile: geometrid::tile::Tile<W, H> = first_tile;
+        let mut char_to_find: Character = match characters.get(1) {
+            Some(c) => *c,
+            None => {
+                path.push(current_tile);
+                solutions.push(path.clone());
+                continue;
+            }
+        };
 
-            let mut current_index: u8 = 0;
-            let mut current_tile: Tile = first_tile;
-            let mut char_to_find: Character = match characters.get(1) {
-                Some(c) => *c,
-                None => {
-                    path.push(current_tile);
-                    return Some(path);
-                }
-            };
-
-            loop {
-                if let Some(vector) = Vector::UNITS.get(current_index as usize) {
-                    current_index += 1;
-                    if let Some(adjacent_tile) = current_tile + vector {
-                        if grid[adjacent_tile] == char_to_find
-                            && !used_tiles.get_bit(&adjacent_tile)
-                        {
-                            //we need to go deeper
-                            path.push(current_tile);
-                            used_tiles.set_bit(&current_tile, true);
-                            indices.push(current_index);
-                            current_index = 0;
-                            current_tile = adjacent_tile;
-                            char_to_find = match characters.get(path.len() + 1) {
-                                Some(c) => *c,
-                                None => {
-                                    path.push(current_tile);
-                                    return Some(path);
-                                }
-                            };
-                        }
+        loop {
+            if let Some(vector) = Vector::UNITS.get(current_index as usize) {
+                current_index += 1;
+                if let Some(adjacent_tile) = current_tile + vector {
+                    if grid[adjacent_tile] == char_to_find && !used_tiles.get(adjacent_tile.inner())
+                    {
+                        //we need to go deeper
+                        path.push(current_tile);
+
+                        match characters.get(path.len() + 1) {
+                            Some(c) => {
+                                used_tiles.set(current_tile.inner(), true);
+                                indices.push(current_index);
+                                current_index = 0;
+                                current_tile = adjacent_tile;
+                                char_to_find = *c;
+                            }
+                            None => {
+                                //we have found all the characters we need to find
+                                let mut final_path = path.clone();
+                                final_path.push(adjacent_tile);
+
+                                solutions.push(final_path);
+                                path.pop();
+                            }
+                        };
                     }
-                } else {
-                    //we have run out of options to try - go up a level
-                    let Some(ct) = path.pop() else {
-                        break;
-                    };
-
-                    used_tiles.set_bit(&ct, false);
-                    current_tile = ct;
-                    let Some(ci) = indices.pop() else {
-                        break;
-                    };
-                    current_index = ci;
-
-                    char_to_find = match characters.get(path.len() + 1) {
-                        Some(c) => *c,
-                        None => break,
-                    };
                 }
+            } else {
+                //we have run out of options to try - go up a level
+                let Some(ct) = path.pop() else {
+                    break;
+                };
+
+                used_tiles.set(ct.inner(), false);
+                current_tile = ct;
+                let Some(ci) = indices.pop() else {
+                    break;
+                };
+                current_index = ci;
+
+                char_to_find = match characters.get(path.len() + 1) {
+                    Some(c) => *c,
+                    None => break,
+                };
             }
         }
+    }
 
-        None
+    solutions
+}
+
+/// Find the first path for some characters through a grid of any dimensions, up to 64 tiles
+pub fn find_sized_solution<const W: u8, const H: u8, const SIZE: usize>(
+    characters: &[Character],
+    grid: &SizedGrid<W, H, SIZE>,
+) -> Option<SizedSolution<W, H, SIZE>> {
+    if characters.len() > SIZE {
+        return None;
     }
+    let first_char = characters.first()?;
+
+    for first_tile in
+        geometrid::tile::Tile::<W, H>::iter_by_row().filter(|tile| grid[*tile] == *first_char)
+    {
+        let mut path: SizedSolution<W, H, SIZE> = Default::default();
+        let mut used_tiles: UsedTiles<SIZE> = UsedTiles::new();
+        let mut indices: ArrayVec<u8, SIZE> = Default::default();
+
+        let mut current_index: u8 = 0;
+        let mut current_tile: geometrid::tile::Tile<W, H> = first_tile;
+        let mut char_to_find: Character = match characters.get(1) {
+            Some(c) => *c,
+            None => {
+                path.push(current_tile);
+                return Some(path);
+            }
+        };
 
-    fn find_solution_with_tiles(&self, grid: &Grid, unneeded_tiles: GridSet) -> Option<Solution> {
-        let mut grid = *grid;
-        for tile in unneeded_tiles.iter_true_tiles() {
-            grid[tile] = Character::Blank;
+        loop {
+            if let Some(vector) = Vector::UNITS.get(current_index as usize) {
+                current_index += 1;
+                if let Some(adjacent_tile) = current_tile + vector {
+                    if grid[adjacent_tile] == char_to_find && !used_tiles.get(adjacent_tile.inner())
+                    {
+                        //we need to go deeper
+                        path.push(current_tile);
+                        used_tiles.set(current_tile.inner(), true);
+                        indices.push(current_index);
+                        current_index = 0;
+                        current_tile = adjacent_tile;
+                        char_to_find = match characters.get(path.len() + 1) {
+                            Some(c) => *c,
+                            None => {
+                                path.push(current_tile);
+                                return Some(path);
+                            }
+                        };
+                    }
+                }
+            } else {
+                //we have run out of options to try - go up a level
+                let Some(ct) = path.pop() else {
+                    break;
+                };
+
+                used_tiles.set(ct.inner(), false);
+                current_tile = ct;
+                let Some(ci) = indices.pop() else {
+                    break;
+                };
+                current_index = ci;
+
+                char_to_find = match characters.get(path.len() + 1) {
+                    Some(c) => *c,
+                    None => break,
+                };
+            }
         }
-        Self::find_solution(self, &grid)
     }
+
+    None
 }
@@ -1,76 +1,47 @@
-use itertools::Itertools;
 use lazy_static::lazy_static;
 use ws_core::{DesignedLevel, Numbering, Ustr};
 
 lazy_static! { //todo data_bake
-    pub(crate) static ref TUTORIAL: Vec<DesignedLevel> = include_str!("levels/tutorial.tsv")
-        .lines()
-        .map(DesignedLevel::from_tsv_line)
-        .map(|x| x.unwrap())
-        .collect_vec();
+    pub(crate) static ref TUTORIAL: Vec<DesignedLevel> = load_levels(include_str!("levels/tutorial.tsv"));
 
 
         pub(crate) static ref US_STATES: Vec<DesignedLevel> = number_levels(
-            include_str!("levels/geography/us_states.tsv")
-                .lines()
-                .map(DesignedLevel::from_tsv_line)
-                .map(|x| x.unwrap()),
+            load_levels(include_str!("levels/geography/us_states.tsv")).into_iter(),
             "US States"
         );
 
     pub(crate) static ref EUROPEAN_CAPITALS: Vec<DesignedLevel> = number_levels(
-        include_str!("levels/geography/european_capitals.tsv")
-            .lines()
-            .map(DesignedLevel::from_tsv_line)
-            .map(|x| x.unwrap()),
+        load_levels(include_str!("levels/geography/european_capitals.tsv")).into_iter(),
         "European Capitals"
     );
     pub(crate) static ref EUROPEAN_COUNTRIES: Vec<DesignedLevel> = number_levels(
-        include_str!("levels/geography/european_countries.tsv")
-            .lines()
-            .map(DesignedLevel::from_tsv_line)
-            .map(|x| x.unwrap()),
+        load_levels(include_str!("levels/geography/european_countries.tsv")).into_iter(),
         "European Countries"
     );
 
 
     pub(crate) static ref SOUTH_AND_EAST_ASIAN_COUNTRIES: Vec<DesignedLevel> = number_levels(
-        include_str!("levels/geography/south_and_east_asian_countries.tsv")
-            .lines()
-            .map(DesignedLevel::from_tsv_line)
-            .map(|x| x.unwrap()),
+        load_levels(include_str!("levels/geography/south_and_east_asian_countries.tsv")).into_iter(),
         "South & East Asian Countries"
     );
 
     pub(crate) static ref MIDDLE_EASTERN_COUNTRIES: Vec<DesignedLevel> = number_levels(
-        include_str!("levels/geography/middle_eastern_countries.tsv")
-            .lines()
-            .map(DesignedLevel::from_tsv_line)
-            .map(|x| x.unwrap()),
+        load_levels(include_str!("levels/geography/middle_eastern_countries.tsv")).into_iter(),
         "Middle Eastern Countries"
     );
 
     pub(crate) static ref AFRICAN_COUNTRIES: Vec<DesignedLevel> = number_levels(
-        include_str!("levels/geography/african_countries.tsv")
-            .lines()
-            .map(DesignedLevel::from_tsv_line)
-            .map(|x| x.unwrap()),
+        load_levels(include_str!("levels/geography/african_countries.tsv")).into_iter(),
         "African Countries"
     );
 
     pub(crate) static ref SOUTH_AND_EAST_ASIAN_CAPITALS: Vec<DesignedLevel> = number_levels(
-        include_str!("levels/geography/south_and_east_asian_capitals.tsv")
-            .lines()
-            .map(DesignedLevel::from_tsv_line)
-            .map(|x| x.unwrap()),
+        load_levels(include_str!("levels/geography/south_and_east_asian_capitals.tsv")).into_iter(),
         "South & East Asian Capitals"
     );
 
     pub(crate) static ref MIDDLE_EASTERN_CAPITALS: Vec<DesignedLevel> = number_levels(
-        include_str!("levels/geography/middle_eastern_capitals.tsv")
-            .lines()
-            .map(DesignedLevel::from_tsv_line)
-            .map(|x| x.unwrap()),
+        load_levels(include_str!("levels/geography/middle_eastern_capitals.tsv")).into_iter(),
         "Middle Eastern Capitals"
     );
 
@@ -78,102 +49,62 @@ lazy_static! { //todo data_bake
 
 
     pub(crate) static ref INSECTS: Vec<DesignedLevel> = number_levels(
-        include_str!("levels/natural_world/insects.tsv")
-            .lines()
-            .map(DesignedLevel::from_tsv_line)
-            .map(|x| x.unwrap()),
+        load_levels(include_str!("levels/natural_world/insects.tsv")).into_iter(),
         "Insects"
     );
     pub(crate) static ref FRUIT: Vec<DesignedLevel> = number_levels(
-        include_str!("levels/natural_world/fruit.tsv")
-            .lines()
-            .map(DesignedLevel::from_tsv_line)
-            .map(|x| x.unwrap()),
+        load_levels(include_str!("levels/natural_world/fruit.tsv")).into_iter(),
         "Fruit"
     );
     pub(crate) static ref GEMSTONES: Vec<DesignedLevel> = number_levels(
-        include_str!("levels/natural_world/gemstones.tsv")
-            .lines()
-            .map(DesignedLevel::from_tsv_line)
-            .map(|x| x.unwrap()),
+        load_levels(include_str!("levels/natural_world/gemstones.tsv")).into_iter(),
         "Gemstones"
     );
     pub(crate) static ref VEGETABLES: Vec<DesignedLevel> = number_levels(
-        include_str!("levels/natural_world/vegetables.tsv")
-            .lines()
-            .map(DesignedLevel::from_tsv_line)
-            .map(|x| x.unwrap()),
+        load_levels(include_str!("levels/natural_world/vegetables.tsv")).into_iter(),
         "Vegetables"
     );
     pub(crate) static ref ELEMENTS: Vec<DesignedLevel> = number_levels(
-        include_str!("levels/natural_world/elements.tsv")
-            .lines()
-            .map(DesignedLevel::from_tsv_line)
-            .map(|x| x.unwrap()),
+        load_levels(include_str!("levels/natural_world/elements.tsv")).into_iter(),
         "Elements"
     );
 
     pub(crate) static ref MAMMALS: Vec<DesignedLevel> = number_levels(
-        include_str!("levels/natural_world/mammals.tsv")
-            .lines()
-            .map(DesignedLevel::from_tsv_line)
-            .map(|x| x.unwrap()),
+        load_levels(include_str!("levels/natural_world/mammals.tsv")).into_iter(),
         "Mammals"
     );
 
     pub(crate) static ref BIRDS: Vec<DesignedLevel> = number_levels(
-        include_str!("levels/natural_world/birds.tsv")
-            .lines()
-            .map(DesignedLevel::from_tsv_line)
-            .map(|x| x.unwrap()),
+        load_levels(include_str!("levels/natural_world/birds.tsv")).into_iter(),
         "Birds"
     );
 
     pub(crate) static ref REPTILES_AND_AMPHIBIANS: Vec<DesignedLevel> = number_levels(
-        include_str!("levels/natural_world/reptiles and amphibians.tsv")
-            .lines()
-            .map(DesignedLevel::from_tsv_line)
-            .map(|x| x.unwrap()),
+        load_levels(include_str!("levels/natural_world/reptiles and amphibians.tsv")).into_iter(),
         "Reptiles & Amphibians"
     );
 
     pub(crate) static ref NFL_TEAMS: Vec<DesignedLevel> = number_levels(
-        include_str!("levels/US Sports/NFL Teams.tsv")
-            .lines()
-            .map(DesignedLevel::from_tsv_line)
-            .map(|x| x.unwrap()),
+        load_levels(include_str!("levels/US Sports/NFL Teams.tsv")).into_iter(),
         "NFL Teams"
     );
 
     pub(crate) static ref NHL_TEAMS: Vec<DesignedLevel> = number_levels(
-        include_str!("levels/US Sports/NHL Teams.tsv")
-            .lines()
-            .map(DesignedLevel::from_tsv_line)
-            .map(|x| x.unwrap()),
+        load_levels(include_str!("levels/US Sports/NHL Teams.tsv")).into_iter(),
         "NHL Teams"
     );
 
     pub(crate) static ref NBA_TEAMS: Vec<DesignedLevel> = number_levels(
-        include_str!("levels/US Sports/NBA Teams.tsv")
-            .lines()
-            .map(DesignedLevel::from_tsv_line)
-            .map(|x| x.unwrap()),
+        load_levels(include_str!("levels/US Sports/NBA Teams.tsv")).into_iter(),
         "NBA Teams"
     );
 
     pub(crate) static ref MLB_TEAMS: Vec<DesignedLevel> = number_levels(
-        include_str!("levels/US Sports/MLB Teams.tsv")
-            .lines()
-            .map(DesignedLevel::from_tsv_line)
-            .map(|x| x.unwrap()),
+        load_levels(include_str!("levels/US Sports/MLB Teams.tsv")).into_iter(),
         "MLB Teams"
     );
 
-    pub static ref DEFAULT_DAILY_CHALLENGE: Vec<DesignedLevel> = include_str!("../../daily.tsv")
-            .lines()
-            .map(DesignedLevel::from_tsv_line)
-            .map(|x| x.unwrap())
-            .collect_vec();
+    pub static ref DEFAULT_DAILY_CHALLENGE: Vec<DesignedLevel> = load_levels(include_str!("../../daily.tsv"));
 
 
     pub static ref DAILY_CHALLENGE_NUMBERED: Vec<DesignedLevel> = {
@@ -184,6 +115,11 @@ lazy_static! { //todo data_bake
     };
 }
 
+/// Load levels from a tsv or structured level file
+fn load_levels(text: &str) -> Vec<DesignedLevel> {
+    DesignedLevel::from_level_file(text).unwrap()
+}
+
 pub fn number_daily_challenge_levels(levels: &mut [DesignedLevel]) {
     for (index, level) in levels.iter_mut().enumerate() {
         level.numbering = Some(Numbering::WordSaladNumber(index + 1));
@@ -226,6 +162,8 @@ pub mod tests {
 
     use crate::prelude::LevelSequence;
 
+    use itertools::Itertools;
+
     use super::*;
 
     pub fn get_all_levels() -> Vec<DesignedLevel> {
@@ -379,6 +317,55 @@ pub mod tests {
         assert!(all_errors.is_empty())
     }
 
+    #[test]
+    pub fn test_levels_round_trip() {
+        for level in get_all_levels()
+            .iter()
+            .chain((*DEFAULT_DAILY_CHALLENGE).iter())
+        {
+            let tsv = level.to_tsv_line();
+            let from_tsv = DesignedLevel::from_tsv_line(&tsv).unwrap();
+            assert_eq!(from_tsv.to_tsv_line(), tsv);
+
+            let yaml = DesignedLevel::to_yaml(&[from_tsv.clone()]).unwrap();
+            let from_yaml = DesignedLevel::from_level_file(&yaml).unwrap();
+            assert_eq!(from_yaml, vec![from_tsv]);
+        }
+    }
+
+    #[test]
+    pub fn test_recommended_hints_can_be_found() {
+        let mut all_errors: Vec<String> = Default::default();
+
+        for level in get_all_levels()
+            .iter()
+            .chain((*DEFAULT_DAILY_CHALLENGE).iter())
+        {
+            let mut found_words: FoundWordsMask = 0;
+            let mut unneeded_tiles = GridSet::EMPTY;
+
+            while let Some(word_index) = recommend_hint(level, unneeded_tiles, found_words) {
+                let word = &level.words[word_index];
+                if level.find_word_solution(word, unneeded_tiles).is_none() {
+                    all_errors.push(format!(
+                        "Level '{level}' recommends '{word}' which cannot be found",
+                        word = word.text
+                    ));
+                    break;
+                }
+
+                found_words |= 1 << word_index;
+                unneeded_tiles = level.unneeded_tiles_after_finding(unneeded_tiles, found_words);
+            }
+        }
+
+        for error in all_errors.iter() {
+            println!("{error}")
+        }
+
+        assert!(all_errors.is_empty())
+    }
+
     #[test]
     pub fn test_sequence_clustering() {
         let mut text: String = String::default();
@@ -426,6 +413,67 @@ pub mod tests {
         insta::assert_snapshot!(text);
     }
 
+    #[test]
+    pub fn test_level_difficulty() {
+        let mut text = "Name\tRating\tScore\tFalling\tHardest Word\n".to_string();
+
+        for level in get_all_levels()
+            .iter()
+            .chain((*DEFAULT_DAILY_CHALLENGE).iter())
+        {
+            let difficulty = calculate_difficulty(level);
+            let hardest_word = difficulty
+                .hardest_word()
+                .map(|index| level.words[index].text)
+                .unwrap_or_default();
+
+            text.push_str(
+                format!(
+                    "{name:30}\t{rating}\t{score:.2}\t{falling:.2}\t{hardest_word}\n",
+                    name = level.full_name().as_str(),
+                    rating = difficulty.rating(),
+                    score = difficulty.score,
+                    falling = difficulty.falling_probability,
+                )
+                .as_str(),
+            );
+        }
+
+        insta::assert_snapshot!(text);
+    }
+
+    #[test]
+    pub fn test_levels_not_too_ambiguous() {
+        let mut all_errors: Vec<String> = Default::default();
+
+        for level in get_all_levels()
+            .iter()
+            .chain((*DEFAULT_DAILY_CHALLENGE).iter())
+        {
+            let grid = level.grid.iter().join("");
+            if AMBIGUITY_EXCEPTIONS.contains(&grid.as_str()) {
+                continue;
+            }
+
+            for word in analyze_ambiguity(level).words.iter() {
+                if word.tile_sets.len() > MAX_WORD_TILE_SETS {
+                    all_errors.push(format!(
+                        "Level '{}' has a word with {} sets of tiles",
+                        level.full_name(),
+                        word.tile_sets.len()
+                    ));
+                    all_errors.push(word.explanation(level));
+                }
+            }
+        }
+
+        for error in all_errors.iter() {
+            println!("{error}")
+        }
+
+        assert!(all_errors.is_empty())
+    }
+
     fn test_word_ordering(level: &DesignedLevel, errors: &mut Vec<String>) {
         for (a, b) in level.words.iter().tuple_windows() {
             if a > b {
@@ -435,9 +483,11 @@ pub mod tests {
     }
 
     fn test_grid_not_taboo(level: &DesignedLevel) -> Result<(), String> {
-        if let Some(taboo_word) = orientation::find_taboo_word(&level.grid) {
+        if let Some(taboo_word) = orientation::find_taboo_word(&level.grid, level.digraphs.as_ref())
+        {
             let mut gr = GridResult {
                 grid: level.grid,
+                digraphs: level.digraphs,
                 words: level
                     .words
                     .iter()
@@ -470,7 +520,13 @@ pub mod tests {
         Ok(())
     }
 
+    /// Words which can be traced with more sets of tiles than this are too confusing.
+    /// The most in any current level, apart from the exceptions below, is four
+    pub const MAX_WORD_TILE_SETS: usize = 4;
+
     //spellchecker:disable
     pub const TABOO_EXCEPTIONS: &[&str] = &["ZEUTMSEIORHDAPON"];
+    /// Mammals 65, where 'Hamster' can be traced with six sets of tiles
+    pub const AMBIGUITY_EXCEPTIONS: &[&str] = &["HCHRBAREMOTEBSWF"];
     //spellchecker:enable
 }
@@ -0,0 +1,190 @@
+use itertools::Itertools;
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use strum::{EnumCount, IntoEnumIterator};
+use ws_core::{
+    finder::{
+        budget::{try_make_grid_budgeted, BudgetedGridResult},
+        helpers::{FinderSingleWord, LetterCounts},
+    },
+    DesignedLevel,
+};
+
+use crate::level_sequence::LevelSequence;
+
+/// Finder steps allowed for each attempt.
+/// Limiting steps rather than time means every device generates the same level from a seed,
+/// and each attempt takes a bounded amount of work.
+pub const STEPS_PER_ATTEMPT: usize = 20_000;
+/// Attempts made for a seed before giving up on it
+pub const MAX_ATTEMPTS: usize = 100;
+const MIN_WORDS: usize = 4;
+const MAX_WORDS: usize = 7;
+
+/// Every word used in the levels of a sequence, in level order
+pub fn category_words(sequence: LevelSequence) -> Vec<FinderSingleWord> {
+    sequence
+        .levels()
+        .iter()
+        .flat_map(|level| level.words.iter())
+        .map(FinderSingleWord::from)
+        .unique_by(|word| word.text)
+        .collect()
+}
+
+/// The seed of the level after this one in an endless run
+pub fn next_endless_seed(seed: u64) -> u64 {
+    seed.wrapping_mul(6364136223846793005)
+        .wrapping_add(1442695040888963407)
+}
+
+#[derive(Debug, Clone)]
+pub enum EndlessStep {
+    Generated(DesignedLevel),
+    /// The attempt failed, the next step will make another one
+    Pending,
+    /// Every attempt failed
+    Failed,
+}
+
+/// Generates a level from a seed, one bounded attempt at a time so it can be spread across frames.
+/// Every step finishes an attempt, so generation always makes progress.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndlessGenerator {
+    seed: u64,
+    attempt: usize,
+}
+
+impl EndlessGenerator {
+    pub fn new(seed: u64) -> Self {
+        Self { seed, attempt: 0 }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Generate a level without stopping
+    pub fn generate(seed: u64) -> Option<DesignedLevel> {
+        let mut generator = Self::new(seed);
+        loop {
+            match generator.step() {
+                EndlessStep::Generated(level) => return Some(level),
+                EndlessStep::Pending => {}
+                EndlessStep::Failed => return None,
+            }
+        }
+    }
+
+    /// Make the next attempt at a level
+    pub fn step(&mut self) -> EndlessStep {
+        if self.attempt >= MAX_ATTEMPTS {
+            return EndlessStep::Failed;
+        }
+
+        let mut rng = ChaCha8Rng::seed_from_u64(self.seed.wrapping_add(self.attempt as u64));
+        // Sample a u32 so that 32 bit builds choose the same sequence
+        let sequence_index = rng.gen_range(0..LevelSequence::COUNT as u32) as usize;
+        let Some(sequence) = LevelSequence::iter().nth(sequence_index) else {
+            self.attempt += 1;
+            return EndlessStep::Pending;
+        };
+
+        let (words, exclude_words) = choose_words(sequence, &mut rng);
+
+        if words.len() >= MIN_WORDS {
+            match try_make_grid_budgeted(&words, &exclude_words, STEPS_PER_ATTEMPT, || false) {
+                BudgetedGridResult::Found(result) => {
+                    let line = format!(
+                        "{}\t{}\t{}",
+                        result.grid.iter().join(""),
+                        sequence.name(),
+                        result.words.iter().map(|word| word.text).join("\t")
+                    );
+                    if let Ok(level) = DesignedLevel::from_tsv_line(&line) {
+                        return EndlessStep::Generated(level);
+                    }
+                }
+                BudgetedGridResult::Impossible
+                | BudgetedGridResult::Cancelled
+                | BudgetedGridResult::NotFound
+                | BudgetedGridResult::OutOfSteps => {}
+            }
+        }
+
+        self.attempt += 1;
+        EndlessStep::Pending
+    }
+}
+
+/// Choose words from the sequence which fit in a grid, and the words which must not appear
+fn choose_words(
+    sequence: LevelSequence,
+    rng: &mut ChaCha8Rng,
+) -> (Vec<FinderSingleWord>, Vec<FinderSingleWord>) {
+    let mut candidates = category_words(sequence);
+    candidates.shuffle(rng);
+
+    let mut letters = LetterCounts::default();
+    let mut words: Vec<FinderSingleWord> = vec![];
+    let mut exclude_words: Vec<FinderSingleWord> = vec![];
+
+    for word in candidates {
+        if words.len() < MAX_WORDS {
+            if let Some(union) = letters.try_union(&word.counts) {
+                if union.into_iter().count() <= 16 {
+                    letters = union;
+                    words.push(word);
+                    continue;
+                }
+            }
+        }
+        exclude_words.push(word);
+    }
+
+    words.sort_by_cached_key(|word| word.text.to_ascii_lowercase());
+    (words, exclude_words)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use test_case::test_case;
+    use ws_core::prelude::*;
+
+    #[test_case(0)]
+    #[test_case(1)]
+    #[test_case(12345)]
+    pub fn test_generation_is_deterministic(seed: u64) {
+        let first = EndlessGenerator::generate(seed).expect("Should generate a level");
+        let second = EndlessGenerator::generate(seed).expect("Should generate a level");
+
+        assert_eq!(first, second);
+        assert!(first.words.len() >= MIN_WORDS);
+        for word in first.words.iter() {
+            assert!(word.find_solution(&first.grid).is_some());
+        }
+    }
+
+    #[test]
+    pub fn test_each_step_makes_an_attempt() {
+        let mut generator = EndlessGenerator::new(42);
+        let level = loop {
+            let attempt = generator.attempt;
+            match generator.step() {
+                EndlessStep::Generated(level) => break level,
+                EndlessStep::Pending => assert_eq!(generator.attempt, attempt + 1),
+                EndlessStep::Failed => panic!("Should generate a level"),
+            }
+        };
+
+        assert_eq!(Some(level), EndlessGenerator::generate(42));
+    }
+
+    #[test]
+    pub fn test_seeds_differ() {
+        let seed = next_endless_seed(0);
+        assert_ne!(seed, 0);
+        assert_ne!(next_endless_seed(seed), seed);
+    }
+}
@@ -1,4 +1,5 @@
 pub mod all_levels;
+pub mod endless;
 pub mod level_group;
 pub mod level_sequence;
 
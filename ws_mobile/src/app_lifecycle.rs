@@ -56,6 +56,7 @@ fn watch_lifecycle(
     mut popup: ResMut<PopupState>,
     mut level_time: ResMut<LevelTime>,
     mut change_level_events: EventWriter<ChangeLevelEvent>,
+    mut challenge_state: ResMut<ChallengeState>,
 ) {
     for event in events.read() {
         match event {
@@ -80,6 +81,7 @@ fn watch_lifecycle(
                                 additional,
                             };
                         }
+                        LevelTime::TimeAttack { .. } => level_time.pause_timer_at(*time_sent),
                         LevelTime::Paused { .. } => {}
                         LevelTime::Finished { .. } => {}
                     }
@@ -94,9 +96,12 @@ fn watch_lifecycle(
             }
             AppLifeCycleEvent::UrlOpened { url } => {
                 info!("Url opened event '{url}'");
+                challenge_state.received = try_received_challenge_from_path(url);
                 if let Some(daily_index) = try_daily_index_from_path(&url) {
                     let new_level = CurrentLevel::DailyChallenge { index: daily_index };
                     change_level_events.send(new_level.into());
+                } else if let Some(seed) = try_endless_seed_from_path(&url) {
+                    change_level_events.send(CurrentLevel::Endless { seed }.into());
                 } else if let Some(level) = DesignedLevel::try_from_path(&url) {
                     let custom_level = CurrentLevel::Custom {
                         name: level.full_name().clone(),